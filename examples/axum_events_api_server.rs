@@ -6,7 +6,6 @@ use http_body_util::{BodyExt, Empty, Full};
 use hyper::Response;
 use tracing::*;
 
-use axum::Extension;
 use std::convert::Infallible;
 use std::sync::Arc;
 use tokio::net::TcpListener;
@@ -31,10 +30,7 @@ async fn test_error_install() -> String {
     "Error while installing".to_string()
 }
 
-async fn test_push_event(
-    Extension(_environment): Extension<Arc<SlackHyperListenerEnvironment>>,
-    Extension(event): Extension<SlackPushEvent>,
-) -> Response<BoxBody<Bytes, Infallible>> {
+async fn test_push_event(event: SlackPushEvent) -> Response<BoxBody<Bytes, Infallible>> {
     println!("Received push event: {:?}", event);
 
     match event {
@@ -45,20 +41,12 @@ async fn test_push_event(
     }
 }
 
-async fn test_command_event(
-    Extension(_environment): Extension<Arc<SlackHyperListenerEnvironment>>,
-    Extension(event): Extension<SlackCommandEvent>,
-) -> axum::Json<SlackCommandEventResponse> {
+async fn test_command_event(event: SlackCommandEvent) -> SlackCommandEventResponse {
     println!("Received command event: {:?}", event);
-    axum::Json(SlackCommandEventResponse::new(
-        SlackMessageContent::new().with_text("Working on it".into()),
-    ))
+    SlackCommandEventResponse::new(SlackMessageContent::new().with_text("Working on it".into()))
 }
 
-async fn test_interaction_event(
-    Extension(_environment): Extension<Arc<SlackHyperListenerEnvironment>>,
-    Extension(event): Extension<SlackInteractionEvent>,
-) {
+async fn test_interaction_event(event: SlackInteractionEvent) {
     println!("Received interaction event: {:?}", event);
 }
 
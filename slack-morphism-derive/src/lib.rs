@@ -0,0 +1,510 @@
+//! Derive macros for Slack Morphism Block Kit templates.
+//! Use them with the `derive` feature of `slack-morphism`, which re-exports them.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use std::collections::HashSet;
+use syn::ext::IdentExt;
+use syn::{
+    parse_macro_input, Attribute, Data, DeriveInput, Fields, GenericArgument, Ident, LitStr,
+    PathArguments, Type,
+};
+
+///
+/// Implements `SlackBlocksTemplate` for a struct from the field attributes:
+///
+/// - `#[slack(header)]`: a header block with the plain text of the field;
+/// - `#[slack(section)]`: a section block with the escaped field as `mrkdwn` text;
+/// - `#[slack(context)]`: a context block with the escaped field as `mrkdwn` text;
+/// - `#[slack(section, raw)]`, `#[slack(context, raw)]`: the same without escaping,
+///   use them only for trusted `mrkdwn`;
+/// - `#[slack(field = "Label")]`: a section field with the bold label and the escaped value,
+///   consecutive fields are grouped into sections;
+/// - `#[slack(button(action_id = "approve", text = "Approve", style = "primary"))]`: a button with the field as its value,
+///   consecutive buttons are grouped into actions blocks;
+/// - `#[slack(bullets)]`: a section with the escaped bullet list of the items;
+/// - `#[slack(list)]`: the blocks of the items that implement `SlackBlocksTemplate`;
+/// - `#[slack(divider)]`: a divider before the block of the field.
+///
+/// Fields with `Option` types produce blocks only when they have values.
+/// Fields without attributes are ignored.
+///
+/// When the struct has buttons, the macro also generates the `<Struct>Action` enum with the variant
+/// for each button that implements `SlackBlockActionsPayload`, so handlers can match the actions
+/// of the template with the same definition. The button action ids must be unique.
+///
+#[proc_macro_derive(SlackBlocksTemplate, attributes(slack))]
+pub fn derive_blocks_template(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    blocks_template(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+///
+/// Implements `SlackBlockActionsPayload` for an enum, matching the variants by `#[slack(action_id = "...")]`
+/// (or the snake case variant name by default). The variants can be unit or have a single field
+/// that implements `SlackBlockActionValue` (e.g. `String` for the action value).
+/// The action ids must be unique.
+///
+#[proc_macro_derive(SlackBlockActionsPayload, attributes(slack))]
+pub fn derive_block_actions_payload(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    block_actions_payload(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+enum TemplateItem {
+    Header,
+    Section,
+    Context,
+    Field(LitStr),
+    Button {
+        action_id: LitStr,
+        text: LitStr,
+        style: Option<Ident>,
+    },
+    Bullets,
+    List,
+}
+
+struct TemplateField {
+    ident: Ident,
+    ty: Type,
+    item: TemplateItem,
+    divider: bool,
+    raw: bool,
+}
+
+fn parse_template_field(
+    ident: &Ident,
+    ty: &Type,
+    attrs: &[Attribute],
+) -> syn::Result<Option<TemplateField>> {
+    let mut item = None;
+    let mut divider = false;
+    let mut raw = false;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("slack")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("header") {
+                item = Some(TemplateItem::Header);
+            } else if meta.path.is_ident("section") {
+                item = Some(TemplateItem::Section);
+            } else if meta.path.is_ident("context") {
+                item = Some(TemplateItem::Context);
+            } else if meta.path.is_ident("bullets") {
+                item = Some(TemplateItem::Bullets);
+            } else if meta.path.is_ident("list") {
+                item = Some(TemplateItem::List);
+            } else if meta.path.is_ident("divider") {
+                divider = true;
+            } else if meta.path.is_ident("raw") {
+                raw = true;
+            } else if meta.path.is_ident("field") {
+                item = Some(TemplateItem::Field(meta.value()?.parse()?));
+            } else if meta.path.is_ident("button") {
+                let mut action_id = None;
+                let mut text = None;
+                let mut style = None;
+                meta.parse_nested_meta(|button| {
+                    let value: LitStr = button.value()?.parse()?;
+                    if button.path.is_ident("action_id") {
+                        action_id = Some(value);
+                    } else if button.path.is_ident("text") {
+                        text = Some(value);
+                    } else if button.path.is_ident("style") {
+                        style = Some(match value.value().as_str() {
+                            "primary" => Ident::new("Primary", value.span()),
+                            "danger" => Ident::new("Danger", value.span()),
+                            _ => return Err(button.error("expected `primary` or `danger`")),
+                        });
+                    } else {
+                        return Err(button.error("expected `action_id`, `text` or `style`"));
+                    }
+                    Ok(())
+                })?;
+                let action_id = action_id
+                    .unwrap_or_else(|| LitStr::new(&ident.unraw().to_string(), ident.span()));
+                item = Some(TemplateItem::Button {
+                    text: text.unwrap_or_else(|| action_id.clone()),
+                    action_id,
+                    style,
+                });
+            } else {
+                return Err(meta.error("unsupported slack template attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
+    if raw && !matches!(item, Some(TemplateItem::Section | TemplateItem::Context)) {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "`raw` is supported only for `section` and `context`",
+        ));
+    }
+
+    Ok(item.map(|item| TemplateField {
+        ident: ident.clone(),
+        ty: ty.clone(),
+        item,
+        divider,
+        raw,
+    }))
+}
+
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Path(type_path) => {
+            let segment = type_path.path.segments.last()?;
+            match (&segment.arguments, segment.ident == "Option") {
+                (PathArguments::AngleBracketed(arguments), true) => {
+                    arguments.args.iter().find_map(|argument| match argument {
+                        GenericArgument::Type(ty) => Some(ty),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The variant name for the field, e.g. `Type` for `r#type`
+fn pascal_case(ident: &Ident) -> syn::Result<Ident> {
+    let name: String = ident
+        .unraw()
+        .to_string()
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect();
+    syn::parse_str::<Ident>(&name)
+        .map(|variant| Ident::new(&variant.to_string(), ident.span()))
+        .map_err(|_| {
+            syn::Error::new_spanned(
+                ident,
+                format!("can't make an action variant name from `{}`", ident),
+            )
+        })
+}
+
+fn snake_case(ident: &Ident) -> String {
+    let mut name = String::new();
+    for (index, c) in ident.unraw().to_string().chars().enumerate() {
+        if c.is_uppercase() {
+            if index > 0 {
+                name.push('_');
+            }
+            name.extend(c.to_lowercase());
+        } else {
+            name.push(c);
+        }
+    }
+    name
+}
+
+fn blocks_template(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "SlackBlocksTemplate can be derived only for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "SlackBlocksTemplate can be derived only for structs",
+            ))
+        }
+    };
+
+    let mut template_fields = Vec::new();
+    for field in fields {
+        if let Some(ident) = &field.ident {
+            template_fields.extend(parse_template_field(ident, &field.ty, &field.attrs)?);
+        }
+    }
+
+    let flush_fields = quote! {
+        for chunk in fields.chunks(10) {
+            blocks.push(SlackSectionBlock::new().with_fields(chunk.to_vec()).into());
+        }
+        fields.clear();
+    };
+    let flush_buttons = quote! {
+        for chunk in buttons.chunks(25) {
+            blocks.push(SlackActionsBlock::new(chunk.to_vec()).into());
+        }
+        buttons.clear();
+    };
+
+    let statements = template_fields.iter().map(|field| {
+        let ident = &field.ident;
+        let mrkdwn = if field.raw {
+            quote! { value.to_string() }
+        } else {
+            quote! { escape_mrkdwn(&value.to_string()) }
+        };
+        let render = match &field.item {
+            TemplateItem::Header => quote! {
+                blocks.push(SlackHeaderBlock::new(value.to_string().into()).into());
+            },
+            TemplateItem::Section => quote! {
+                blocks.push(
+                    SlackSectionBlock::new()
+                        .with_text(SlackBlockMarkDownText::new(#mrkdwn).into())
+                        .into(),
+                );
+            },
+            TemplateItem::Context => quote! {
+                blocks.push(
+                    SlackContextBlock::new(vec![SlackContextBlockElement::MarkDown(
+                        SlackBlockMarkDownText::new(#mrkdwn),
+                    )])
+                    .into(),
+                );
+            },
+            TemplateItem::Field(label) => quote! {
+                fields.push(
+                    SlackMrkdwnBuilder::new()
+                        .bold(#label)
+                        .newline()
+                        .text(value.to_string())
+                        .to_block_text(),
+                );
+            },
+            TemplateItem::Button {
+                action_id,
+                text,
+                style,
+            } => {
+                let style = style.iter();
+                quote! {
+                    buttons.push(
+                        SlackBlockButtonElement::new(#action_id.into(), #text.to_string().into())
+                            .with_value(value.to_string())
+                            #(.with_style(SlackBlockButtonStyle::#style))*
+                            .into(),
+                    );
+                }
+            }
+            TemplateItem::Bullets => quote! {
+                let items: Vec<String> = value.iter().map(|item| item.to_string()).collect();
+                if !items.is_empty() {
+                    blocks.push(
+                        SlackSectionBlock::new()
+                            .with_text(SlackMrkdwnBuilder::new().bullet_list(items).to_block_text())
+                            .into(),
+                    );
+                }
+            },
+            TemplateItem::List => quote! {
+                for item in value.iter() {
+                    blocks.extend(SlackBlocksTemplate::render_template(item));
+                }
+            },
+        };
+
+        let flush = match &field.item {
+            TemplateItem::Field(_) => flush_buttons.clone(),
+            TemplateItem::Button { .. } => flush_fields.clone(),
+            _ => quote! { #flush_fields #flush_buttons },
+        };
+        let divider = if field.divider {
+            quote! {
+                #flush_fields
+                #flush_buttons
+                blocks.push(SlackDividerBlock::new().into());
+            }
+        } else {
+            quote! {}
+        };
+
+        if option_inner_type(&field.ty).is_some() {
+            quote! {
+                if let Some(value) = &self.#ident {
+                    #flush
+                    #divider
+                    #render
+                }
+            }
+        } else {
+            quote! {
+                {
+                    let value = &self.#ident;
+                    #flush
+                    #divider
+                    #render
+                }
+            }
+        }
+    });
+
+    let template_impl = quote! {
+        impl #impl_generics ::slack_morphism::prelude::SlackBlocksTemplate for #name #type_generics #where_clause {
+            #[allow(unused_mut, unused_variables)]
+            fn render_template(&self) -> Vec<::slack_morphism::prelude::SlackBlock> {
+                use ::slack_morphism::prelude::*;
+
+                let mut blocks: Vec<SlackBlock> = Vec::new();
+                let mut fields: Vec<SlackBlockText> = Vec::new();
+                let mut buttons: Vec<SlackActionBlockElement> = Vec::new();
+                #(#statements)*
+                #flush_fields
+                #flush_buttons
+                blocks
+            }
+        }
+    };
+
+    let buttons: Vec<(&TemplateField, &LitStr)> = template_fields
+        .iter()
+        .filter_map(|field| match &field.item {
+            TemplateItem::Button { action_id, .. } => Some((field, action_id)),
+            _ => None,
+        })
+        .collect();
+    unique_action_ids(buttons.iter().map(|(_, action_id)| *action_id))?;
+
+    let actions_enum = if buttons.is_empty() {
+        quote! {}
+    } else {
+        let actions_name = format_ident!("{}Action", name);
+        let variants = buttons
+            .iter()
+            .map(|(field, _)| pascal_case(&field.ident))
+            .collect::<syn::Result<Vec<Ident>>>()?;
+        let mut variant_names = HashSet::new();
+        for (variant, (field, _)) in variants.iter().zip(&buttons) {
+            if !variant_names.insert(variant.to_string()) {
+                return Err(syn::Error::new_spanned(
+                    &field.ident,
+                    format!("duplicate action variant `{}`", variant),
+                ));
+            }
+        }
+        let action_ids = buttons.iter().map(|(_, action_id)| action_id);
+        let doc = format!(
+            "The actions of the `{}` template buttons with their values",
+            name
+        );
+
+        quote! {
+            #[doc = #doc]
+            #[derive(Debug, PartialEq, Eq, Clone)]
+            #vis enum #actions_name {
+                #(#variants(String)),*
+            }
+
+            impl ::slack_morphism::prelude::SlackBlockActionsPayload for #actions_name {
+                fn from_action(action: &::slack_morphism::prelude::SlackInteractionActionInfo) -> Option<Self> {
+                    match action.action_id.0.as_str() {
+                        #(#action_ids => Some(Self::#variants(action.value.clone().unwrap_or_default())),)*
+                        _ => None,
+                    }
+                }
+            }
+        }
+    };
+
+    Ok(quote! {
+        #template_impl
+        #actions_enum
+    })
+}
+
+/// The same action id would match only the first button or variant
+fn unique_action_ids<'a>(action_ids: impl Iterator<Item = &'a LitStr>) -> syn::Result<()> {
+    let mut seen = HashSet::new();
+    for action_id in action_ids {
+        if !seen.insert(action_id.value()) {
+            return Err(syn::Error::new_spanned(
+                action_id,
+                format!("duplicate action_id `{}`", action_id.value()),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn block_actions_payload(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "SlackBlockActionsPayload can be derived only for enums",
+            ))
+        }
+    };
+
+    let mut arms = Vec::new();
+    let mut action_ids = Vec::new();
+    for variant in variants {
+        let ident = &variant.ident;
+        let mut action_id = LitStr::new(&snake_case(ident), Span::call_site());
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("slack"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("action_id") {
+                    action_id = meta.value()?.parse()?;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `action_id`"))
+                }
+            })?;
+        }
+
+        arms.push(match &variant.fields {
+            Fields::Unit => quote! { #action_id => Some(Self::#ident), },
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => quote! {
+                #action_id => ::slack_morphism::prelude::SlackBlockActionValue::from_action_value(action)
+                    .map(Self::#ident),
+            },
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "SlackBlockActionsPayload variants can be unit or have a single unnamed field",
+                ))
+            }
+        });
+        action_ids.push(action_id);
+    }
+    unique_action_ids(action_ids.iter())?;
+
+    Ok(quote! {
+        impl #impl_generics ::slack_morphism::prelude::SlackBlockActionsPayload for #name #type_generics #where_clause {
+            fn from_action(action: &::slack_morphism::prelude::SlackInteractionActionInfo) -> Option<Self> {
+                match action.action_id.0.as_str() {
+                    #(#arms)*
+                    _ => None,
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,11 @@
+use slack_morphism_derive::SlackBlocksTemplate;
+
+#[derive(SlackBlocksTemplate)]
+struct ReviewTemplate {
+    #[slack(button(action_id = "review", text = "Approve"))]
+    approve: String,
+    #[slack(button(action_id = "review", text = "Reject"))]
+    reject: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use slack_morphism_derive::SlackBlockActionsPayload;
+
+#[derive(SlackBlockActionsPayload)]
+enum ReviewAction {
+    Approve,
+    #[slack(action_id = "approve")]
+    ApproveAll,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use slack_morphism_derive::SlackBlocksTemplate;
+
+#[derive(SlackBlocksTemplate)]
+struct ReviewTemplate {
+    #[slack(button(action_id = "review"))]
+    self_: String,
+}
+
+fn main() {}
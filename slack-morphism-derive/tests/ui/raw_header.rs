@@ -0,0 +1,9 @@
+use slack_morphism_derive::SlackBlocksTemplate;
+
+#[derive(SlackBlocksTemplate)]
+struct ReviewTemplate {
+    #[slack(header, raw)]
+    title: String,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use slack_morphism_derive::{SlackBlockActionsPayload, SlackBlocksTemplate};
+
+#[derive(SlackBlocksTemplate)]
+struct ReviewTemplate {
+    #[slack(footer)]
+    title: String,
+}
+
+#[derive(SlackBlocksTemplate)]
+struct StyleTemplate {
+    #[slack(button(style = "secondary"))]
+    approve: String,
+}
+
+#[derive(SlackBlockActionsPayload)]
+enum ReviewAction {
+    #[slack(value = "approve")]
+    Approve,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use slack_morphism_derive::{SlackBlockActionsPayload, SlackBlocksTemplate};
+
+#[derive(SlackBlocksTemplate)]
+struct ReviewTemplate(String);
+
+#[derive(SlackBlockActionsPayload)]
+struct ReviewAction {
+    approve: String,
+}
+
+#[derive(SlackBlockActionsPayload)]
+enum DeployAction {
+    Deploy(String, String),
+}
+
+fn main() {}
@@ -0,0 +1,132 @@
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::*;
+use crate::ratectl::*;
+use crate::*;
+use futures::future::{BoxFuture, FutureExt};
+
+impl<'a, SCHC> SlackClientSession<'a, SCHC>
+where
+    SCHC: SlackClientHttpConnector + Send,
+{
+    ///
+    /// https://api.slack.com/methods/admin.apps.approve
+    ///
+    pub async fn admin_apps_approve(
+        &self,
+        req: &SlackApiAdminAppsApproveRequest,
+    ) -> ClientResult<SlackApiAdminAppsApproveResponse> {
+        self.http_session_api
+            .http_post("admin.apps.approve", req, Some(&SLACK_TIER2_METHOD_CONFIG))
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.apps.restrict
+    ///
+    pub async fn admin_apps_restrict(
+        &self,
+        req: &SlackApiAdminAppsRestrictRequest,
+    ) -> ClientResult<SlackApiAdminAppsRestrictResponse> {
+        self.http_session_api
+            .http_post("admin.apps.restrict", req, Some(&SLACK_TIER2_METHOD_CONFIG))
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.apps.requests.list
+    ///
+    pub async fn admin_apps_requests_list(
+        &self,
+        req: &SlackApiAdminAppsRequestsListRequest,
+    ) -> ClientResult<SlackApiAdminAppsRequestsListResponse> {
+        self.http_session_api
+            .http_post(
+                "admin.apps.requests.list",
+                req,
+                Some(&SLACK_TIER2_METHOD_CONFIG),
+            )
+            .await
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminAppsApproveRequest {
+    pub app_id: Option<SlackAppId>,
+    pub request_id: Option<SlackAdminAppRequestId>,
+    pub team_id: Option<SlackTeamId>,
+    pub enterprise_id: Option<SlackEnterpriseId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminAppsApproveResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminAppsRestrictRequest {
+    pub app_id: Option<SlackAppId>,
+    pub request_id: Option<SlackAdminAppRequestId>,
+    pub team_id: Option<SlackTeamId>,
+    pub enterprise_id: Option<SlackEnterpriseId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminAppsRestrictResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminAppsRequestsListRequest {
+    pub team_id: Option<SlackTeamId>,
+    pub enterprise_id: Option<SlackEnterpriseId>,
+    pub certified: Option<bool>,
+    pub cursor: Option<SlackCursorId>,
+    pub limit: Option<u16>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminAppsRequestsListResponse {
+    pub app_requests: Vec<SlackAdminAppRequest>,
+    pub response_metadata: Option<SlackResponseMetadata>,
+}
+
+impl<SCHC> SlackApiScrollableRequest<SCHC> for SlackApiAdminAppsRequestsListRequest
+where
+    SCHC: SlackClientHttpConnector + Send + Sync + Clone + 'static,
+{
+    type ResponseType = SlackApiAdminAppsRequestsListResponse;
+    type CursorType = SlackCursorId;
+    type ResponseItemType = SlackAdminAppRequest;
+
+    fn with_new_cursor(&self, new_cursor: Option<&Self::CursorType>) -> Self {
+        self.clone().opt_cursor(new_cursor.cloned())
+    }
+
+    fn scroll<'a, 's>(
+        &'a self,
+        session: &'a SlackClientSession<'s, SCHC>,
+    ) -> BoxFuture<'a, ClientResult<Self::ResponseType>> {
+        async move { session.admin_apps_requests_list(self).await }.boxed()
+    }
+}
+
+impl SlackApiScrollableResponse for SlackApiAdminAppsRequestsListResponse {
+    type CursorType = SlackCursorId;
+    type ResponseItemType = SlackAdminAppRequest;
+
+    fn next_cursor(&self) -> Option<Self::CursorType> {
+        self.response_metadata
+            .as_ref()
+            .and_then(|rm| rm.next_cursor.as_ref())
+            .cloned()
+    }
+
+    fn scrollable_items<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::ResponseItemType> + 'a> {
+        Box::new(self.app_requests.iter())
+    }
+}
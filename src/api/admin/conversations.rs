@@ -0,0 +1,296 @@
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, skip_serializing_none};
+
+use crate::api::files::to_csv;
+use crate::models::*;
+use crate::ratectl::*;
+use crate::*;
+use futures::future::{BoxFuture, FutureExt};
+
+impl<'a, SCHC> SlackClientSession<'a, SCHC>
+where
+    SCHC: SlackClientHttpConnector + Send,
+{
+    ///
+    /// https://api.slack.com/methods/admin.conversations.search
+    ///
+    pub async fn admin_conversations_search(
+        &self,
+        req: &SlackApiAdminConversationsSearchRequest,
+    ) -> ClientResult<SlackApiAdminConversationsSearchResponse> {
+        self.http_session_api
+            .http_post(
+                "admin.conversations.search",
+                req,
+                Some(&SLACK_TIER2_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.conversations.archive
+    ///
+    pub async fn admin_conversations_archive(
+        &self,
+        req: &SlackApiAdminConversationsArchiveRequest,
+    ) -> ClientResult<SlackApiAdminConversationsArchiveResponse> {
+        self.http_session_api
+            .http_post(
+                "admin.conversations.archive",
+                req,
+                Some(&SLACK_TIER2_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.conversations.setTeams
+    ///
+    pub async fn admin_conversations_set_teams(
+        &self,
+        req: &SlackApiAdminConversationsSetTeamsRequest,
+    ) -> ClientResult<SlackApiAdminConversationsSetTeamsResponse> {
+        self.http_session_api
+            .http_post(
+                "admin.conversations.setTeams",
+                req,
+                Some(&SLACK_TIER2_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.conversations.convertToPrivate
+    ///
+    pub async fn admin_conversations_convert_to_private(
+        &self,
+        req: &SlackApiAdminConversationsConvertToPrivateRequest,
+    ) -> ClientResult<SlackApiAdminConversationsConvertToPrivateResponse> {
+        self.http_session_api
+            .http_post(
+                "admin.conversations.convertToPrivate",
+                req,
+                Some(&SLACK_TIER2_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.conversations.restrictAccess.addGroup
+    ///
+    pub async fn admin_conversations_restrict_access_add_group(
+        &self,
+        req: &SlackApiAdminConversationsRestrictAccessAddGroupRequest,
+    ) -> ClientResult<SlackApiAdminConversationsRestrictAccessAddGroupResponse> {
+        self.http_session_api
+            .http_post(
+                "admin.conversations.restrictAccess.addGroup",
+                req,
+                Some(&SLACK_TIER2_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.conversations.restrictAccess.removeGroup
+    ///
+    pub async fn admin_conversations_restrict_access_remove_group(
+        &self,
+        req: &SlackApiAdminConversationsRestrictAccessRemoveGroupRequest,
+    ) -> ClientResult<SlackApiAdminConversationsRestrictAccessRemoveGroupResponse> {
+        self.http_session_api
+            .http_post(
+                "admin.conversations.restrictAccess.removeGroup",
+                req,
+                Some(&SLACK_TIER2_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.conversations.restrictAccess.listGroups
+    ///
+    pub async fn admin_conversations_restrict_access_list_groups(
+        &self,
+        req: &SlackApiAdminConversationsRestrictAccessListGroupsRequest,
+    ) -> ClientResult<SlackApiAdminConversationsRestrictAccessListGroupsResponse> {
+        self.http_session_api
+            .http_post(
+                "admin.conversations.restrictAccess.listGroups",
+                req,
+                Some(&SLACK_TIER2_METHOD_CONFIG),
+            )
+            .await
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminConversationsSearchRequest {
+    pub query: Option<String>,
+    pub cursor: Option<SlackCursorId>,
+    pub limit: Option<u16>,
+    #[serde(serialize_with = "to_csv")]
+    pub search_channel_types: Option<Vec<SlackChannelType>>,
+    pub sort: Option<String>,
+    pub sort_dir: Option<String>,
+    #[serde(serialize_with = "to_csv")]
+    pub team_ids: Option<Vec<SlackTeamId>>,
+    #[serde(serialize_with = "to_csv")]
+    pub connected_team_ids: Option<Vec<SlackTeamId>>,
+    pub total_count_only: Option<bool>,
+}
+
+#[serde_as]
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminConversationsSearchResponse {
+    #[serde(default)]
+    pub conversations: Vec<SlackAdminConversation>,
+    #[serde(default)]
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    pub next_cursor: Option<SlackCursorId>,
+    pub total_count: Option<u64>,
+}
+
+impl<SCHC> SlackApiScrollableRequest<SCHC> for SlackApiAdminConversationsSearchRequest
+where
+    SCHC: SlackClientHttpConnector + Send + Sync + Clone + 'static,
+{
+    type ResponseType = SlackApiAdminConversationsSearchResponse;
+    type CursorType = SlackCursorId;
+    type ResponseItemType = SlackAdminConversation;
+
+    fn with_new_cursor(&self, new_cursor: Option<&Self::CursorType>) -> Self {
+        self.clone().opt_cursor(new_cursor.cloned())
+    }
+
+    fn scroll<'a, 's>(
+        &'a self,
+        session: &'a SlackClientSession<'s, SCHC>,
+    ) -> BoxFuture<'a, ClientResult<Self::ResponseType>> {
+        async move { session.admin_conversations_search(self).await }.boxed()
+    }
+}
+
+impl SlackApiScrollableResponse for SlackApiAdminConversationsSearchResponse {
+    type CursorType = SlackCursorId;
+    type ResponseItemType = SlackAdminConversation;
+
+    fn next_cursor(&self) -> Option<Self::CursorType> {
+        self.next_cursor.clone()
+    }
+
+    fn scrollable_items<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::ResponseItemType> + 'a> {
+        Box::new(self.conversations.iter())
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminConversationsArchiveRequest {
+    pub channel_id: SlackChannelId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminConversationsArchiveResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminConversationsSetTeamsRequest {
+    pub channel_id: SlackChannelId,
+    pub team_id: Option<SlackTeamId>,
+    #[serde(serialize_with = "to_csv")]
+    pub target_team_ids: Option<Vec<SlackTeamId>>,
+    pub org_channel: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminConversationsSetTeamsResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminConversationsConvertToPrivateRequest {
+    pub channel_id: SlackChannelId,
+    pub name: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminConversationsConvertToPrivateResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminConversationsRestrictAccessAddGroupRequest {
+    pub channel_id: SlackChannelId,
+    pub group_id: SlackEnterpriseSubteamId,
+    pub team_id: Option<SlackTeamId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminConversationsRestrictAccessAddGroupResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminConversationsRestrictAccessRemoveGroupRequest {
+    pub channel_id: SlackChannelId,
+    pub group_id: SlackEnterpriseSubteamId,
+    pub team_id: SlackTeamId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminConversationsRestrictAccessRemoveGroupResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminConversationsRestrictAccessListGroupsRequest {
+    pub channel_id: SlackChannelId,
+    pub team_id: Option<SlackTeamId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminConversationsRestrictAccessListGroupsResponse {
+    pub group_ids: Vec<SlackEnterpriseSubteamId>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_slack_api_admin_conversations_search_request() {
+        let req = SlackApiAdminConversationsSearchRequest::new()
+            .with_query("general".into())
+            .with_team_ids(vec!["T0001".into(), "T0002".into()]);
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            serde_json::json!({
+                "query": "general",
+                "team_ids": "T0001,T0002"
+            })
+        );
+    }
+
+    #[test]
+    fn test_slack_api_admin_conversations_search_response() {
+        let payload =
+            include_str!("../fixtures/slack_api_admin_conversations_search_response.json");
+        let model: SlackApiAdminConversationsSearchResponse =
+            serde_json::from_str(payload).unwrap();
+        assert_eq!(model.conversations.len(), 1);
+        assert_eq!(
+            model.conversations[0].connected_team_ids,
+            Some(vec![SlackTeamId::from("T0001")])
+        );
+        assert_eq!(
+            SlackApiScrollableResponse::next_cursor(&model),
+            Some(SlackCursorId::from("dXNlcjpVMEc5V0ZYTlo="))
+        );
+    }
+}
@@ -0,0 +1,13 @@
+//!
+//! Support for Slack Admin API methods (Enterprise Grid)
+//!
+
+mod apps;
+mod conversations;
+mod teams;
+mod users;
+
+pub use apps::*;
+pub use conversations::*;
+pub use teams::*;
+pub use users::*;
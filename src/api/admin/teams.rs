@@ -0,0 +1,345 @@
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::*;
+use crate::ratectl::*;
+use crate::*;
+use futures::future::{BoxFuture, FutureExt};
+
+impl<'a, SCHC> SlackClientSession<'a, SCHC>
+where
+    SCHC: SlackClientHttpConnector + Send,
+{
+    ///
+    /// https://api.slack.com/methods/admin.teams.create
+    ///
+    pub async fn admin_teams_create(
+        &self,
+        req: &SlackApiAdminTeamsCreateRequest,
+    ) -> ClientResult<SlackApiAdminTeamsCreateResponse> {
+        self.http_session_api
+            .http_post("admin.teams.create", req, Some(&SLACK_TIER2_METHOD_CONFIG))
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.teams.list
+    ///
+    pub async fn admin_teams_list(
+        &self,
+        req: &SlackApiAdminTeamsListRequest,
+    ) -> ClientResult<SlackApiAdminTeamsListResponse> {
+        self.http_session_api
+            .http_post("admin.teams.list", req, Some(&SLACK_TIER3_METHOD_CONFIG))
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.teams.admins.list
+    ///
+    pub async fn admin_teams_admins_list(
+        &self,
+        req: &SlackApiAdminTeamsAdminsListRequest,
+    ) -> ClientResult<SlackApiAdminTeamsAdminsListResponse> {
+        self.http_session_api
+            .http_post(
+                "admin.teams.admins.list",
+                req,
+                Some(&SLACK_TIER3_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.teams.owners.list
+    ///
+    pub async fn admin_teams_owners_list(
+        &self,
+        req: &SlackApiAdminTeamsOwnersListRequest,
+    ) -> ClientResult<SlackApiAdminTeamsOwnersListResponse> {
+        self.http_session_api
+            .http_post(
+                "admin.teams.owners.list",
+                req,
+                Some(&SLACK_TIER3_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.teams.settings.info
+    ///
+    pub async fn admin_teams_settings_info(
+        &self,
+        req: &SlackApiAdminTeamsSettingsInfoRequest,
+    ) -> ClientResult<SlackApiAdminTeamsSettingsInfoResponse> {
+        self.http_session_api
+            .http_post(
+                "admin.teams.settings.info",
+                req,
+                Some(&SLACK_TIER3_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.teams.settings.setName
+    ///
+    pub async fn admin_teams_settings_set_name(
+        &self,
+        req: &SlackApiAdminTeamsSettingsSetNameRequest,
+    ) -> ClientResult<SlackApiAdminTeamsSettingsSetNameResponse> {
+        self.http_session_api
+            .http_post(
+                "admin.teams.settings.setName",
+                req,
+                Some(&SLACK_TIER2_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.teams.settings.setDescription
+    ///
+    pub async fn admin_teams_settings_set_description(
+        &self,
+        req: &SlackApiAdminTeamsSettingsSetDescriptionRequest,
+    ) -> ClientResult<SlackApiAdminTeamsSettingsSetDescriptionResponse> {
+        self.http_session_api
+            .http_post(
+                "admin.teams.settings.setDescription",
+                req,
+                Some(&SLACK_TIER2_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.teams.settings.setDiscoverability
+    ///
+    pub async fn admin_teams_settings_set_discoverability(
+        &self,
+        req: &SlackApiAdminTeamsSettingsSetDiscoverabilityRequest,
+    ) -> ClientResult<SlackApiAdminTeamsSettingsSetDiscoverabilityResponse> {
+        self.http_session_api
+            .http_post(
+                "admin.teams.settings.setDiscoverability",
+                req,
+                Some(&SLACK_TIER2_METHOD_CONFIG),
+            )
+            .await
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminTeamsCreateRequest {
+    pub team_domain: String,
+    pub team_name: String,
+    pub team_description: Option<String>,
+    pub team_discoverability: Option<SlackAdminTeamDiscoverability>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminTeamsCreateResponse {
+    pub team: SlackTeamId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminTeamsListRequest {
+    pub cursor: Option<SlackCursorId>,
+    pub limit: Option<u16>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminTeamsListResponse {
+    pub teams: Vec<SlackAdminTeam>,
+    pub response_metadata: Option<SlackResponseMetadata>,
+}
+
+impl<SCHC> SlackApiScrollableRequest<SCHC> for SlackApiAdminTeamsListRequest
+where
+    SCHC: SlackClientHttpConnector + Send + Sync + Clone + 'static,
+{
+    type ResponseType = SlackApiAdminTeamsListResponse;
+    type CursorType = SlackCursorId;
+    type ResponseItemType = SlackAdminTeam;
+
+    fn with_new_cursor(&self, new_cursor: Option<&Self::CursorType>) -> Self {
+        self.clone().opt_cursor(new_cursor.cloned())
+    }
+
+    fn scroll<'a, 's>(
+        &'a self,
+        session: &'a SlackClientSession<'s, SCHC>,
+    ) -> BoxFuture<'a, ClientResult<Self::ResponseType>> {
+        async move { session.admin_teams_list(self).await }.boxed()
+    }
+}
+
+impl SlackApiScrollableResponse for SlackApiAdminTeamsListResponse {
+    type CursorType = SlackCursorId;
+    type ResponseItemType = SlackAdminTeam;
+
+    fn next_cursor(&self) -> Option<Self::CursorType> {
+        self.response_metadata
+            .as_ref()
+            .and_then(|rm| rm.next_cursor.as_ref())
+            .cloned()
+    }
+
+    fn scrollable_items<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::ResponseItemType> + 'a> {
+        Box::new(self.teams.iter())
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminTeamsAdminsListRequest {
+    pub team_id: SlackTeamId,
+    pub cursor: Option<SlackCursorId>,
+    pub limit: Option<u16>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminTeamsAdminsListResponse {
+    pub admin_ids: Vec<SlackUserId>,
+    pub response_metadata: Option<SlackResponseMetadata>,
+}
+
+impl<SCHC> SlackApiScrollableRequest<SCHC> for SlackApiAdminTeamsAdminsListRequest
+where
+    SCHC: SlackClientHttpConnector + Send + Sync + Clone + 'static,
+{
+    type ResponseType = SlackApiAdminTeamsAdminsListResponse;
+    type CursorType = SlackCursorId;
+    type ResponseItemType = SlackUserId;
+
+    fn with_new_cursor(&self, new_cursor: Option<&Self::CursorType>) -> Self {
+        self.clone().opt_cursor(new_cursor.cloned())
+    }
+
+    fn scroll<'a, 's>(
+        &'a self,
+        session: &'a SlackClientSession<'s, SCHC>,
+    ) -> BoxFuture<'a, ClientResult<Self::ResponseType>> {
+        async move { session.admin_teams_admins_list(self).await }.boxed()
+    }
+}
+
+impl SlackApiScrollableResponse for SlackApiAdminTeamsAdminsListResponse {
+    type CursorType = SlackCursorId;
+    type ResponseItemType = SlackUserId;
+
+    fn next_cursor(&self) -> Option<Self::CursorType> {
+        self.response_metadata
+            .as_ref()
+            .and_then(|rm| rm.next_cursor.as_ref())
+            .cloned()
+    }
+
+    fn scrollable_items<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::ResponseItemType> + 'a> {
+        Box::new(self.admin_ids.iter())
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminTeamsOwnersListRequest {
+    pub team_id: SlackTeamId,
+    pub cursor: Option<SlackCursorId>,
+    pub limit: Option<u16>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminTeamsOwnersListResponse {
+    pub owner_ids: Vec<SlackUserId>,
+    pub response_metadata: Option<SlackResponseMetadata>,
+}
+
+impl<SCHC> SlackApiScrollableRequest<SCHC> for SlackApiAdminTeamsOwnersListRequest
+where
+    SCHC: SlackClientHttpConnector + Send + Sync + Clone + 'static,
+{
+    type ResponseType = SlackApiAdminTeamsOwnersListResponse;
+    type CursorType = SlackCursorId;
+    type ResponseItemType = SlackUserId;
+
+    fn with_new_cursor(&self, new_cursor: Option<&Self::CursorType>) -> Self {
+        self.clone().opt_cursor(new_cursor.cloned())
+    }
+
+    fn scroll<'a, 's>(
+        &'a self,
+        session: &'a SlackClientSession<'s, SCHC>,
+    ) -> BoxFuture<'a, ClientResult<Self::ResponseType>> {
+        async move { session.admin_teams_owners_list(self).await }.boxed()
+    }
+}
+
+impl SlackApiScrollableResponse for SlackApiAdminTeamsOwnersListResponse {
+    type CursorType = SlackCursorId;
+    type ResponseItemType = SlackUserId;
+
+    fn next_cursor(&self) -> Option<Self::CursorType> {
+        self.response_metadata
+            .as_ref()
+            .and_then(|rm| rm.next_cursor.as_ref())
+            .cloned()
+    }
+
+    fn scrollable_items<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::ResponseItemType> + 'a> {
+        Box::new(self.owner_ids.iter())
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminTeamsSettingsInfoRequest {
+    pub team_id: SlackTeamId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminTeamsSettingsInfoResponse {
+    pub team: SlackAdminTeam,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminTeamsSettingsSetNameRequest {
+    pub team_id: SlackTeamId,
+    pub name: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminTeamsSettingsSetNameResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminTeamsSettingsSetDescriptionRequest {
+    pub team_id: SlackTeamId,
+    pub description: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminTeamsSettingsSetDescriptionResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminTeamsSettingsSetDiscoverabilityRequest {
+    pub team_id: SlackTeamId,
+    pub discoverability: SlackAdminTeamDiscoverability,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminTeamsSettingsSetDiscoverabilityResponse {}
@@ -0,0 +1,216 @@
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::api::files::{to_csv, vec_to_csv};
+use crate::models::*;
+use crate::ratectl::*;
+use crate::*;
+use futures::future::{BoxFuture, FutureExt};
+
+impl<'a, SCHC> SlackClientSession<'a, SCHC>
+where
+    SCHC: SlackClientHttpConnector + Send,
+{
+    ///
+    /// https://api.slack.com/methods/admin.users.invite
+    ///
+    pub async fn admin_users_invite(
+        &self,
+        req: &SlackApiAdminUsersInviteRequest,
+    ) -> ClientResult<SlackApiAdminUsersInviteResponse> {
+        self.http_session_api
+            .http_post("admin.users.invite", req, Some(&SLACK_TIER2_METHOD_CONFIG))
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.users.assign
+    ///
+    pub async fn admin_users_assign(
+        &self,
+        req: &SlackApiAdminUsersAssignRequest,
+    ) -> ClientResult<SlackApiAdminUsersAssignResponse> {
+        self.http_session_api
+            .http_post("admin.users.assign", req, Some(&SLACK_TIER2_METHOD_CONFIG))
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.users.remove
+    ///
+    pub async fn admin_users_remove(
+        &self,
+        req: &SlackApiAdminUsersRemoveRequest,
+    ) -> ClientResult<SlackApiAdminUsersRemoveResponse> {
+        self.http_session_api
+            .http_post("admin.users.remove", req, Some(&SLACK_TIER2_METHOD_CONFIG))
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.users.setAdmin
+    ///
+    pub async fn admin_users_set_admin(
+        &self,
+        req: &SlackApiAdminUsersSetAdminRequest,
+    ) -> ClientResult<SlackApiAdminUsersSetAdminResponse> {
+        self.http_session_api
+            .http_post(
+                "admin.users.setAdmin",
+                req,
+                Some(&SLACK_TIER2_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.users.session.reset
+    ///
+    pub async fn admin_users_session_reset(
+        &self,
+        req: &SlackApiAdminUsersSessionResetRequest,
+    ) -> ClientResult<SlackApiAdminUsersSessionResetResponse> {
+        self.http_session_api
+            .http_post(
+                "admin.users.session.reset",
+                req,
+                Some(&SLACK_TIER2_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/admin.users.list
+    ///
+    pub async fn admin_users_list(
+        &self,
+        req: &SlackApiAdminUsersListRequest,
+    ) -> ClientResult<SlackApiAdminUsersListResponse> {
+        self.http_session_api
+            .http_post("admin.users.list", req, Some(&SLACK_TIER2_METHOD_CONFIG))
+            .await
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminUsersInviteRequest {
+    #[serde(serialize_with = "vec_to_csv")]
+    pub channel_ids: Vec<SlackChannelId>,
+    pub email: EmailAddress,
+    pub team_id: SlackTeamId,
+    pub custom_message: Option<String>,
+    pub email_password_policy_enabled: Option<bool>,
+    pub guest_expiration_ts: Option<SlackDateTime>,
+    pub is_restricted: Option<bool>,
+    pub is_ultra_restricted: Option<bool>,
+    pub real_name: Option<String>,
+    pub resend: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminUsersInviteResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminUsersAssignRequest {
+    pub team_id: SlackTeamId,
+    pub user_id: SlackUserId,
+    #[serde(serialize_with = "to_csv")]
+    pub channel_ids: Option<Vec<SlackChannelId>>,
+    pub is_restricted: Option<bool>,
+    pub is_ultra_restricted: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminUsersAssignResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminUsersRemoveRequest {
+    pub team_id: SlackTeamId,
+    pub user_id: SlackUserId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminUsersRemoveResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminUsersSetAdminRequest {
+    pub team_id: SlackTeamId,
+    pub user_id: SlackUserId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminUsersSetAdminResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminUsersSessionResetRequest {
+    pub user_id: SlackUserId,
+    pub mobile_only: Option<bool>,
+    pub web_only: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminUsersSessionResetResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminUsersListRequest {
+    pub team_id: Option<SlackTeamId>,
+    pub cursor: Option<SlackCursorId>,
+    pub limit: Option<u16>,
+    pub is_active: Option<bool>,
+    pub include_deactivated_user_workspaces: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAdminUsersListResponse {
+    pub users: Vec<SlackAdminUser>,
+    pub response_metadata: Option<SlackResponseMetadata>,
+}
+
+impl<SCHC> SlackApiScrollableRequest<SCHC> for SlackApiAdminUsersListRequest
+where
+    SCHC: SlackClientHttpConnector + Send + Sync + Clone + 'static,
+{
+    type ResponseType = SlackApiAdminUsersListResponse;
+    type CursorType = SlackCursorId;
+    type ResponseItemType = SlackAdminUser;
+
+    fn with_new_cursor(&self, new_cursor: Option<&Self::CursorType>) -> Self {
+        self.clone().opt_cursor(new_cursor.cloned())
+    }
+
+    fn scroll<'a, 's>(
+        &'a self,
+        session: &'a SlackClientSession<'s, SCHC>,
+    ) -> BoxFuture<'a, ClientResult<Self::ResponseType>> {
+        async move { session.admin_users_list(self).await }.boxed()
+    }
+}
+
+impl SlackApiScrollableResponse for SlackApiAdminUsersListResponse {
+    type CursorType = SlackCursorId;
+    type ResponseItemType = SlackAdminUser;
+
+    fn next_cursor(&self) -> Option<Self::CursorType> {
+        self.response_metadata
+            .as_ref()
+            .and_then(|rm| rm.next_cursor.as_ref())
+            .cloned()
+    }
+
+    fn scrollable_items<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::ResponseItemType> + 'a> {
+        Box::new(self.users.iter())
+    }
+}
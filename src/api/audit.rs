@@ -0,0 +1,203 @@
+//!
+//! Support for Slack Audit Logs API (Enterprise Grid)
+//!
+
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::HashMap;
+
+use crate::models::*;
+use crate::*;
+use futures::future::{BoxFuture, FutureExt};
+use rvstruct::ValueStruct;
+
+impl<'a, SCHC> SlackClientSession<'a, SCHC>
+where
+    SCHC: SlackClientHttpConnector + Send,
+{
+    ///
+    /// https://api.slack.com/admins/audit-logs-call#the-logs-endpoint
+    ///
+    pub async fn audit_logs(
+        &self,
+        req: &SlackApiAuditLogsRequest,
+    ) -> ClientResult<SlackApiAuditLogsResponse> {
+        let limit = req.limit.map(|v| v.to_string());
+        let oldest = req
+            .oldest
+            .as_ref()
+            .map(|v| v.value().timestamp().to_string());
+        let latest = req
+            .latest
+            .as_ref()
+            .map(|v| v.value().timestamp().to_string());
+        let action = req.action.as_ref().map(|actions| {
+            actions
+                .iter()
+                .map(|a| a.value().as_str())
+                .collect::<Vec<&str>>()
+                .join(",")
+        });
+
+        let full_uri = SlackClientHttpApiUri::create_url_with_params(
+            self.http_session_api
+                .client
+                .http_api
+                .connector
+                .create_audit_logs_uri_path("logs")?,
+            &vec![
+                ("limit", limit.as_ref()),
+                ("oldest", oldest.as_ref()),
+                ("latest", latest.as_ref()),
+                ("action", action.as_ref()),
+                ("actor", req.actor.as_ref().map(|x| x.value())),
+                ("entity", req.entity.as_ref()),
+                ("cursor", req.cursor.as_ref().map(|x| x.value())),
+            ],
+        )?;
+
+        self.http_session_api
+            .http_request_uri(full_uri, http::Method::GET, None::<&()>, None)
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/admins/audit-logs-call#the-schemas-endpoint
+    ///
+    pub async fn audit_schemas(&self) -> ClientResult<SlackApiAuditSchemasResponse> {
+        let full_uri = self
+            .http_session_api
+            .client
+            .http_api
+            .connector
+            .create_audit_logs_uri_path("schemas")?;
+
+        self.http_session_api
+            .http_request_uri(full_uri, http::Method::GET, None::<&()>, None)
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/admins/audit-logs-call#the-actions-endpoint
+    ///
+    pub async fn audit_actions(&self) -> ClientResult<SlackApiAuditActionsResponse> {
+        let full_uri = self
+            .http_session_api
+            .client
+            .http_api
+            .connector
+            .create_audit_logs_uri_path("actions")?;
+
+        self.http_session_api
+            .http_request_uri(full_uri, http::Method::GET, None::<&()>, None)
+            .await
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAuditLogsRequest {
+    pub limit: Option<u16>,
+    pub oldest: Option<SlackDateTime>,
+    pub latest: Option<SlackDateTime>,
+    pub action: Option<Vec<SlackAuditLogAction>>,
+    pub actor: Option<SlackUserId>,
+    pub entity: Option<String>,
+    pub cursor: Option<SlackCursorId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAuditLogsResponse {
+    pub entries: Vec<SlackAuditLogEntry>,
+    pub response_metadata: Option<SlackResponseMetadata>,
+}
+
+impl<SCHC> SlackApiScrollableRequest<SCHC> for SlackApiAuditLogsRequest
+where
+    SCHC: SlackClientHttpConnector + Send + Sync + Clone + 'static,
+{
+    type ResponseType = SlackApiAuditLogsResponse;
+    type CursorType = SlackCursorId;
+    type ResponseItemType = SlackAuditLogEntry;
+
+    fn with_new_cursor(&self, new_cursor: Option<&Self::CursorType>) -> Self {
+        self.clone().opt_cursor(new_cursor.cloned())
+    }
+
+    fn scroll<'a, 's>(
+        &'a self,
+        session: &'a SlackClientSession<'s, SCHC>,
+    ) -> BoxFuture<'a, ClientResult<Self::ResponseType>> {
+        async move { session.audit_logs(self).await }.boxed()
+    }
+}
+
+impl SlackApiScrollableResponse for SlackApiAuditLogsResponse {
+    type CursorType = SlackCursorId;
+    type ResponseItemType = SlackAuditLogEntry;
+
+    fn next_cursor(&self) -> Option<Self::CursorType> {
+        self.response_metadata
+            .as_ref()
+            .and_then(|rm| rm.next_cursor.as_ref())
+            .cloned()
+    }
+
+    fn scrollable_items<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::ResponseItemType> + 'a> {
+        Box::new(self.entries.iter())
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAuditSchemasResponse {
+    pub schemas: Vec<SlackAuditLogSchema>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiAuditActionsResponse {
+    /// Available actions grouped by the entity type they apply to
+    pub actions: HashMap<String, Vec<SlackAuditLogAction>>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_slack_api_audit_logs_response() {
+        let payload = include_str!("./fixtures/slack_api_audit_logs_response.json");
+        let model: SlackApiAuditLogsResponse = serde_json::from_str(payload).unwrap();
+        assert_eq!(model.entries.len(), 2);
+
+        let login = &model.entries[0];
+        assert_eq!(login.action, "user_login".into());
+        assert_eq!(
+            login.actor.user.as_ref().map(|u| u.id.clone()),
+            Some("W123AB456".into())
+        );
+        assert_eq!(login.entity.entity_type, SlackAuditLogEntityType::User);
+
+        let channel_created = &model.entries[1];
+        assert_eq!(
+            channel_created.entity.entity_type,
+            SlackAuditLogEntityType::Channel
+        );
+        assert_eq!(
+            channel_created
+                .entity
+                .channel
+                .as_ref()
+                .map(|c| c.id.clone()),
+            Some("C0123ABCD".into())
+        );
+
+        assert_eq!(
+            SlackApiScrollableResponse::next_cursor(&model),
+            Some(SlackCursorId::from("dXNlcjpVMEc5V0ZYTlo="))
+        );
+    }
+}
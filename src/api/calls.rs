@@ -0,0 +1,176 @@
+//!
+//! Support for Slack Calls API methods
+//!
+
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use url::Url;
+
+use crate::models::*;
+use crate::ratectl::*;
+use crate::*;
+
+impl<'a, SCHC> SlackClientSession<'a, SCHC>
+where
+    SCHC: SlackClientHttpConnector + Send,
+{
+    ///
+    /// https://api.slack.com/methods/calls.add
+    ///
+    pub async fn calls_add(
+        &self,
+        req: &SlackApiCallsAddRequest,
+    ) -> ClientResult<SlackApiCallsAddResponse> {
+        self.http_session_api
+            .http_post("calls.add", req, Some(&SLACK_TIER2_METHOD_CONFIG))
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/calls.end
+    ///
+    pub async fn calls_end(
+        &self,
+        req: &SlackApiCallsEndRequest,
+    ) -> ClientResult<SlackApiCallsEndResponse> {
+        self.http_session_api
+            .http_post("calls.end", req, Some(&SLACK_TIER2_METHOD_CONFIG))
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/calls.info
+    ///
+    pub async fn calls_info(
+        &self,
+        req: &SlackApiCallsInfoRequest,
+    ) -> ClientResult<SlackApiCallsInfoResponse> {
+        self.http_session_api
+            .http_post("calls.info", req, Some(&SLACK_TIER3_METHOD_CONFIG))
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/calls.update
+    ///
+    pub async fn calls_update(
+        &self,
+        req: &SlackApiCallsUpdateRequest,
+    ) -> ClientResult<SlackApiCallsUpdateResponse> {
+        self.http_session_api
+            .http_post("calls.update", req, Some(&SLACK_TIER2_METHOD_CONFIG))
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/calls.participants.add
+    ///
+    pub async fn calls_participants_add(
+        &self,
+        req: &SlackApiCallsParticipantsAddRequest,
+    ) -> ClientResult<SlackApiCallsParticipantsAddResponse> {
+        self.http_session_api
+            .http_post(
+                "calls.participants.add",
+                req,
+                Some(&SLACK_TIER2_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/calls.participants.remove
+    ///
+    pub async fn calls_participants_remove(
+        &self,
+        req: &SlackApiCallsParticipantsRemoveRequest,
+    ) -> ClientResult<SlackApiCallsParticipantsRemoveResponse> {
+        self.http_session_api
+            .http_post(
+                "calls.participants.remove",
+                req,
+                Some(&SLACK_TIER2_METHOD_CONFIG),
+            )
+            .await
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiCallsAddRequest {
+    pub external_unique_id: String,
+    pub join_url: Url,
+    pub created_by: Option<SlackUserId>,
+    pub date_start: Option<SlackDateTime>,
+    pub desktop_app_join_url: Option<Url>,
+    pub external_display_id: Option<String>,
+    pub title: Option<String>,
+    pub users: Option<Vec<SlackCallParticipant>>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiCallsAddResponse {
+    pub call: SlackCall,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiCallsEndRequest {
+    pub id: SlackCallId,
+    pub duration: Option<u64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiCallsEndResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiCallsInfoRequest {
+    pub id: SlackCallId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiCallsInfoResponse {
+    pub call: SlackCall,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiCallsUpdateRequest {
+    pub id: SlackCallId,
+    pub desktop_app_join_url: Option<Url>,
+    pub join_url: Option<Url>,
+    pub title: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiCallsUpdateResponse {
+    pub call: SlackCall,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiCallsParticipantsAddRequest {
+    pub id: SlackCallId,
+    pub users: Vec<SlackCallParticipant>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiCallsParticipantsAddResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiCallsParticipantsRemoveRequest {
+    pub id: SlackCallId,
+    pub users: Vec<SlackCallParticipant>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiCallsParticipantsRemoveResponse {}
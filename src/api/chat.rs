@@ -76,6 +76,9 @@ where
         &self,
         req: &SlackApiChatPostEphemeralRequest,
     ) -> ClientResult<SlackApiChatPostEphemeralResponse> {
+        self.http_session_api
+            .validate_blocks(|| req.content.validate())?;
+
         self.http_session_api
             .http_post("chat.postEphemeral", req, Some(&SLACK_TIER4_METHOD_CONFIG))
             .await
@@ -88,6 +91,9 @@ where
         &self,
         req: &SlackApiChatPostMessageRequest,
     ) -> ClientResult<SlackApiChatPostMessageResponse> {
+        self.http_session_api
+            .validate_blocks(|| req.content.validate())?;
+
         self.http_session_api
             .http_post(
                 "chat.postMessage",
@@ -104,6 +110,9 @@ where
         &self,
         req: &SlackApiChatScheduleMessageRequest,
     ) -> ClientResult<SlackApiChatScheduleMessageResponse> {
+        self.http_session_api
+            .validate_blocks(|| req.content.validate())?;
+
         self.http_session_api
             .http_post(
                 "chat.scheduleMessage",
@@ -160,11 +169,50 @@ where
         &self,
         req: &SlackApiChatUpdateRequest,
     ) -> ClientResult<SlackApiChatUpdateResponse> {
+        self.http_session_api
+            .validate_blocks(|| req.content.validate())?;
+
         self.http_session_api
             .http_post("chat.update", req, Some(&SLACK_TIER3_METHOD_CONFIG))
             .await
     }
 
+    ///
+    /// https://api.slack.com/methods/chat.startStream
+    ///
+    pub async fn chat_start_stream(
+        &self,
+        req: &SlackApiChatStartStreamRequest,
+    ) -> ClientResult<SlackApiChatStartStreamResponse> {
+        self.http_session_api
+            .http_post("chat.startStream", req, Some(&SLACK_TIER2_METHOD_CONFIG))
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/chat.appendStream
+    ///
+    pub async fn chat_append_stream(
+        &self,
+        req: &SlackApiChatAppendStreamRequest,
+    ) -> ClientResult<SlackApiChatAppendStreamResponse> {
+        self.http_session_api
+            .http_post("chat.appendStream", req, Some(&SLACK_TIER4_METHOD_CONFIG))
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/chat.stopStream
+    ///
+    pub async fn chat_stop_stream(
+        &self,
+        req: &SlackApiChatStopStreamRequest,
+    ) -> ClientResult<SlackApiChatStopStreamResponse> {
+        self.http_session_api
+            .http_post("chat.stopStream", req, Some(&SLACK_TIER2_METHOD_CONFIG))
+            .await
+    }
+
     ///
     /// https://api.slack.com/methods/chat.scheduledMessages.list
     ///
@@ -403,6 +451,56 @@ pub struct SlackApiChatUpdateResponse {
     pub message: SlackUpdatedMessage,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiChatStartStreamRequest {
+    pub channel: SlackChannelId,
+    pub thread_ts: SlackTs,
+    pub markdown_text: Option<String>,
+    pub recipient_team_id: Option<SlackTeamId>,
+    pub recipient_user_id: Option<SlackUserId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiChatStartStreamResponse {
+    pub channel: SlackChannelId,
+    pub ts: SlackTs,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiChatAppendStreamRequest {
+    pub channel: SlackChannelId,
+    pub ts: SlackTs,
+    pub markdown_text: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiChatAppendStreamResponse {
+    pub channel: SlackChannelId,
+    pub ts: SlackTs,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiChatStopStreamRequest {
+    pub channel: SlackChannelId,
+    pub ts: SlackTs,
+    pub markdown_text: Option<String>,
+    pub blocks: Option<Vec<SlackBlock>>,
+    pub metadata: Option<SlackMessageMetadata>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiChatStopStreamResponse {
+    pub channel: SlackChannelId,
+    pub ts: SlackTs,
+    pub message: Option<SlackHistoryMessage>,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
 pub struct SlackApiChatScheduledMessagesListRequest {
@@ -345,12 +345,22 @@ pub struct SlackApiFilesDeleteRequest {
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
 pub struct SlackApiFilesDeleteResponse {}
 
-fn to_csv<S: Serializer>(x: &Option<Vec<SlackChannelId>>, s: S) -> Result<S::Ok, S::Error> {
+pub(crate) fn to_csv<S, T>(x: &Option<Vec<T>>, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: ValueStruct<ValueType = String>,
+{
     match x {
         None => s.serialize_none(),
-        Some(ids) => {
-            let y: Vec<String> = ids.iter().map(|v| v.0.clone()).collect();
-            y.join(",").serialize(s)
-        }
+        Some(ids) => vec_to_csv(ids, s),
     }
 }
+
+pub(crate) fn vec_to_csv<S, T>(x: &[T], s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: ValueStruct<ValueType = String>,
+{
+    let y: Vec<&str> = x.iter().map(|v| v.value().as_str()).collect();
+    y.join(",").serialize(s)
+}
@@ -0,0 +1,71 @@
+//!
+//! Support for Slack Functions API methods (custom workflow steps)
+//!
+
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::HashMap;
+
+use crate::models::*;
+use crate::ratectl::*;
+use crate::*;
+
+impl<'a, SCHC> SlackClientSession<'a, SCHC>
+where
+    SCHC: SlackClientHttpConnector + Send,
+{
+    ///
+    /// https://api.slack.com/methods/functions.completeSuccess
+    ///
+    pub async fn functions_complete_success(
+        &self,
+        req: &SlackApiFunctionsCompleteSuccessRequest,
+    ) -> ClientResult<SlackApiFunctionsCompleteSuccessResponse> {
+        self.http_session_api
+            .http_post(
+                "functions.completeSuccess",
+                req,
+                Some(&SLACK_TIER3_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/functions.completeError
+    ///
+    pub async fn functions_complete_error(
+        &self,
+        req: &SlackApiFunctionsCompleteErrorRequest,
+    ) -> ClientResult<SlackApiFunctionsCompleteErrorResponse> {
+        self.http_session_api
+            .http_post(
+                "functions.completeError",
+                req,
+                Some(&SLACK_TIER3_METHOD_CONFIG),
+            )
+            .await
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiFunctionsCompleteSuccessRequest {
+    pub function_execution_id: SlackFunctionExecutionId,
+    pub outputs: HashMap<String, serde_json::Value>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiFunctionsCompleteSuccessResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiFunctionsCompleteErrorRequest {
+    pub function_execution_id: SlackFunctionExecutionId,
+    pub error: String,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiFunctionsCompleteErrorResponse {}
@@ -0,0 +1,338 @@
+//!
+//! Support for Slack Lists API methods
+//!
+
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::blocks::SlackRichTextBlock;
+use crate::models::*;
+use crate::ratectl::*;
+use crate::*;
+use futures::future::{BoxFuture, FutureExt};
+
+impl<'a, SCHC> SlackClientSession<'a, SCHC>
+where
+    SCHC: SlackClientHttpConnector + Send,
+{
+    ///
+    /// https://api.slack.com/methods/slackLists.create
+    ///
+    pub async fn slack_lists_create(
+        &self,
+        req: &SlackApiSlackListsCreateRequest,
+    ) -> ClientResult<SlackApiSlackListsCreateResponse> {
+        self.http_session_api
+            .http_post("slackLists.create", req, Some(&SLACK_TIER2_METHOD_CONFIG))
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/slackLists.update
+    ///
+    pub async fn slack_lists_update(
+        &self,
+        req: &SlackApiSlackListsUpdateRequest,
+    ) -> ClientResult<SlackApiSlackListsUpdateResponse> {
+        self.http_session_api
+            .http_post("slackLists.update", req, Some(&SLACK_TIER2_METHOD_CONFIG))
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/slackLists.items.create
+    ///
+    pub async fn slack_lists_items_create(
+        &self,
+        req: &SlackApiSlackListsItemsCreateRequest,
+    ) -> ClientResult<SlackApiSlackListsItemsCreateResponse> {
+        self.http_session_api
+            .http_post(
+                "slackLists.items.create",
+                req,
+                Some(&SLACK_TIER3_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/slackLists.items.update
+    ///
+    pub async fn slack_lists_items_update(
+        &self,
+        req: &SlackApiSlackListsItemsUpdateRequest,
+    ) -> ClientResult<SlackApiSlackListsItemsUpdateResponse> {
+        self.http_session_api
+            .http_post(
+                "slackLists.items.update",
+                req,
+                Some(&SLACK_TIER3_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/slackLists.items.delete
+    ///
+    pub async fn slack_lists_items_delete(
+        &self,
+        req: &SlackApiSlackListsItemsDeleteRequest,
+    ) -> ClientResult<SlackApiSlackListsItemsDeleteResponse> {
+        self.http_session_api
+            .http_post(
+                "slackLists.items.delete",
+                req,
+                Some(&SLACK_TIER3_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/slackLists.items.deleteMultiple
+    ///
+    pub async fn slack_lists_items_delete_multiple(
+        &self,
+        req: &SlackApiSlackListsItemsDeleteMultipleRequest,
+    ) -> ClientResult<SlackApiSlackListsItemsDeleteMultipleResponse> {
+        self.http_session_api
+            .http_post(
+                "slackLists.items.deleteMultiple",
+                req,
+                Some(&SLACK_TIER3_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/slackLists.items.info
+    ///
+    pub async fn slack_lists_items_info(
+        &self,
+        req: &SlackApiSlackListsItemsInfoRequest,
+    ) -> ClientResult<SlackApiSlackListsItemsInfoResponse> {
+        self.http_session_api
+            .http_post(
+                "slackLists.items.info",
+                req,
+                Some(&SLACK_TIER3_METHOD_CONFIG),
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/methods/slackLists.items.list
+    ///
+    pub async fn slack_lists_items_list(
+        &self,
+        req: &SlackApiSlackListsItemsListRequest,
+    ) -> ClientResult<SlackApiSlackListsItemsListResponse> {
+        self.http_session_api
+            .http_post(
+                "slackLists.items.list",
+                req,
+                Some(&SLACK_TIER3_METHOD_CONFIG),
+            )
+            .await
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiSlackListsCreateRequest {
+    pub name: String,
+    pub description_blocks: Option<Vec<SlackRichTextBlock>>,
+    pub schema: Option<Vec<SlackListColumn>>,
+    pub copy_from_list_id: Option<SlackListId>,
+    pub include_copied_list_records: Option<bool>,
+    pub todo_mode: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiSlackListsCreateResponse {
+    pub list_id: SlackListId,
+    pub list_metadata: Option<SlackListMetadata>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiSlackListsUpdateRequest {
+    pub id: SlackListId,
+    pub name: Option<String>,
+    pub description_blocks: Option<Vec<SlackRichTextBlock>>,
+    pub todo_mode: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiSlackListsUpdateResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiSlackListsItemsCreateRequest {
+    pub list_id: SlackListId,
+    pub initial_fields: Option<Vec<SlackListFieldValue>>,
+    pub parent_item_id: Option<SlackListItemId>,
+    pub duplicated_item_id: Option<SlackListItemId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiSlackListsItemsCreateResponse {
+    pub item: SlackListItem,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiSlackListsItemsUpdateRequest {
+    pub list_id: SlackListId,
+    pub cells: Vec<SlackListCellUpdate>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiSlackListsItemsUpdateResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiSlackListsItemsDeleteRequest {
+    pub list_id: SlackListId,
+    pub id: SlackListItemId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiSlackListsItemsDeleteResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiSlackListsItemsDeleteMultipleRequest {
+    pub list_id: SlackListId,
+    pub ids: Vec<SlackListItemId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiSlackListsItemsDeleteMultipleResponse {}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiSlackListsItemsInfoRequest {
+    pub list_id: SlackListId,
+    pub id: SlackListItemId,
+    pub include_is_subscribed: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiSlackListsItemsInfoResponse {
+    pub record: SlackListItem,
+    pub subtasks: Option<Vec<SlackListItem>>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiSlackListsItemsListRequest {
+    pub list_id: SlackListId,
+    pub cursor: Option<SlackCursorId>,
+    pub limit: Option<u16>,
+    pub archived: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiSlackListsItemsListResponse {
+    pub items: Vec<SlackListItem>,
+    pub response_metadata: Option<SlackResponseMetadata>,
+}
+
+impl<SCHC> SlackApiScrollableRequest<SCHC> for SlackApiSlackListsItemsListRequest
+where
+    SCHC: SlackClientHttpConnector + Send + Sync + Clone + 'static,
+{
+    type ResponseType = SlackApiSlackListsItemsListResponse;
+    type CursorType = SlackCursorId;
+    type ResponseItemType = SlackListItem;
+
+    fn with_new_cursor(&self, new_cursor: Option<&Self::CursorType>) -> Self {
+        self.clone().opt_cursor(new_cursor.cloned())
+    }
+
+    fn scroll<'a, 's>(
+        &'a self,
+        session: &'a SlackClientSession<'s, SCHC>,
+    ) -> BoxFuture<'a, ClientResult<Self::ResponseType>> {
+        async move { session.slack_lists_items_list(self).await }.boxed()
+    }
+}
+
+impl SlackApiScrollableResponse for SlackApiSlackListsItemsListResponse {
+    type CursorType = SlackCursorId;
+    type ResponseItemType = SlackListItem;
+
+    fn next_cursor(&self) -> Option<Self::CursorType> {
+        self.response_metadata
+            .as_ref()
+            .and_then(|rm| rm.next_cursor.as_ref())
+            .cloned()
+    }
+
+    fn scrollable_items<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::ResponseItemType> + 'a> {
+        Box::new(self.items.iter())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_slack_api_lists_items_list_response() {
+        let payload = include_str!("./fixtures/slack_api_lists_items_list_response.json");
+        let model: SlackApiSlackListsItemsListResponse = serde_json::from_str(payload).unwrap();
+        assert_eq!(model.items.len(), 1);
+        let item = &model.items[0];
+        assert_eq!(item.id, SlackListItemId::from("Rec018ALE9718"));
+        assert_eq!(item.fields.len(), 3);
+        match item.fields[0].cell_value() {
+            Some(SlackListCellValue::RichText(blocks)) => assert_eq!(blocks.len(), 1),
+            other => panic!("unexpected cell value: {:?}", other),
+        }
+        assert_eq!(
+            item.fields[1].cell_value(),
+            Some(SlackListCellValue::User(vec!["U0123456789".into()]))
+        );
+        assert_eq!(
+            item.fields[2].cell_value(),
+            Some(SlackListCellValue::Checkbox(true))
+        );
+        assert_eq!(
+            SlackApiScrollableResponse::next_cursor(&model),
+            Some(SlackCursorId::from("next-cursor"))
+        );
+    }
+
+    #[test]
+    fn test_slack_api_lists_items_update_request() {
+        let req = SlackApiSlackListsItemsUpdateRequest::new(
+            "F0123456789".into(),
+            vec![SlackListCellUpdate::new(
+                "Rec018ALE9718".into(),
+                "Col0123".into(),
+                SlackListCellValue::Select(vec!["OptDone".into()]),
+            )],
+        );
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            serde_json::json!({
+                "list_id": "F0123456789",
+                "cells": [{
+                    "row_id": "Rec018ALE9718",
+                    "column_id": "Col0123",
+                    "select": ["OptDone"]
+                }]
+            })
+        );
+    }
+}
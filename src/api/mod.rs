@@ -1,16 +1,22 @@
 #![allow(clippy::derive_partial_eq_without_eq)]
 
+mod admin;
 mod apps;
 mod assistant;
+mod audit;
 mod auth;
 mod bots;
+mod calls;
 mod chat;
 mod conversations;
 mod emoji;
 mod files;
+mod functions;
+mod lists;
 mod oauth;
 mod pins;
 mod reactions;
+mod scim;
 mod stars;
 mod team;
 mod test;
@@ -19,17 +25,23 @@ mod users;
 mod views;
 mod webhook;
 
+pub use admin::*;
 pub use apps::*;
 pub use assistant::*;
+pub use audit::*;
 pub use auth::*;
 pub use bots::*;
+pub use calls::*;
 pub use chat::*;
 pub use conversations::*;
 pub use emoji::*;
 pub use files::*;
+pub use functions::*;
+pub use lists::*;
 pub use oauth::*;
 pub use pins::*;
 pub use reactions::*;
+pub use scim::*;
 pub use stars::*;
 pub use team::*;
 pub use test::*;
@@ -0,0 +1,399 @@
+//!
+//! Support for Slack SCIM v2 API (user provisioning for Enterprise Grid and Business+ plans)
+//!
+
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::models::*;
+use crate::*;
+use futures::future::{BoxFuture, FutureExt};
+use rvstruct::ValueStruct;
+use url::Url;
+
+impl<'a, SCHC> SlackClientSession<'a, SCHC>
+where
+    SCHC: SlackClientHttpConnector + Send,
+{
+    ///
+    /// https://api.slack.com/admins/scim2#get-users
+    ///
+    pub async fn scim_users_list(
+        &self,
+        req: &SlackApiScimUsersListRequest,
+    ) -> ClientResult<SlackApiScimUsersListResponse> {
+        let start_index = req.start_index.map(|v| v.to_string());
+        let count = req.count.map(|v| v.to_string());
+        let full_uri = SlackClientHttpApiUri::create_url_with_params(
+            self.create_scim_uri("Users")?,
+            &vec![
+                ("startIndex", start_index.as_ref()),
+                ("count", count.as_ref()),
+                ("filter", req.filter.as_ref().map(|x| x.value())),
+            ],
+        )?;
+        self.http_session_api
+            .http_request_uri(full_uri, http::Method::GET, None::<&()>, None)
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/admins/scim2#get-users-id
+    ///
+    pub async fn scim_users_get(&self, id: &SlackUserId) -> ClientResult<SlackScimUser> {
+        self.http_session_api
+            .http_request_uri(
+                self.create_scim_uri(&format!("Users/{}", id.value()))?,
+                http::Method::GET,
+                None::<&()>,
+                None,
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/admins/scim2#post-users
+    ///
+    pub async fn scim_users_create(&self, user: &SlackScimUser) -> ClientResult<SlackScimUser> {
+        self.http_session_api
+            .http_request_uri(
+                self.create_scim_uri("Users")?,
+                http::Method::POST,
+                Some(user),
+                None,
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/admins/scim2#patch-users-id
+    ///
+    pub async fn scim_users_patch(
+        &self,
+        id: &SlackUserId,
+        req: &SlackScimPatchRequest,
+    ) -> ClientResult<SlackScimUser> {
+        self.http_session_api
+            .http_request_uri(
+                self.create_scim_uri(&format!("Users/{}", id.value()))?,
+                http::Method::PATCH,
+                Some(req),
+                None,
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/admins/scim2#put-users-id
+    ///
+    pub async fn scim_users_replace(
+        &self,
+        id: &SlackUserId,
+        user: &SlackScimUser,
+    ) -> ClientResult<SlackScimUser> {
+        self.http_session_api
+            .http_request_uri(
+                self.create_scim_uri(&format!("Users/{}", id.value()))?,
+                http::Method::PUT,
+                Some(user),
+                None,
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/admins/scim2#delete-users-id
+    ///
+    pub async fn scim_users_deactivate(&self, id: &SlackUserId) -> ClientResult<()> {
+        self.http_session_api
+            .http_request_uri::<(), serde::de::IgnoredAny>(
+                self.create_scim_uri(&format!("Users/{}", id.value()))?,
+                http::Method::DELETE,
+                None,
+                None,
+            )
+            .await
+            .map(|_| ())
+    }
+
+    ///
+    /// https://api.slack.com/admins/scim2#get-groups
+    ///
+    pub async fn scim_groups_list(
+        &self,
+        req: &SlackApiScimGroupsListRequest,
+    ) -> ClientResult<SlackApiScimGroupsListResponse> {
+        let start_index = req.start_index.map(|v| v.to_string());
+        let count = req.count.map(|v| v.to_string());
+        let full_uri = SlackClientHttpApiUri::create_url_with_params(
+            self.create_scim_uri("Groups")?,
+            &vec![
+                ("startIndex", start_index.as_ref()),
+                ("count", count.as_ref()),
+                ("filter", req.filter.as_ref().map(|x| x.value())),
+            ],
+        )?;
+        self.http_session_api
+            .http_request_uri(full_uri, http::Method::GET, None::<&()>, None)
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/admins/scim2#get-groups-id
+    ///
+    pub async fn scim_groups_get(
+        &self,
+        id: &SlackEnterpriseSubteamId,
+    ) -> ClientResult<SlackScimGroup> {
+        self.http_session_api
+            .http_request_uri(
+                self.create_scim_uri(&format!("Groups/{}", id.value()))?,
+                http::Method::GET,
+                None::<&()>,
+                None,
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/admins/scim2#post-groups
+    ///
+    pub async fn scim_groups_create(&self, group: &SlackScimGroup) -> ClientResult<SlackScimGroup> {
+        self.http_session_api
+            .http_request_uri(
+                self.create_scim_uri("Groups")?,
+                http::Method::POST,
+                Some(group),
+                None,
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/admins/scim2#patch-groups-id
+    ///
+    pub async fn scim_groups_patch(
+        &self,
+        id: &SlackEnterpriseSubteamId,
+        req: &SlackScimPatchRequest,
+    ) -> ClientResult<()> {
+        self.http_session_api
+            .http_request_uri::<_, serde::de::IgnoredAny>(
+                self.create_scim_uri(&format!("Groups/{}", id.value()))?,
+                http::Method::PATCH,
+                Some(req),
+                None,
+            )
+            .await
+            .map(|_| ())
+    }
+
+    ///
+    /// https://api.slack.com/admins/scim2#put-groups-id
+    ///
+    pub async fn scim_groups_replace(
+        &self,
+        id: &SlackEnterpriseSubteamId,
+        group: &SlackScimGroup,
+    ) -> ClientResult<SlackScimGroup> {
+        self.http_session_api
+            .http_request_uri(
+                self.create_scim_uri(&format!("Groups/{}", id.value()))?,
+                http::Method::PUT,
+                Some(group),
+                None,
+            )
+            .await
+    }
+
+    ///
+    /// https://api.slack.com/admins/scim2#delete-groups-id
+    ///
+    pub async fn scim_groups_delete(&self, id: &SlackEnterpriseSubteamId) -> ClientResult<()> {
+        self.http_session_api
+            .http_request_uri::<(), serde::de::IgnoredAny>(
+                self.create_scim_uri(&format!("Groups/{}", id.value()))?,
+                http::Method::DELETE,
+                None,
+                None,
+            )
+            .await
+            .map(|_| ())
+    }
+
+    fn create_scim_uri(&self, resource_path: &str) -> ClientResult<Url> {
+        self.http_session_api
+            .client
+            .http_api
+            .connector
+            .create_scim_uri_path(resource_path)
+    }
+}
+
+/// SCIM uses 1-based `startIndex` pagination, so the next cursor is the index of the next resource.
+fn scim_next_start_index(
+    start_index: Option<u64>,
+    total_results: u64,
+    resources_count: usize,
+) -> Option<u64> {
+    let next_start_index = start_index.unwrap_or(1) + resources_count as u64;
+    if resources_count > 0 && next_start_index <= total_results {
+        Some(next_start_index)
+    } else {
+        None
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiScimUsersListRequest {
+    pub start_index: Option<u64>,
+    pub count: Option<u16>,
+    pub filter: Option<SlackScimFilter>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackApiScimUsersListResponse {
+    pub schemas: Option<Vec<String>>,
+    pub total_results: u64,
+    pub items_per_page: Option<u64>,
+    pub start_index: Option<u64>,
+    #[serde(rename = "Resources", default)]
+    pub resources: Vec<SlackScimUser>,
+}
+
+impl<SCHC> SlackApiScrollableRequest<SCHC> for SlackApiScimUsersListRequest
+where
+    SCHC: SlackClientHttpConnector + Send + Sync + Clone + 'static,
+{
+    type ResponseType = SlackApiScimUsersListResponse;
+    type CursorType = u64;
+    type ResponseItemType = SlackScimUser;
+
+    fn with_new_cursor(&self, new_cursor: Option<&Self::CursorType>) -> Self {
+        self.clone().opt_start_index(new_cursor.cloned())
+    }
+
+    fn scroll<'a, 's>(
+        &'a self,
+        session: &'a SlackClientSession<'s, SCHC>,
+    ) -> BoxFuture<'a, ClientResult<Self::ResponseType>> {
+        async move { session.scim_users_list(self).await }.boxed()
+    }
+}
+
+impl SlackApiScrollableResponse for SlackApiScimUsersListResponse {
+    type CursorType = u64;
+    type ResponseItemType = SlackScimUser;
+
+    fn next_cursor(&self) -> Option<Self::CursorType> {
+        scim_next_start_index(self.start_index, self.total_results, self.resources.len())
+    }
+
+    fn scrollable_items<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::ResponseItemType> + 'a> {
+        Box::new(self.resources.iter())
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackApiScimGroupsListRequest {
+    pub start_index: Option<u64>,
+    pub count: Option<u16>,
+    pub filter: Option<SlackScimFilter>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackApiScimGroupsListResponse {
+    pub schemas: Option<Vec<String>>,
+    pub total_results: u64,
+    pub items_per_page: Option<u64>,
+    pub start_index: Option<u64>,
+    #[serde(rename = "Resources", default)]
+    pub resources: Vec<SlackScimGroup>,
+}
+
+impl<SCHC> SlackApiScrollableRequest<SCHC> for SlackApiScimGroupsListRequest
+where
+    SCHC: SlackClientHttpConnector + Send + Sync + Clone + 'static,
+{
+    type ResponseType = SlackApiScimGroupsListResponse;
+    type CursorType = u64;
+    type ResponseItemType = SlackScimGroup;
+
+    fn with_new_cursor(&self, new_cursor: Option<&Self::CursorType>) -> Self {
+        self.clone().opt_start_index(new_cursor.cloned())
+    }
+
+    fn scroll<'a, 's>(
+        &'a self,
+        session: &'a SlackClientSession<'s, SCHC>,
+    ) -> BoxFuture<'a, ClientResult<Self::ResponseType>> {
+        async move { session.scim_groups_list(self).await }.boxed()
+    }
+}
+
+impl SlackApiScrollableResponse for SlackApiScimGroupsListResponse {
+    type CursorType = u64;
+    type ResponseItemType = SlackScimGroup;
+
+    fn next_cursor(&self) -> Option<Self::CursorType> {
+        scim_next_start_index(self.start_index, self.total_results, self.resources.len())
+    }
+
+    fn scrollable_items<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::ResponseItemType> + 'a> {
+        Box::new(self.resources.iter())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_slack_api_scim_users_list_response() {
+        let payload = include_str!("./fixtures/slack_api_scim_users_list_response.json");
+        let model: SlackApiScimUsersListResponse = serde_json::from_str(payload).unwrap();
+        assert_eq!(model.resources.len(), 1);
+        let user = &model.resources[0];
+        assert_eq!(user.id, Some("W1234567890".into()));
+        assert_eq!(
+            user.enterprise_user
+                .as_ref()
+                .and_then(|eu| eu.department.clone()),
+            Some("Engineering".to_string())
+        );
+        assert_eq!(SlackApiScrollableResponse::next_cursor(&model), Some(2));
+    }
+
+    #[test]
+    fn test_slack_scim_filter() {
+        let filter = SlackScimFilter::eq("userName", "john \"the\" doe")
+            .and(SlackScimFilter::pr("title").negate());
+        assert_eq!(
+            filter.value(),
+            r#"(userName eq "john \"the\" doe") and (not (title pr))"#
+        );
+    }
+
+    #[test]
+    fn test_slack_scim_patch_request() {
+        let req = SlackScimPatchRequest::from_operations(vec![SlackScimPatchOperation::new(
+            SlackScimPatchOperationType::Replace,
+        )
+        .with_path("active".into())
+        .with_value(serde_json::json!(false))]);
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            serde_json::json!({
+                "schemas": ["urn:ietf:params:scim:api:messages:2.0:PatchOp"],
+                "Operations": [{ "op": "replace", "path": "active", "value": false }]
+            })
+        );
+    }
+}
@@ -23,6 +23,9 @@ where
         &self,
         req: &SlackApiViewsOpenRequest,
     ) -> ClientResult<SlackApiViewsOpenResponse> {
+        self.http_session_api
+            .validate_blocks(|| req.view.validate())?;
+
         self.http_session_api
             .http_post("views.open", req, Some(&SLACK_TIER4_METHOD_CONFIG))
             .await
@@ -35,6 +38,9 @@ where
         &self,
         req: &SlackApiViewsPublishRequest,
     ) -> ClientResult<SlackApiViewsPublishResponse> {
+        self.http_session_api
+            .validate_blocks(|| req.view.validate())?;
+
         self.http_session_api
             .http_post("views.publish", req, Some(&SLACK_TIER4_METHOD_CONFIG))
             .await
@@ -47,6 +53,9 @@ where
         &self,
         req: &SlackApiViewsPushRequest,
     ) -> ClientResult<SlackApiViewsPushResponse> {
+        self.http_session_api
+            .validate_blocks(|| req.view.validate())?;
+
         self.http_session_api
             .http_post("views.push", req, Some(&SLACK_TIER4_METHOD_CONFIG))
             .await
@@ -59,6 +68,9 @@ where
         &self,
         req: &SlackApiViewsUpdateRequest,
     ) -> ClientResult<SlackApiViewsUpdateResponse> {
+        self.http_session_api
+            .validate_blocks(|| req.view.validate())?;
+
         self.http_session_api
             .http_post("views.update", req, Some(&SLACK_TIER4_METHOD_CONFIG))
             .await
@@ -1,18 +1,23 @@
 use crate::hyper_tokio::SlackClientHyperConnector;
-use crate::listener::SlackClientEventsListenerEnvironment;
+use crate::listener::{SlackClientEventsListenerEnvironment, SlackClientEventsUserState};
 use hyper_util::client::legacy::connect::Connect;
 use std::sync::Arc;
 
 mod slack_events_middleware;
 pub use slack_events_middleware::SlackEventsApiMiddleware;
 
-pub struct SlackEventsAxumListener<H: 'static + Send + Sync + Connect + Clone> {
-    pub environment: Arc<SlackClientEventsListenerEnvironment<SlackClientHyperConnector<H>>>,
+pub struct SlackEventsAxumListener<
+    H: 'static + Send + Sync + Connect + Clone,
+    ST: Clone + Send + Sync + 'static = SlackClientEventsUserState,
+> {
+    pub environment: Arc<SlackClientEventsListenerEnvironment<SlackClientHyperConnector<H>, ST>>,
 }
 
-impl<H: 'static + Send + Sync + Connect + Clone> SlackEventsAxumListener<H> {
+impl<H: 'static + Send + Sync + Connect + Clone, ST: Clone + Send + Sync + 'static>
+    SlackEventsAxumListener<H, ST>
+{
     pub fn new(
-        environment: Arc<SlackClientEventsListenerEnvironment<SlackClientHyperConnector<H>>>,
+        environment: Arc<SlackClientEventsListenerEnvironment<SlackClientHyperConnector<H>, ST>>,
     ) -> Self {
         Self { environment }
     }
@@ -21,5 +26,10 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackEventsAxumListener<H> {
 mod slack_oauth_routes;
 pub use slack_oauth_routes::*;
 
+mod slack_events_router;
+
 mod slack_events_extractors;
 pub use slack_events_extractors::SlackEventsExtractors;
+
+mod slack_events_axum_extractors;
+pub use slack_events_axum_extractors::*;
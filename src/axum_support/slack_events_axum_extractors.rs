@@ -0,0 +1,229 @@
+use crate::events::*;
+use crate::listener::SlackEventErrorContext;
+use crate::models::blocks::SlackViewSubmissionResponse;
+use crate::{
+    SlackApiToken, SlackClient, SlackClientHttpConnector, SlackClientSession, SlackTeamId,
+};
+use async_trait::async_trait;
+use axum::extract::FromRequestParts;
+use axum::response::{IntoResponse, Response};
+use http::request::Parts;
+use http::StatusCode;
+use std::sync::Arc;
+use tracing::*;
+
+/// The rejection of the Slack extractors. It means the events layer with the required
+/// extractor (or token resolver) hasn't been configured for the route.
+pub type SlackEventsExtractorRejection = (StatusCode, &'static str);
+
+impl<S: Send + Sync> FromRequestParts<S> for SlackPushEvent {
+    type Rejection = SlackEventsExtractorRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        extracted_from_parts(parts)
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for SlackCommandEvent {
+    type Rejection = SlackEventsExtractorRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        extracted_from_parts(parts)
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for SlackInteractionEvent {
+    type Rejection = SlackEventsExtractorRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        extracted_from_parts(parts)
+    }
+}
+
+/// The request body verified with the Slack signature
+#[derive(Debug, PartialEq, Clone)]
+pub struct SlackVerifiedBody(pub String);
+
+impl<S: Send + Sync> FromRequestParts<S> for SlackVerifiedBody {
+    type Rejection = SlackEventsExtractorRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        extracted_from_parts(parts)
+    }
+}
+
+/// Resolves API tokens for the teams sending events, so handlers can extract `SlackEventSession`
+#[async_trait]
+pub trait SlackTeamTokenResolver {
+    async fn resolve_token(&self, team_id: &SlackTeamId) -> Option<SlackApiToken>;
+}
+
+/// A single token for the apps installed only to one workspace
+#[async_trait]
+impl SlackTeamTokenResolver for SlackApiToken {
+    async fn resolve_token(&self, _team_id: &SlackTeamId) -> Option<SlackApiToken> {
+        Some(self.clone())
+    }
+}
+
+pub(crate) type SlackTeamTokenResolverRef = Arc<dyn SlackTeamTokenResolver + Send + Sync>;
+
+///
+/// The client and the token of the team that sent the event.
+/// It requires the token resolver configured with `with_token_resolver` for the events layer.
+///
+pub struct SlackEventSession<SCHC>
+where
+    SCHC: SlackClientHttpConnector + Send + Sync,
+{
+    pub client: Arc<SlackClient<SCHC>>,
+    pub team_id: SlackTeamId,
+    pub token: SlackApiToken,
+}
+
+impl<SCHC> SlackEventSession<SCHC>
+where
+    SCHC: SlackClientHttpConnector + Send + Sync,
+{
+    pub fn session(&self) -> SlackClientSession<'_, SCHC> {
+        self.client.open_session(&self.token)
+    }
+}
+
+impl<S, SCHC> FromRequestParts<S> for SlackEventSession<SCHC>
+where
+    S: Send + Sync,
+    SCHC: SlackClientHttpConnector + Send + Sync + 'static,
+{
+    type Rejection = SlackEventsExtractorRejection;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let client: Arc<SlackClient<SCHC>> = extracted_from_parts(parts)?;
+        let token_resolver: SlackTeamTokenResolverRef = extracted_from_parts(parts)?;
+
+        let team_id = event_team_id(parts).ok_or((
+            StatusCode::BAD_REQUEST,
+            "Slack event doesn't have a team id",
+        ))?;
+
+        match token_resolver.resolve_token(&team_id).await {
+            Some(token) => Ok(Self {
+                client,
+                team_id,
+                token,
+            }),
+            None => {
+                warn!("No Slack API token found for the team: {}", team_id);
+                Err((
+                    StatusCode::UNAUTHORIZED,
+                    "No Slack API token found for the team",
+                ))
+            }
+        }
+    }
+}
+
+fn extracted_from_parts<T: Clone + Send + Sync + 'static>(
+    parts: &Parts,
+) -> Result<T, SlackEventsExtractorRejection> {
+    parts.extensions.get::<T>().cloned().ok_or_else(|| {
+        error!(
+            "{} wasn't found in the request. Check the events layer configuration",
+            std::any::type_name::<T>()
+        );
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Slack events layer isn't configured for the route",
+        )
+    })
+}
+
+fn event_team_id(parts: &Parts) -> Option<SlackTeamId> {
+    let extensions = &parts.extensions;
+    if let Some(event) = extensions.get::<SlackPushEvent>() {
+        SlackEventErrorContext::from_push_event(event).team_id
+    } else if let Some(event) = extensions.get::<SlackCommandEvent>() {
+        Some(event.team_id.clone())
+    } else if let Some(event) = extensions.get::<SlackInteractionEvent>() {
+        SlackEventErrorContext::from_interaction_event(event).team_id
+    } else {
+        None
+    }
+}
+
+impl IntoResponse for SlackCommandEventResponse {
+    fn into_response(self) -> Response {
+        axum::Json(self).into_response()
+    }
+}
+
+impl IntoResponse for SlackBlockSuggestionResponse {
+    fn into_response(self) -> Response {
+        axum::Json(self).into_response()
+    }
+}
+
+impl IntoResponse for SlackViewSubmissionResponse {
+    fn into_response(self) -> Response {
+        axum::Json(self).into_response()
+    }
+}
+
+impl IntoResponse for SlackInteractionEventResponsePayload {
+    fn into_response(self) -> Response {
+        axum::Json(self).into_response()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SlackMessageContent;
+
+    #[tokio::test]
+    async fn test_command_event_extractor() {
+        let (mut parts, _) = http::Request::new(()).into_parts();
+
+        assert_eq!(
+            SlackCommandEvent::from_request_parts(&mut parts, &())
+                .await
+                .unwrap_err()
+                .0,
+            StatusCode::INTERNAL_SERVER_ERROR
+        );
+
+        let event = SlackCommandEvent::new(
+            "T1".into(),
+            "C1".into(),
+            "U1".into(),
+            "/deploy".into(),
+            url::Url::parse("https://hooks.slack.com/commands/T1/1/xyz")
+                .unwrap()
+                .into(),
+            "trigger".into(),
+        );
+        parts.extensions.insert(event.clone());
+
+        assert_eq!(
+            SlackCommandEvent::from_request_parts(&mut parts, &())
+                .await
+                .unwrap(),
+            event
+        );
+        assert_eq!(event_team_id(&parts), Some("T1".into()));
+    }
+
+    #[test]
+    fn test_command_event_response_into_response() {
+        let response = SlackCommandEventResponse::new(
+            SlackMessageContent::new().with_text("Deploying".into()),
+        )
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(http::header::CONTENT_TYPE).unwrap(),
+            "application/json"
+        );
+    }
+}
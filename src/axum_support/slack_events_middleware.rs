@@ -1,9 +1,19 @@
+use crate::axum_support::slack_events_axum_extractors::{
+    SlackTeamTokenResolver, SlackTeamTokenResolverRef, SlackVerifiedBody,
+};
 use crate::axum_support::slack_events_extractors::{
     SlackEventsEmptyExtractor, SlackEventsExtractor,
 };
 use crate::axum_support::SlackEventsAxumListener;
-use crate::hyper_tokio::SlackClientHyperConnector;
-use crate::listener::SlackClientEventsListenerEnvironment;
+use crate::errors::*;
+use crate::events::{
+    SlackCommandEvent, SlackEventRetryInfo, SlackInteractionEvent, SlackPushEvent,
+};
+use crate::hyper_tokio::{SlackClientHyperConnector, SlackEventsBackgroundExecutor};
+use crate::listener::{
+    SlackClientEventsListenerEnvironment, SlackClientEventsUserState, SlackEventErrorContext,
+    SlackEventKind,
+};
 use crate::prelude::hyper_ext::HyperExtensions;
 use crate::signature_verifier::SlackEventSignatureVerifier;
 use crate::{SlackClientHttpConnector, SlackSigningSecret};
@@ -19,19 +29,23 @@ use tower::{Layer, Service};
 use tracing::*;
 
 #[derive(Clone)]
-pub struct SlackEventsApiMiddlewareService<S, SCHC, SE>
+pub struct SlackEventsApiMiddlewareService<S, SCHC, SE, ST = SlackClientEventsUserState>
 where
     SCHC: SlackClientHttpConnector + Send + Sync,
     SE: SlackEventsExtractor + Clone,
+    ST: Clone + Send + Sync + 'static,
 {
     inner: Option<S>,
-    environment: Arc<SlackClientEventsListenerEnvironment<SCHC>>,
+    environment: Arc<SlackClientEventsListenerEnvironment<SCHC, ST>>,
     signature_verifier: Arc<SlackEventSignatureVerifier>,
     extractor: SE,
+    background_executor: Option<Arc<SlackEventsBackgroundExecutor>>,
+    token_resolver: Option<SlackTeamTokenResolverRef>,
 }
 
-impl<S, SCHC, I, SE> SlackEventsApiMiddlewareService<S, SCHC, SE>
+impl<S, SCHC, I, SE, ST> SlackEventsApiMiddlewareService<S, SCHC, SE, ST>
 where
+    ST: Clone + Send + Sync + 'static,
     S: Service<Request<Body>, Response = I> + Send + 'static + Clone,
     S::Future: Send + 'static,
     S::Error: std::error::Error + 'static + Send + Sync,
@@ -41,21 +55,47 @@ where
 {
     pub fn new(
         service: S,
-        environment: Arc<SlackClientEventsListenerEnvironment<SCHC>>,
+        environment: Arc<SlackClientEventsListenerEnvironment<SCHC, ST>>,
         secret: &SlackSigningSecret,
         extractor: SE,
+        background_executor: Option<Arc<SlackEventsBackgroundExecutor>>,
+        token_resolver: Option<SlackTeamTokenResolverRef>,
     ) -> Self {
         Self {
             inner: Some(service),
             environment,
             signature_verifier: Arc::new(SlackEventSignatureVerifier::new(secret)),
             extractor,
+            background_executor,
+            token_resolver,
+        }
+    }
+}
+
+impl<S, SCHC, SE, ST> SlackEventsApiMiddlewareService<S, SCHC, SE, ST>
+where
+    ST: Clone + Send + Sync + 'static,
+    SCHC: SlackClientHttpConnector + Send + Sync,
+    SE: SlackEventsExtractor + Clone,
+{
+    fn error_context(request: &Request<Body>, raw_payload: &str) -> SlackEventErrorContext {
+        let extensions = request.extensions();
+        if let Some(event) = extensions.get::<SlackPushEvent>() {
+            SlackEventErrorContext::from_push_event(event)
+        } else if let Some(event) = extensions.get::<SlackCommandEvent>() {
+            SlackEventErrorContext::from_command_event(event)
+        } else if let Some(event) = extensions.get::<SlackInteractionEvent>() {
+            SlackEventErrorContext::from_interaction_event(event)
+        } else {
+            SlackEventErrorContext::new(SlackEventKind::Unknown)
         }
+        .with_raw_payload(raw_payload.to_string())
     }
 }
 
-impl<S, SCHC, SE> Service<Request<Body>> for SlackEventsApiMiddlewareService<S, SCHC, SE>
+impl<S, SCHC, SE, ST> Service<Request<Body>> for SlackEventsApiMiddlewareService<S, SCHC, SE, ST>
 where
+    ST: Clone + Send + Sync + 'static,
     S: Service<Request<Body>, Response = Response, Error = Infallible> + Send + 'static + Clone,
     S::Future: Send + 'static,
     SCHC: SlackClientHttpConnector + Send + Sync + 'static,
@@ -80,6 +120,8 @@ where
 
         let signature_verifier = self.signature_verifier.clone();
         let extractor = self.extractor.clone();
+        let background_executor = self.background_executor.clone();
+        let token_resolver = self.token_resolver.clone();
         let request_uri = request.uri().clone();
 
         debug!("Received Slack event: {}", &request_uri);
@@ -92,37 +134,143 @@ where
                     verified_request
                         .extensions_mut()
                         .insert(environment.clone());
+                    verified_request
+                        .extensions_mut()
+                        .insert(environment.client.clone());
+                    verified_request
+                        .extensions_mut()
+                        .insert(SlackVerifiedBody(verified_body.clone()));
+                    if let Some(token_resolver) = token_resolver {
+                        verified_request.extensions_mut().insert(token_resolver);
+                    }
 
                     if let Err(err) =
                         extractor.extract(verified_body.as_str(), verified_request.extensions_mut())
                     {
-                        let http_status = (environment.error_handler)(
-                            err,
-                            environment.client.clone(),
-                            environment.user_state.clone(),
-                        );
+                        let http_status = environment
+                            .handle_error_with_status(
+                                err,
+                                SlackEventErrorContext::new(SlackEventKind::Unknown)
+                                    .with_raw_payload(verified_body),
+                            )
+                            .await;
                         Ok(Response::builder()
                             .status(http_status)
                             .body(Body::default())
                             .unwrap())
                     } else {
+                        let error_context = Self::error_context(&verified_request, &verified_body);
                         *verified_request.body_mut() = Body::from(verified_body);
 
+                        let retry = SlackEventRetryInfo::from_headers(verified_request.headers());
+                        if let Some(SlackPushEvent::EventCallback(event_callback)) =
+                            verified_request
+                                .extensions_mut()
+                                .get_mut::<SlackPushEvent>()
+                        {
+                            event_callback.retry = retry;
+                        }
+                        let event_id = match verified_request.extensions().get::<SlackPushEvent>() {
+                            Some(SlackPushEvent::EventCallback(event_callback)) => {
+                                Some(event_callback.event_id.clone())
+                            }
+                            _ => None,
+                        };
+
+                        if let (
+                            Some(deduplicator),
+                            Some(SlackPushEvent::EventCallback(event_callback)),
+                        ) = (
+                            &environment.deduplicator,
+                            verified_request.extensions().get::<SlackPushEvent>(),
+                        ) {
+                            if deduplicator.is_duplicate_push_event(event_callback).await {
+                                return Ok(Response::builder()
+                                    .status(http::StatusCode::OK)
+                                    .body(Body::default())
+                                    .unwrap());
+                            }
+                        }
+
+                        if let Some(executor) = background_executor.filter(|_| {
+                            !matches!(
+                                verified_request.extensions().get::<SlackPushEvent>(),
+                                Some(SlackPushEvent::UrlVerification(_))
+                            )
+                        }) {
+                            debug!(
+                                "Acknowledged Slack event and calling a route service in background: {}",
+                                &request_uri
+                            );
+                            executor
+                                .spawn(async move {
+                                    match service.call(verified_request).await {
+                                        Ok(response) if response.status().is_success() => {
+                                            debug!(
+                                                "Route service finished successfully for: {}",
+                                                &request_uri
+                                            );
+                                        }
+                                        Ok(response) => {
+                                            if let Some(event_id) = &event_id {
+                                                environment.forget_failed_push_event(event_id).await;
+                                            }
+                                            environment
+                                                .handle_error_with_status(
+                                                    Box::new(SlackClientError::SystemError(
+                                                        SlackClientSystemError::new().with_message(
+                                                            format!(
+                                                            "Route service for {} responded with {}",
+                                                            &request_uri,
+                                                            response.status()
+                                                        ),
+                                                        ),
+                                                    )),
+                                                    error_context,
+                                                )
+                                                .await;
+                                        }
+                                        Err(err) => {
+                                            if let Some(event_id) = &event_id {
+                                                environment.forget_failed_push_event(event_id).await;
+                                            }
+                                            environment
+                                                .handle_error_with_status(
+                                                    Box::new(err),
+                                                    error_context,
+                                                )
+                                                .await;
+                                        }
+                                    }
+                                })
+                                .await;
+                            return Ok(Response::builder()
+                                .status(http::StatusCode::OK)
+                                .body(Body::default())
+                                .unwrap());
+                        }
+
                         debug!("Calling a route service with Slack event: {}", &request_uri);
 
                         match service.call(verified_request).await {
                             Ok(response) => {
-                                debug!("Route service finished successfully for: {}", &request_uri);
+                                if !response.status().is_success() {
+                                    if let Some(event_id) = &event_id {
+                                        environment.forget_failed_push_event(event_id).await;
+                                    }
+                                }
+                                debug!("Route service finished for: {}", &request_uri);
                                 Ok(response)
                             }
                             Err(err) => {
                                 debug!("A route service failed: {} with {}", &request_uri, err);
+                                if let Some(event_id) = &event_id {
+                                    environment.forget_failed_push_event(event_id).await;
+                                }
 
-                                let http_status = (environment.error_handler)(
-                                    Box::new(err),
-                                    environment.client.clone(),
-                                    environment.user_state.clone(),
-                                );
+                                let http_status = environment
+                                    .handle_error_with_status(Box::new(err), error_context)
+                                    .await;
                                 Ok(Response::builder()
                                     .status(http_status)
                                     .body(Body::default())
@@ -133,11 +281,12 @@ where
                 }
                 Err(err) => {
                     debug!("Slack event error: {}", err);
-                    let http_status = (environment.error_handler)(
-                        err,
-                        environment.client.clone(),
-                        environment.user_state.clone(),
-                    );
+                    let http_status = environment
+                        .handle_error_with_status(
+                            err,
+                            SlackEventErrorContext::new(SlackEventKind::Unknown),
+                        )
+                        .await;
                     Ok(Response::builder()
                         .status(http_status)
                         .body(Body::default())
@@ -149,34 +298,43 @@ where
 }
 
 #[derive(Clone)]
-pub struct SlackEventsApiMiddleware<SCHC, S, SE>
+pub struct SlackEventsApiMiddleware<SCHC, S, SE, ST = SlackClientEventsUserState>
 where
     SCHC: SlackClientHttpConnector + Send + Sync + Clone,
     SE: SlackEventsExtractor + Clone,
+    ST: Clone + Send + Sync + 'static,
 {
     slack_signing_secret: SlackSigningSecret,
-    environment: Arc<SlackClientEventsListenerEnvironment<SCHC>>,
+    environment: Arc<SlackClientEventsListenerEnvironment<SCHC, ST>>,
     extractor: SE,
+    background_executor: Option<Arc<SlackEventsBackgroundExecutor>>,
+    token_resolver: Option<SlackTeamTokenResolverRef>,
     _ph_s: PhantomData<S>,
 }
 
-impl<SCHC, S> SlackEventsApiMiddleware<SCHC, S, SlackEventsEmptyExtractor>
+impl<SCHC, S, ST> SlackEventsApiMiddleware<SCHC, S, SlackEventsEmptyExtractor, ST>
 where
     SCHC: SlackClientHttpConnector + Send + Sync + Clone,
+    ST: Clone + Send + Sync + 'static,
 {
     pub fn new(
-        environment: Arc<SlackClientEventsListenerEnvironment<SCHC>>,
+        environment: Arc<SlackClientEventsListenerEnvironment<SCHC, ST>>,
         slack_signing_secret: &SlackSigningSecret,
     ) -> Self {
         Self {
             slack_signing_secret: slack_signing_secret.clone(),
             environment,
             extractor: SlackEventsEmptyExtractor::new(),
+            background_executor: None,
+            token_resolver: None,
             _ph_s: PhantomData,
         }
     }
 
-    pub fn with_event_extractor<SE>(self, extractor: SE) -> SlackEventsApiMiddleware<SCHC, S, SE>
+    pub fn with_event_extractor<SE>(
+        self,
+        extractor: SE,
+    ) -> SlackEventsApiMiddleware<SCHC, S, SE, ST>
     where
         SE: SlackEventsExtractor + Clone,
     {
@@ -184,13 +342,46 @@ where
             slack_signing_secret: self.slack_signing_secret,
             environment: self.environment,
             extractor,
+            background_executor: self.background_executor,
+            token_resolver: self.token_resolver,
             _ph_s: PhantomData,
         }
     }
 }
 
-impl<S, SCHC, I, SE> Layer<S> for SlackEventsApiMiddleware<SCHC, S, SE>
+impl<SCHC, S, SE, ST> SlackEventsApiMiddleware<SCHC, S, SE, ST>
+where
+    ST: Clone + Send + Sync + 'static,
+    SCHC: SlackClientHttpConnector + Send + Sync + Clone,
+    SE: SlackEventsExtractor + Clone,
+{
+    /// Enables the ack-first mode: events are acknowledged with 200 right after the signature
+    /// verification and extraction, and the route service runs on the background executor.
+    /// Route service responses are discarded, so it doesn't suit handlers responding with
+    /// a payload (e.g. commands or view submissions). Failed responses are reported
+    /// to the error handler.
+    pub fn with_ack_first(self, background_executor: Arc<SlackEventsBackgroundExecutor>) -> Self {
+        Self {
+            background_executor: Some(background_executor),
+            ..self
+        }
+    }
+
+    /// Enables extracting `SlackEventSession` in handlers using the tokens from the resolver
+    pub fn with_token_resolver<R>(self, token_resolver: R) -> Self
+    where
+        R: SlackTeamTokenResolver + Send + Sync + 'static,
+    {
+        Self {
+            token_resolver: Some(Arc::new(token_resolver)),
+            ..self
+        }
+    }
+}
+
+impl<S, SCHC, I, SE, ST> Layer<S> for SlackEventsApiMiddleware<SCHC, S, SE, ST>
 where
+    ST: Clone + Send + Sync + 'static,
     S: Service<Request<Body>, Response = I> + Send + 'static + Clone,
     S::Future: Send + 'static,
     S::Error: std::error::Error + 'static + Send + Sync,
@@ -198,23 +389,27 @@ where
     SCHC: SlackClientHttpConnector + Send + Sync + 'static + Clone,
     SE: SlackEventsExtractor + Clone,
 {
-    type Service = SlackEventsApiMiddlewareService<S, SCHC, SE>;
+    type Service = SlackEventsApiMiddlewareService<S, SCHC, SE, ST>;
 
-    fn layer(&self, service: S) -> SlackEventsApiMiddlewareService<S, SCHC, SE> {
+    fn layer(&self, service: S) -> SlackEventsApiMiddlewareService<S, SCHC, SE, ST> {
         SlackEventsApiMiddlewareService::new(
             service,
             self.environment.clone(),
             &self.slack_signing_secret,
             self.extractor.clone(),
+            self.background_executor.clone(),
+            self.token_resolver.clone(),
         )
     }
 }
 
-impl<H: 'static + Send + Sync + Connect + Clone> SlackEventsAxumListener<H> {
+impl<H: 'static + Send + Sync + Connect + Clone, ST: Clone + Send + Sync + 'static>
+    SlackEventsAxumListener<H, ST>
+{
     pub fn events_layer<S, I>(
         &self,
         slack_signing_secret: &SlackSigningSecret,
-    ) -> SlackEventsApiMiddleware<SlackClientHyperConnector<H>, S, SlackEventsEmptyExtractor>
+    ) -> SlackEventsApiMiddleware<SlackClientHyperConnector<H>, S, SlackEventsEmptyExtractor, ST>
     where
         S: Service<Request<Body>, Response = I> + Send + 'static + Clone,
         S::Future: Send + 'static,
@@ -0,0 +1,191 @@
+use crate::axum_support::SlackEventsAxumListener;
+use crate::errors::*;
+use crate::events::*;
+use crate::hyper_tokio::SlackClientHyperConnector;
+use crate::listener::*;
+use crate::models::SlackMessageResponseType;
+use crate::{AnyStdResult, BoxError};
+use axum::body::Body;
+use axum::response::{IntoResponse, Response};
+use futures_util::future::BoxFuture;
+use futures_util::FutureExt;
+use http::{Request, StatusCode};
+use hyper_util::client::legacy::connect::Connect;
+use std::sync::Arc;
+
+type SlackHyperEventsRouter<H, ST> = SlackEventsRouter<SlackClientHyperConnector<H>, ST>;
+
+impl<H: 'static + Send + Sync + Connect + Clone, ST: Clone + Send + Sync + 'static>
+    SlackEventsAxumListener<H, ST>
+{
+    /// Axum handler routing push events with the events router.
+    /// It expects the events layer with the push event extractor.
+    pub fn push_events_router_handler(
+        &self,
+        router: Arc<SlackHyperEventsRouter<H, ST>>,
+    ) -> impl Fn(Request<Body>) -> BoxFuture<'static, Response> + 'static + Send + Clone {
+        let environment = self.environment.clone();
+        move |req| {
+            let environment = environment.clone();
+            let router = router.clone();
+            async move {
+                let (event, raw_payload) = match Self::extracted_event::<SlackPushEvent>(req).await
+                {
+                    Ok(extracted) => extracted,
+                    Err(err) => {
+                        return Self::router_error_response(
+                            &environment,
+                            err,
+                            SlackEventErrorContext::new(SlackEventKind::PushEvent),
+                        )
+                        .await
+                    }
+                };
+                match event {
+                    SlackPushEvent::UrlVerification(url_ver) => url_ver.challenge.into_response(),
+                    other => {
+                        let error_context = SlackEventErrorContext::from_push_event(&other)
+                            .opt_raw_payload(raw_payload);
+                        match router
+                            .route_push_event(
+                                other,
+                                environment.client.clone(),
+                                environment.user_state.clone(),
+                            )
+                            .await
+                        {
+                            Ok(_) => StatusCode::OK.into_response(),
+                            Err(err) => {
+                                Self::router_error_response(&environment, err, error_context).await
+                            }
+                        }
+                    }
+                }
+            }
+            .boxed()
+        }
+    }
+
+    /// Axum handler routing commands with the events router.
+    /// It expects the events layer with the command event extractor.
+    pub fn command_events_router_handler(
+        &self,
+        router: Arc<SlackHyperEventsRouter<H, ST>>,
+    ) -> impl Fn(Request<Body>) -> BoxFuture<'static, Response> + 'static + Send + Clone {
+        let environment = self.environment.clone();
+        move |req| {
+            let environment = environment.clone();
+            let router = router.clone();
+            async move {
+                let (event, raw_payload) =
+                    match Self::extracted_event::<SlackCommandEvent>(req).await {
+                        Ok(extracted) => extracted,
+                        Err(err) => {
+                            return Self::router_error_response(
+                                &environment,
+                                err,
+                                SlackEventErrorContext::new(SlackEventKind::Command),
+                            )
+                            .await
+                        }
+                    };
+                let error_context =
+                    SlackEventErrorContext::from_command_event(&event).opt_raw_payload(raw_payload);
+                match router
+                    .route_command_event(
+                        event,
+                        environment.client.clone(),
+                        environment.user_state.clone(),
+                    )
+                    .await
+                {
+                    Ok(response) => response.into_response(),
+                    Err(err) => Self::router_error_response(&environment, err, error_context).await,
+                }
+            }
+            .boxed()
+        }
+    }
+
+    /// Axum handler routing interactions with the events router.
+    /// It expects the events layer with the interaction event extractor.
+    pub fn interaction_events_router_handler(
+        &self,
+        router: Arc<SlackHyperEventsRouter<H, ST>>,
+    ) -> impl Fn(Request<Body>) -> BoxFuture<'static, Response> + 'static + Send + Clone {
+        let environment = self.environment.clone();
+        move |req| {
+            let environment = environment.clone();
+            let router = router.clone();
+            async move {
+                let (event, raw_payload) =
+                    match Self::extracted_event::<SlackInteractionEvent>(req).await {
+                        Ok(extracted) => extracted,
+                        Err(err) => {
+                            return Self::router_error_response(
+                                &environment,
+                                err,
+                                SlackEventErrorContext::new(SlackEventKind::Interaction),
+                            )
+                            .await
+                        }
+                    };
+                let error_context = SlackEventErrorContext::from_interaction_event(&event)
+                    .opt_raw_payload(raw_payload);
+                match router
+                    .route_interaction_event(
+                        event,
+                        environment.client.clone(),
+                        environment.user_state.clone(),
+                    )
+                    .await
+                {
+                    Ok(Some(response)) => response.into_response(),
+                    Ok(None) => StatusCode::OK.into_response(),
+                    Err(err) => Self::router_error_response(&environment, err, error_context).await,
+                }
+            }
+            .boxed()
+        }
+    }
+
+    async fn extracted_event<E: Clone + Send + Sync + 'static>(
+        req: Request<Body>,
+    ) -> AnyStdResult<(E, Option<String>)> {
+        let event = req.extensions().get::<E>().cloned().ok_or_else(|| {
+            SlackClientError::SystemError(
+                SlackClientSystemError::new().with_message(
+                    "Slack event wasn't found in the request. Check the events layer extractor"
+                        .to_string(),
+                ),
+            )
+        })?;
+        let raw_payload = axum::body::to_bytes(req.into_body(), usize::MAX)
+            .await
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes.to_vec()).ok());
+        Ok((event, raw_payload))
+    }
+
+    async fn router_error_response(
+        environment: &SlackClientEventsListenerEnvironment<SlackClientHyperConnector<H>, ST>,
+        err: BoxError,
+        context: SlackEventErrorContext,
+    ) -> Response {
+        if context.event_kind == SlackEventKind::Command {
+            match environment.handle_error(err, context).await {
+                SlackEventErrorResponse::Status(http_status) => http_status.into_response(),
+                SlackEventErrorResponse::EphemeralReply(content) => {
+                    SlackCommandEventResponse::new(content)
+                        .with_response_type(SlackMessageResponseType::Ephemeral)
+                        .into_response()
+                }
+            }
+        } else {
+            environment
+                .handle_error_with_status(err, context)
+                .await
+                .into_response()
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use crate::axum_support::SlackEventsAxumListener;
 use crate::hyper_tokio::hyper_ext::HyperExtensions;
-use crate::listener::{SlackClientEventsListenerEnvironment, UserCallbackFunction};
+use crate::listener::{
+    SlackClientEventsListenerEnvironment, SlackEventErrorContext, SlackEventKind,
+};
 use crate::prelude::SlackOAuthListenerConfig;
 use axum::body::Body;
 use axum::response::{IntoResponse, Response};
@@ -16,9 +18,11 @@ use tracing::*;
 use crate::api::*;
 use crate::errors::*;
 use crate::hyper_tokio::SlackClientHyperConnector;
-use crate::{AnyStdResult, SlackClientHttpApiUri};
+use crate::{AnyStdResult, SlackClient, SlackClientHttpApiUri};
 
-impl<H: 'static + Send + Sync + Connect + Clone> SlackEventsAxumListener<H> {
+impl<H: 'static + Send + Sync + Connect + Clone, ST: Clone + Send + Sync + 'static>
+    SlackEventsAxumListener<H, ST>
+{
     pub fn slack_oauth_install(
         &self,
         config: &SlackOAuthListenerConfig,
@@ -44,24 +48,33 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackEventsAxumListener<H> {
                 debug!("Redirecting to Slack OAuth authorize: {}", &full_uri);
                 HyperExtensions::hyper_redirect_to(full_uri.as_ref()).map(|r| r.into_response())
             }
-            .map(|res| Self::handle_error(environment, res))
+            .then(|res| Self::handle_error(environment, res))
             .boxed()
         }
     }
 
-    pub fn slack_oauth_callback(
+    pub fn slack_oauth_callback<IF, IFR>(
         &self,
         config: &SlackOAuthListenerConfig,
-        install_service_fn: UserCallbackFunction<
-            SlackOAuthV2AccessTokenResponse,
-            impl Future<Output = ()> + 'static + Send,
-            SlackClientHyperConnector<H>,
-        >,
-    ) -> impl Fn(Request<Body>) -> BoxFuture<'static, Response<Body>> + 'static + Send + Clone {
+        install_service_fn: IF,
+    ) -> impl Fn(Request<Body>) -> BoxFuture<'static, Response<Body>> + 'static + Send + Clone
+    where
+        IF: Fn(
+                SlackOAuthV2AccessTokenResponse,
+                Arc<SlackClient<SlackClientHyperConnector<H>>>,
+                ST,
+            ) -> IFR
+            + 'static
+            + Send
+            + Sync,
+        IFR: Future<Output = ()> + 'static + Send,
+    {
         let environment = self.environment.clone();
         let config = config.clone();
+        let install_service_fn = Arc::new(install_service_fn);
         move |req| {
             let config = config.clone();
+            let install_service_fn = install_service_fn.clone();
             let environment = environment.clone();
             let err_environment = environment.clone();
             let err_config = config.clone();
@@ -110,11 +123,12 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackEventsAxumListener<H> {
                             }
                             Err(err) => {
                                 error!("Slack OAuth error: {}", &err);
-                                (environment.clone().error_handler)(
-                                    Box::new(err),
-                                    environment.client.clone(),
-                                    environment.user_state.clone(),
-                                );
+                                environment
+                                    .handle_error_with_status(
+                                        Box::new(err),
+                                        SlackEventErrorContext::new(SlackEventKind::OAuth),
+                                    )
+                                    .await;
                                 HyperExtensions::hyper_redirect_to(
                                     &config.redirect_error_redirect_url,
                                 )
@@ -124,13 +138,15 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackEventsAxumListener<H> {
                     }
                     (None, Some(err)) => {
                         info!("Slack OAuth cancelled with the reason: {}", err);
-                        (environment.error_handler)(
-                            Box::new(SlackClientError::ApiError(SlackClientApiError::new(
-                                err.clone(),
-                            ))),
-                            environment.client.clone(),
-                            environment.user_state.clone(),
-                        );
+                        environment
+                            .handle_error_with_status(
+                                Box::new(SlackClientError::ApiError(SlackClientApiError::new(
+                                    err.clone(),
+                                ))),
+                                SlackEventErrorContext::new(SlackEventKind::OAuth)
+                                    .opt_raw_payload(req.uri().query().map(|q| q.to_string())),
+                            )
+                            .await;
                         let redirect_error_url = format!(
                             "{}{}",
                             config.redirect_error_redirect_url,
@@ -141,50 +157,63 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackEventsAxumListener<H> {
                     }
                     _ => {
                         error!("Slack OAuth cancelled with unknown reason");
-                        (environment.error_handler)(
-                            Box::new(SlackClientError::SystemError(
-                                SlackClientSystemError::new()
-                                    .with_message("OAuth cancelled with unknown reason".into()),
-                            )),
-                            environment.client.clone(),
-                            environment.user_state.clone(),
-                        );
+                        environment
+                            .handle_error_with_status(
+                                Box::new(SlackClientError::SystemError(
+                                    SlackClientSystemError::new()
+                                        .with_message("OAuth cancelled with unknown reason".into()),
+                                )),
+                                SlackEventErrorContext::new(SlackEventKind::OAuth)
+                                    .opt_raw_payload(req.uri().query().map(|q| q.to_string())),
+                            )
+                            .await;
                         HyperExtensions::hyper_redirect_to(&config.redirect_error_redirect_url)
                             .map(|r| r.into_response())
                     }
                 }
             }
-            .map(move |res| match res {
-                Ok(result) => result,
-                Err(err) => {
-                    error!("Slack OAuth system error: {}", err);
-                    (err_environment.error_handler)(
-                        Box::new(SlackClientError::SystemError(
-                            SlackClientSystemError::new()
-                                .with_message(format!("OAuth cancelled system error: {err}")),
-                        )),
-                        err_environment.client.clone(),
-                        err_environment.user_state.clone(),
-                    );
-                    HyperExtensions::hyper_redirect_to(&err_config.redirect_error_redirect_url)
-                        .unwrap()
-                        .into_response()
+            .then(move |res| async move {
+                match res {
+                    Ok(result) => result,
+                    Err(err) => {
+                        error!("Slack OAuth system error: {}", err);
+                        err_environment
+                            .handle_error_with_status(
+                                Box::new(SlackClientError::SystemError(
+                                    SlackClientSystemError::new().with_message(format!(
+                                        "OAuth cancelled system error: {err}"
+                                    )),
+                                )),
+                                SlackEventErrorContext::new(SlackEventKind::OAuth),
+                            )
+                            .await;
+                        HyperExtensions::hyper_redirect_to(&err_config.redirect_error_redirect_url)
+                            .unwrap()
+                            .into_response()
+                    }
                 }
             })
             .boxed()
         }
     }
 
-    pub fn oauth_router(
+    pub fn oauth_router<IF, IFR>(
         &self,
         root_path: &str,
         config: &SlackOAuthListenerConfig,
-        install_service_fn: UserCallbackFunction<
-            SlackOAuthV2AccessTokenResponse,
-            impl Future<Output = ()> + 'static + Send,
-            SlackClientHyperConnector<H>,
-        >,
-    ) -> axum::routing::Router {
+        install_service_fn: IF,
+    ) -> axum::routing::Router
+    where
+        IF: Fn(
+                SlackOAuthV2AccessTokenResponse,
+                Arc<SlackClient<SlackClientHyperConnector<H>>>,
+                ST,
+            ) -> IFR
+            + 'static
+            + Send
+            + Sync,
+        IFR: Future<Output = ()> + 'static + Send,
+    {
         axum::routing::Router::new()
             .route(
                 config.install_path.replace(root_path, "").as_str(),
@@ -199,17 +228,18 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackEventsAxumListener<H> {
             )
     }
 
-    fn handle_error(
-        environment: Arc<SlackClientEventsListenerEnvironment<SlackClientHyperConnector<H>>>,
+    async fn handle_error(
+        environment: Arc<SlackClientEventsListenerEnvironment<SlackClientHyperConnector<H>, ST>>,
         result: AnyStdResult<Response>,
     ) -> Response {
         match result {
             Err(err) => {
-                let http_status = (environment.error_handler)(
-                    err,
-                    environment.client.clone(),
-                    environment.user_state.clone(),
-                );
+                let http_status = environment
+                    .handle_error_with_status(
+                        err,
+                        SlackEventErrorContext::new(SlackEventKind::OAuth),
+                    )
+                    .await;
                 Response::builder()
                     .status(http_status)
                     .body(Body::empty())
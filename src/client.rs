@@ -4,7 +4,8 @@ use std::sync::Arc;
 
 use crate::token::*;
 
-use crate::errors::SlackClientError;
+use crate::errors::{SlackClientError, SlackClientSystemError};
+use crate::models::blocks::SlackBlocksValidationResult;
 use crate::models::*;
 use crate::multipart_form::FileMultipartData;
 use crate::ratectl::SlackApiMethodRateControlConfig;
@@ -21,6 +22,7 @@ where
     SCHC: SlackClientHttpConnector + Send,
 {
     pub http_api: SlackClientHttpApi<SCHC>,
+    pub blocks_validation: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -162,9 +164,44 @@ pub trait SlackClientHttpConnector {
     where
         RS: for<'de> serde::de::Deserialize<'de> + Send + 'a + Send + 'a;
 
+    /// Sends a JSON request with an arbitrary HTTP method.
+    /// Used by REST-like Slack APIs (such as SCIM) that rely on PUT/PATCH/DELETE.
+    /// The default implementation supports only GET and POST, so connectors should override it.
+    fn http_request_uri<'a, RQ, RS>(
+        &'a self,
+        full_uri: Url,
+        method: http::Method,
+        request_body: Option<&'a RQ>,
+        context: SlackClientApiCallContext<'a>,
+    ) -> BoxFuture<'a, ClientResult<RS>>
+    where
+        RQ: serde::ser::Serialize + Send + Sync,
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
+    {
+        match (method, request_body) {
+            (http::Method::GET, None) => self.http_get_uri(full_uri, context),
+            (http::Method::POST, Some(request_body)) => {
+                self.http_post_uri(full_uri, request_body, context)
+            }
+            (method, _) => std::future::ready(Err(SlackClientError::SystemError(
+                SlackClientSystemError::new()
+                    .with_message(format!("HTTP method {method} isn't supported by connector")),
+            )))
+            .boxed(),
+        }
+    }
+
     fn create_method_uri_path(&self, method_relative_uri: &str) -> ClientResult<Url> {
         Ok(SlackClientHttpApiUri::create_method_uri_path(method_relative_uri).parse()?)
     }
+
+    fn create_audit_logs_uri_path(&self, relative_uri: &str) -> ClientResult<Url> {
+        Ok(SlackClientHttpApiUri::create_audit_logs_uri_path(relative_uri).parse()?)
+    }
+
+    fn create_scim_uri_path(&self, relative_uri: &str) -> ClientResult<Url> {
+        Ok(SlackClientHttpApiUri::create_scim_uri_path(relative_uri).parse()?)
+    }
 }
 
 pub(crate) type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
@@ -177,6 +214,8 @@ pub type AnyStdResult<T> = std::result::Result<T, BoxError>;
 
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub struct SlackEnvelopeMessage {
+    // Not all Slack APIs (such as SCIM or Audit Logs) include `ok` in successful responses
+    #[serde(default)]
     pub ok: bool,
     pub error: Option<String>,
     // Slack may return validation errors in `errors` field with `ok: false` for some methods (such as `apps.manifest.validate`.
@@ -204,11 +243,21 @@ pub struct SlackClientHttpApiUri;
 
 impl SlackClientHttpApiUri {
     pub const SLACK_API_URI_STR: &'static str = "https://slack.com/api";
+    pub const SLACK_SCIM_API_URI_STR: &'static str = "https://api.slack.com/scim/v2";
+    pub const SLACK_AUDIT_LOGS_API_URI_STR: &'static str = "https://api.slack.com/audit/v1";
 
     pub fn create_method_uri_path(method_relative_uri: &str) -> String {
         format!("{}/{}", Self::SLACK_API_URI_STR, method_relative_uri)
     }
 
+    pub fn create_audit_logs_uri_path(relative_uri: &str) -> String {
+        format!("{}/{}", Self::SLACK_AUDIT_LOGS_API_URI_STR, relative_uri)
+    }
+
+    pub fn create_scim_uri_path(relative_uri: &str) -> String {
+        format!("{}/{}", Self::SLACK_SCIM_API_URI_STR, relative_uri)
+    }
+
     pub fn create_url_with_params<'p, PT, TS>(base_url: Url, params: &'p PT) -> ClientResult<Url>
     where
         PT: std::iter::IntoIterator<Item = (&'p str, Option<TS>)> + Clone,
@@ -231,6 +280,17 @@ where
     pub fn new(http_connector: SCHC) -> Self {
         Self {
             http_api: SlackClientHttpApi::new(Arc::new(http_connector)),
+            blocks_validation: false,
+        }
+    }
+
+    /// Validates Block Kit limits before sending messages and views,
+    /// so the violations are reported as `SlackClientError::BlocksValidationError`
+    /// instead of `invalid_blocks` errors from Slack.
+    pub fn with_blocks_validation(self) -> Self {
+        Self {
+            blocks_validation: true,
+            ..self
         }
     }
 
@@ -268,6 +328,17 @@ impl<'a, SCHC> SlackClientHttpSessionApi<'a, SCHC>
 where
     SCHC: SlackClientHttpConnector + Send,
 {
+    pub(crate) fn validate_blocks<F>(&self, validate: F) -> ClientResult<()>
+    where
+        F: FnOnce() -> SlackBlocksValidationResult,
+    {
+        if self.client.blocks_validation {
+            validate().map_err(SlackClientError::BlocksValidationError)
+        } else {
+            Ok(())
+        }
+    }
+
     pub async fn http_get_uri<RS, PT, TS>(
         &self,
         full_uri: Url,
@@ -438,4 +509,29 @@ where
             .http_post_uri_binary(full_uri, content_type, data, context)
             .await
     }
+
+    pub async fn http_request_uri<RQ, RS>(
+        &self,
+        full_uri: Url,
+        method: http::Method,
+        request: Option<&RQ>,
+        rate_control_params: Option<&'a SlackApiMethodRateControlConfig>,
+    ) -> ClientResult<RS>
+    where
+        RQ: serde::ser::Serialize + Send + Sync,
+        RS: for<'de> serde::de::Deserialize<'de> + Send,
+    {
+        let context = SlackClientApiCallContext {
+            rate_control_params,
+            token: Some(self.token),
+            tracing_span: &self.span,
+            is_sensitive_url: false,
+        };
+
+        self.client
+            .http_api
+            .connector
+            .http_request_uri(full_uri, method, request, context)
+            .await
+    }
 }
@@ -1,3 +1,4 @@
+use crate::blocks::SlackBlocksValidationError;
 use rsb_derive::Builder;
 use std::error::Error;
 use std::fmt::Display;
@@ -15,6 +16,7 @@ pub enum SlackClientError {
     ProtocolError(SlackClientProtocolError),
     SocketModeProtocolError(SlackClientSocketModeProtocolError),
     RateLimitError(SlackRateLimitError),
+    BlocksValidationError(SlackBlocksValidationError),
 }
 
 impl SlackClientError {
@@ -36,6 +38,7 @@ impl Display for SlackClientError {
             SlackClientError::SocketModeProtocolError(ref err) => err.fmt(f),
             SlackClientError::SystemError(ref err) => err.fmt(f),
             SlackClientError::RateLimitError(ref err) => err.fmt(f),
+            SlackClientError::BlocksValidationError(ref err) => err.fmt(f),
         }
     }
 }
@@ -51,6 +54,7 @@ impl Error for SlackClientError {
             SlackClientError::SocketModeProtocolError(ref err) => Some(err),
             SlackClientError::SystemError(ref err) => Some(err),
             SlackClientError::RateLimitError(ref err) => Some(err),
+            SlackClientError::BlocksValidationError(ref err) => Some(err),
         }
     }
 }
@@ -0,0 +1,163 @@
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Notify, Semaphore};
+use tracing::*;
+
+///
+/// A bounded pool for the event handlers running after the listeners acknowledged the events
+/// (the ack-first mode). Spawning never waits for a free slot, so the events are acknowledged
+/// right away: when all the slots are busy, the tasks are queued until a slot is free.
+///
+#[derive(Debug)]
+pub struct SlackEventsBackgroundExecutor {
+    permits: Arc<Semaphore>,
+    max_concurrent_tasks: u32,
+    pending_tasks: Arc<AtomicUsize>,
+    tasks_finished: Arc<Notify>,
+    shut_down: AtomicBool,
+}
+
+impl SlackEventsBackgroundExecutor {
+    pub const DEFAULT_MAX_CONCURRENT_TASKS: u32 = 64;
+
+    pub fn new() -> Self {
+        Self::with_max_concurrent_tasks(Self::DEFAULT_MAX_CONCURRENT_TASKS)
+    }
+
+    pub fn with_max_concurrent_tasks(max_concurrent_tasks: u32) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(max_concurrent_tasks as usize)),
+            max_concurrent_tasks,
+            pending_tasks: Arc::new(AtomicUsize::new(0)),
+            tasks_finished: Arc::new(Notify::new()),
+            shut_down: AtomicBool::new(false),
+        }
+    }
+
+    /// Spawns a task, which runs when there is a free slot in the pool.
+    /// After the executor is shut down, the task is executed in place instead.
+    pub async fn spawn<F>(&self, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        if self.shut_down.load(Ordering::SeqCst) {
+            debug!("Slack events background executor is shut down. Running a task in place");
+            return task.await;
+        }
+
+        self.pending_tasks.fetch_add(1, Ordering::SeqCst);
+        let permits = self.permits.clone();
+        let pending_tasks = self.pending_tasks.clone();
+        let tasks_finished = self.tasks_finished.clone();
+        tokio::spawn(async move {
+            // The semaphore is never closed, so acquiring it doesn't fail
+            if let Ok(permit) = permits.acquire_owned().await {
+                task.await;
+                drop(permit);
+            }
+            pending_tasks.fetch_sub(1, Ordering::SeqCst);
+            tasks_finished.notify_waiters();
+        });
+    }
+
+    /// The number of tasks running at the moment
+    pub fn running_tasks(&self) -> u32 {
+        self.max_concurrent_tasks - self.permits.available_permits() as u32
+    }
+
+    /// The number of tasks running or waiting for a free slot
+    pub fn pending_tasks(&self) -> usize {
+        self.pending_tasks.load(Ordering::SeqCst)
+    }
+
+    /// Stops accepting new tasks and waits for the running and queued tasks to finish.
+    /// Returns `false` if the tasks didn't finish in the specified timeout.
+    pub async fn shutdown(&self, timeout: Duration) -> bool {
+        self.shut_down.store(true, Ordering::SeqCst);
+        let drained = async {
+            loop {
+                // Created before the check, so a notification between them isn't missed
+                let finished = self.tasks_finished.notified();
+                if self.pending_tasks() == 0 {
+                    break;
+                }
+                finished.await;
+            }
+        };
+
+        match tokio::time::timeout(timeout, drained).await {
+            Ok(()) => true,
+            Err(_) => {
+                warn!(
+                    "Slack events background executor shutdown timed out with {} pending tasks",
+                    self.pending_tasks()
+                );
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_shutdown_drains_running_tasks() {
+        let executor = SlackEventsBackgroundExecutor::with_max_concurrent_tasks(2);
+        let counter = Arc::new(AtomicUsize::new(0));
+
+        for _ in 0..5 {
+            let counter = counter.clone();
+            executor
+                .spawn(async move {
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    counter.fetch_add(1, Ordering::SeqCst);
+                })
+                .await;
+        }
+
+        assert!(executor.shutdown(Duration::from_secs(5)).await);
+        assert_eq!(counter.load(Ordering::SeqCst), 5);
+        assert_eq!(executor.running_tasks(), 0);
+        assert_eq!(executor.pending_tasks(), 0);
+
+        let counter_after_shutdown = counter.clone();
+        executor
+            .spawn(async move {
+                counter_after_shutdown.fetch_add(1, Ordering::SeqCst);
+            })
+            .await;
+        assert_eq!(counter.load(Ordering::SeqCst), 6);
+    }
+
+    #[tokio::test]
+    async fn test_spawn_doesnt_wait_for_saturated_pool() {
+        let executor = SlackEventsBackgroundExecutor::with_max_concurrent_tasks(1);
+        let gate = Arc::new(Semaphore::new(0));
+        let counter = Arc::new(AtomicUsize::new(0));
+
+        for _ in 0..3 {
+            let gate = gate.clone();
+            let counter = counter.clone();
+            tokio::time::timeout(
+                Duration::from_millis(100),
+                executor.spawn(async move {
+                    gate.acquire().await.unwrap().forget();
+                    counter.fetch_add(1, Ordering::SeqCst);
+                }),
+            )
+            .await
+            .expect("spawn waited for a free slot");
+        }
+        tokio::task::yield_now().await;
+        assert_eq!(executor.running_tasks(), 1);
+        assert_eq!(executor.pending_tasks(), 3);
+
+        gate.add_permits(3);
+        assert!(executor.shutdown(Duration::from_secs(5)).await);
+        assert_eq!(counter.load(Ordering::SeqCst), 3);
+    }
+}
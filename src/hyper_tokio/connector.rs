@@ -33,6 +33,8 @@ pub struct SlackClientHyperConnector<H: Send + Sync + Clone + connect::Connect>
     hyper_connector: Client<H, Body>,
     tokio_rate_controller: Option<Arc<SlackTokioRateController>>,
     slack_api_url: String,
+    slack_audit_logs_api_url: String,
+    slack_scim_api_url: String,
 }
 
 pub type SlackClientHyperHttpsConnector =
@@ -63,6 +65,9 @@ impl<H: 'static + Send + Sync + Clone + connect::Connect> SlackClientHyperConnec
             hyper_connector: Client::builder(TokioExecutor::new()).build::<_, Body>(connector),
             tokio_rate_controller: None,
             slack_api_url: SlackClientHttpApiUri::SLACK_API_URI_STR.to_string(),
+            slack_audit_logs_api_url: SlackClientHttpApiUri::SLACK_AUDIT_LOGS_API_URI_STR
+                .to_string(),
+            slack_scim_api_url: SlackClientHttpApiUri::SLACK_SCIM_API_URI_STR.to_string(),
         }
     }
 
@@ -82,6 +87,20 @@ impl<H: 'static + Send + Sync + Clone + connect::Connect> SlackClientHyperConnec
         }
     }
 
+    pub fn with_slack_audit_logs_api_url(self, slack_audit_logs_api_url: &str) -> Self {
+        Self {
+            slack_audit_logs_api_url: slack_audit_logs_api_url.to_string(),
+            ..self
+        }
+    }
+
+    pub fn with_slack_scim_api_url(self, slack_scim_api_url: &str) -> Self {
+        Self {
+            slack_scim_api_url: slack_scim_api_url.to_string(),
+            ..self
+        }
+    }
+
     async fn send_http_request<'a, RS>(
         &'a self,
         request: Request<Body>,
@@ -140,7 +159,7 @@ impl<H: 'static + Send + Sync + Clone + connect::Connect> SlackClientHyperConnec
         });
 
         match http_status {
-            StatusCode::OK if http_content_is_json => {
+            StatusCode::OK | StatusCode::CREATED if http_content_is_json => {
                 let slack_message: SlackEnvelopeMessage =
                     serde_json::from_str(http_body_str.as_str())
                         .map_err(|err| map_serde_error(err, Some(http_body_str.as_str())))?;
@@ -282,6 +301,14 @@ impl<H: 'static + Send + Sync + Clone + connect::Connect> SlackClientHttpConnect
         Ok(format!("{}/{}", self.slack_api_url, method_relative_uri).parse()?)
     }
 
+    fn create_audit_logs_uri_path(&self, relative_uri: &str) -> ClientResult<Url> {
+        Ok(format!("{}/{}", self.slack_audit_logs_api_url, relative_uri).parse()?)
+    }
+
+    fn create_scim_uri_path(&self, relative_uri: &str) -> ClientResult<Url> {
+        Ok(format!("{}/{}", self.slack_scim_api_url, relative_uri).parse()?)
+    }
+
     fn http_get_uri<'a, RS>(
         &'a self,
         full_uri: Url,
@@ -492,4 +519,52 @@ impl<H: 'static + Send + Sync + Clone + connect::Connect> SlackClientHttpConnect
         }
         .boxed()
     }
+
+    fn http_request_uri<'a, RQ, RS>(
+        &'a self,
+        full_uri: Url,
+        method: hyper::http::Method,
+        request_body: Option<&'a RQ>,
+        context: SlackClientApiCallContext<'a>,
+    ) -> BoxFuture<'a, ClientResult<RS>>
+    where
+        RQ: serde::ser::Serialize + Send + Sync,
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
+    {
+        let context_token = context.token;
+
+        async move {
+            let request_json = request_body
+                .map(|body| serde_json::to_string(body).map_err(|err| map_serde_error(err, None)))
+                .transpose()?;
+
+            let response_body = self
+                .send_rate_controlled_request(
+                    || {
+                        let base_http_request =
+                            HyperExtensions::create_http_request(full_uri.clone(), method.clone());
+
+                        let http_request = HyperExtensions::setup_token_auth_header(
+                            base_http_request,
+                            context_token,
+                        );
+
+                        match request_json.as_ref() {
+                            Some(json) => http_request
+                                .header("content-type", "application/json; charset=utf-8")
+                                .body(Full::new(json.clone().into()).boxed()),
+                            None => http_request.body(Empty::new().boxed()),
+                        }
+                        .map_err(|e| e.into())
+                    },
+                    context,
+                    None,
+                    0,
+                )
+                .await?;
+
+            Ok(response_body)
+        }
+        .boxed()
+    }
 }
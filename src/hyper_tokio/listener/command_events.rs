@@ -17,34 +17,39 @@ use std::collections::HashMap;
 use std::future::Future;
 use std::sync::Arc;
 
-impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<H> {
-    pub fn command_events_service_fn<'a, D, F, R>(
+impl<H: 'static + Send + Sync + Connect + Clone, S: Clone + Send + Sync + 'static>
+    SlackClientEventsHyperListener<H, S>
+{
+    pub fn command_events_service_fn<'a, D, F, R, CF, CFR>(
         &self,
         config: Arc<SlackCommandEventsListenerConfig>,
-        command_service_fn: UserCallbackFunction<
-            SlackCommandEvent,
-            impl Future<Output = UserCallbackResult<R>> + 'static + Send,
-            SlackClientHyperConnector<H>,
-        >,
+        command_service_fn: CF,
     ) -> impl Fn(Request<Incoming>, D) -> BoxFuture<'a, AnyStdResult<Response<Body>>> + 'a + Send + Clone
     where
         D: Fn(Request<Incoming>) -> F + 'a + Send + Sync + Clone,
         F: Future<Output = AnyStdResult<Response<Body>>> + 'a + Send,
+        CF: Fn(SlackCommandEvent, Arc<SlackClient<SlackClientHyperConnector<H>>>, S) -> CFR
+            + 'static
+            + Send
+            + Sync,
+        CFR: Future<Output = UserCallbackResult<R>> + 'static + Send,
         R: Into<Option<SlackCommandEventResponse>>,
     {
         let signature_verifier: Arc<SlackEventSignatureVerifier> = Arc::new(
             SlackEventSignatureVerifier::new(&config.events_signing_secret),
         );
         let client = self.environment.client.clone();
-        let error_handler = self.environment.error_handler.clone();
+        let environment = self.environment.clone();
         let user_state_storage = self.environment.user_state.clone();
+        let command_service_fn = Arc::new(command_service_fn);
 
         move |req: Request<Incoming>, chain: D| {
             let cfg = config.clone();
             let sign_verifier = signature_verifier.clone();
             let sc = client.clone();
-            let thread_error_handler = error_handler.clone();
+            let thread_environment = environment.clone();
             let thread_user_state_storage = user_state_storage.clone();
+            let thread_command_service_fn = command_service_fn.clone();
 
             async move {
                 match (req.method(), req.uri().path()) {
@@ -56,7 +61,7 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<
                                         .into_owned()
                                         .collect();
 
-                                match (
+                                let event = match (
                                     body_params.get("team_id"),
                                     body_params.get("channel_id"),
                                     body_params.get("user_id"),
@@ -88,57 +93,78 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<
                                         ),
                                     ))
                                     .map_err(|e| e.into()),
-                                }
+                                };
+                                Ok::<_, url::ParseError>((body, event))
                             })
-                            .and_then(|event| async move {
+                            .and_then(|parsed| async move {
+                                let (body, event) = parsed?;
                                 match event {
                                     Ok(command_event) => {
-                                        match command_service_fn(
+                                        let error_context =
+                                            SlackEventErrorContext::from_command_event(
+                                                &command_event,
+                                            )
+                                            .with_raw_payload(body);
+                                        let command_result = thread_command_service_fn(
                                             command_event,
                                             sc.clone(),
                                             thread_user_state_storage.clone(),
                                         )
                                         .await
-                                        {
-                                            Ok(cresp) => match cresp.into() {
-                                                Some(cresp) => Response::builder()
-                                                    .status(StatusCode::OK)
-                                                    .header(
-                                                        "content-type",
-                                                        "application/json; charset=utf-8",
+                                        .map(|cresp| cresp.into());
+                                        let command_response = match command_result {
+                                            Ok(cresp) => cresp,
+                                            Err(err) => match thread_environment
+                                                .handle_error(err, error_context)
+                                                .await
+                                            {
+                                                SlackEventErrorResponse::Status(status_code) => {
+                                                    return Response::builder()
+                                                        .status(status_code)
+                                                        .body(Empty::new().boxed())
+                                                        .map_err(|e| e.into());
+                                                }
+                                                SlackEventErrorResponse::EphemeralReply(
+                                                    content,
+                                                ) => Some(
+                                                    SlackCommandEventResponse::new(content)
+                                                        .with_response_type(
+                                                            SlackMessageResponseType::Ephemeral,
+                                                        ),
+                                                ),
+                                            },
+                                        };
+                                        match command_response {
+                                            Some(cresp) => Response::builder()
+                                                .status(StatusCode::OK)
+                                                .header(
+                                                    "content-type",
+                                                    "application/json; charset=utf-8",
+                                                )
+                                                .body(
+                                                    Full::new(
+                                                        serde_json::to_string(&cresp)
+                                                            .unwrap()
+                                                            .into(),
                                                     )
-                                                    .body(
-                                                        Full::new(
-                                                            serde_json::to_string(&cresp)
-                                                                .unwrap()
-                                                                .into(),
-                                                        )
-                                                        .boxed(),
-                                                    ),
-                                                None => Response::builder()
-                                                    .status(StatusCode::OK)
-                                                    .body(Empty::new().boxed()),
-                                            }
-                                            .map_err(|e| e.into()),
-                                            Err(err) => {
-                                                let status_code = thread_error_handler(
-                                                    err,
-                                                    sc.clone(),
-                                                    thread_user_state_storage.clone(),
-                                                );
-                                                Response::builder()
-                                                    .status(status_code)
-                                                    .body(Empty::new().boxed())
-                                                    .map_err(|e| e.into())
-                                            }
+                                                    .boxed(),
+                                                ),
+                                            None => Response::builder()
+                                                .status(StatusCode::OK)
+                                                .body(Empty::new().boxed()),
                                         }
+                                        .map_err(|e| e.into())
                                     }
                                     Err(command_event_err) => {
-                                        let status_code = thread_error_handler(
-                                            command_event_err,
-                                            sc,
-                                            thread_user_state_storage,
-                                        );
+                                        let status_code = thread_environment
+                                            .handle_error_with_status(
+                                                command_event_err,
+                                                SlackEventErrorContext::new(
+                                                    SlackEventKind::Command,
+                                                )
+                                                .with_raw_payload(body),
+                                            )
+                                            .await;
                                         Response::builder()
                                             .status(status_code)
                                             .body(Empty::new().boxed())
@@ -16,34 +16,39 @@ use std::collections::HashMap;
 use std::future::Future;
 use std::sync::Arc;
 
-impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<H> {
-    pub fn interaction_events_service_fn<'a, D, F, R>(
+impl<H: 'static + Send + Sync + Connect + Clone, S: Clone + Send + Sync + 'static>
+    SlackClientEventsHyperListener<H, S>
+{
+    pub fn interaction_events_service_fn<'a, D, F, R, IF, IFR>(
         &self,
         config: Arc<SlackInteractionEventsListenerConfig>,
-        interaction_service_fn: UserCallbackFunction<
-            SlackInteractionEvent,
-            impl Future<Output = UserCallbackResult<R>> + 'static + Send,
-            SlackClientHyperConnector<H>,
-        >,
+        interaction_service_fn: IF,
     ) -> impl Fn(Request<Incoming>, D) -> BoxFuture<'a, AnyStdResult<Response<Body>>> + 'a + Send + Clone
     where
         D: Fn(Request<Incoming>) -> F + 'a + Send + Sync + Clone,
         F: Future<Output = AnyStdResult<Response<Body>>> + 'a + Send,
+        IF: Fn(SlackInteractionEvent, Arc<SlackClient<SlackClientHyperConnector<H>>>, S) -> IFR
+            + 'static
+            + Send
+            + Sync,
+        IFR: Future<Output = UserCallbackResult<R>> + 'static + Send,
         R: SlackInteractionEventResponse,
     {
         let signature_verifier: Arc<SlackEventSignatureVerifier> = Arc::new(
             SlackEventSignatureVerifier::new(&config.events_signing_secret),
         );
         let client = self.environment.client.clone();
-        let error_handler = self.environment.error_handler.clone();
+        let environment = self.environment.clone();
         let user_state_storage = self.environment.user_state.clone();
+        let interaction_service_fn = Arc::new(interaction_service_fn);
 
         move |req: Request<Incoming>, chain: D| {
             let cfg = config.clone();
             let sign_verifier = signature_verifier.clone();
             let sc = client.clone();
-            let thread_error_handler = error_handler.clone();
+            let thread_environment = environment.clone();
             let thread_user_state_storage = user_state_storage.clone();
+            let thread_interaction_service_fn = interaction_service_fn.clone();
 
             async move {
                 match (req.method(), req.uri().path()) {
@@ -64,20 +69,27 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<
                                     ))
                                     .map_err(|e| e.into());
 
-                                payload.and_then(|payload_value| {
+                                let event = payload.and_then(|payload_value| {
                                     serde_json::from_str::<SlackInteractionEvent>(payload_value)
                                         .map_err(|e| SlackClientProtocolError::new(e).with_json_body(payload_value.clone()).into())
-                                })
+                                });
+                                (body_params.get("payload").cloned(), event)
                             })
-                            .and_then(|event| async move {
+                            .and_then(|(payload, event)| async move {
                                 match event {
                                     Ok(view_submission_event@SlackInteractionEvent::ViewSubmission(_)) => {
-                                        match interaction_service_fn(view_submission_event.clone(), sc.clone(), thread_user_state_storage.clone()).await {
-                                            Ok(response) => {
-                                                response.to_http_response(&view_submission_event)
-                                            }
+                                        let interaction_result = thread_interaction_service_fn(view_submission_event.clone(), sc.clone(), thread_user_state_storage.clone())
+                                            .await
+                                            .map(|response| response.to_http_response(&view_submission_event));
+                                        match interaction_result {
+                                            Ok(response) => response,
                                             Err(err) => {
-                                                let status_code = thread_error_handler(err, sc, thread_user_state_storage);
+                                                let status_code = thread_environment
+                                                    .handle_error_with_status(
+                                                        err,
+                                                        SlackEventErrorContext::from_interaction_event(&view_submission_event).opt_raw_payload(payload),
+                                                    )
+                                                    .await;
                                                 Response::builder()
                                                     .status(status_code)
                                                     .body(Empty::new().boxed())
@@ -87,12 +99,18 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<
 
                                     }
                                     Ok(block_suggestion_event@SlackInteractionEvent::BlockSuggestion(_)) => {
-                                        match interaction_service_fn(block_suggestion_event.clone(), sc.clone(), thread_user_state_storage.clone()).await {
-                                            Ok(response) => {
-                                                response.to_http_response(&block_suggestion_event)
-                                            }
+                                        let interaction_result = thread_interaction_service_fn(block_suggestion_event.clone(), sc.clone(), thread_user_state_storage.clone())
+                                            .await
+                                            .map(|response| response.to_http_response(&block_suggestion_event));
+                                        match interaction_result {
+                                            Ok(response) => response,
                                             Err(err) => {
-                                                let status_code = thread_error_handler(err, sc, thread_user_state_storage);
+                                                let status_code = thread_environment
+                                                    .handle_error_with_status(
+                                                        err,
+                                                        SlackEventErrorContext::from_interaction_event(&block_suggestion_event).opt_raw_payload(payload),
+                                                    )
+                                                    .await;
                                                 Response::builder()
                                                     .status(status_code)
                                                     .body(Empty::new().boxed())
@@ -102,10 +120,18 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<
 
                                     }
                                     Ok(interaction_event) => {
-                                        match interaction_service_fn(interaction_event.clone(), sc.clone(), thread_user_state_storage.clone()).await {
-                                            Ok(response) => response.to_http_response(&interaction_event),
+                                        let interaction_result = thread_interaction_service_fn(interaction_event.clone(), sc.clone(), thread_user_state_storage.clone())
+                                            .await
+                                            .map(|response| response.to_http_response(&interaction_event));
+                                        match interaction_result {
+                                            Ok(response) => response,
                                             Err(err) => {
-                                                let status_code = thread_error_handler(err, sc, thread_user_state_storage);
+                                                let status_code = thread_environment
+                                                    .handle_error_with_status(
+                                                        err,
+                                                        SlackEventErrorContext::from_interaction_event(&interaction_event).opt_raw_payload(payload),
+                                                    )
+                                                    .await;
                                                 Response::builder()
                                                     .status(status_code)
                                                     .body(Empty::new().boxed())
@@ -114,7 +140,12 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<
                                         }
                                     }
                                     Err(event_err) => {
-                                        let status_code = thread_error_handler(event_err, sc, thread_user_state_storage);
+                                        let status_code = thread_environment
+                                            .handle_error_with_status(
+                                                event_err,
+                                                SlackEventErrorContext::new(SlackEventKind::Interaction).opt_raw_payload(payload),
+                                            )
+                                            .await;
                                         Response::builder()
                                             .status(status_code)
                                             .body(Empty::new().boxed())
@@ -170,3 +201,22 @@ impl SlackInteractionEventResponse for SlackBlockSuggestionResponse {
             .body(Full::new(json_str.into()).boxed())?)
     }
 }
+
+impl SlackInteractionEventResponse for SlackInteractionEventResponsePayload {
+    fn to_http_response(&self, _event: &SlackInteractionEvent) -> AnyStdResult<Response<Body>> {
+        let json_str = serde_json::to_string(&self)?;
+        Ok(Response::builder()
+            .status(StatusCode::OK)
+            .header("content-type", "application/json; charset=utf-8")
+            .body(Full::new(json_str.into()).boxed())?)
+    }
+}
+
+impl<T: SlackInteractionEventResponse> SlackInteractionEventResponse for Option<T> {
+    fn to_http_response(&self, event: &SlackInteractionEvent) -> AnyStdResult<Response<Body>> {
+        match self {
+            Some(response) => response.to_http_response(event),
+            None => ().to_http_response(event),
+        }
+    }
+}
@@ -7,8 +7,8 @@ use hyper::{Request, Response};
 use hyper_util::client::legacy::connect::Connect;
 
 use crate::hyper_tokio::connector::SlackClientHyperConnector;
-use crate::hyper_tokio::Body;
-use crate::listener::SlackClientEventsListenerEnvironment;
+use crate::hyper_tokio::{Body, SlackEventsBackgroundExecutor};
+use crate::listener::{SlackClientEventsListenerEnvironment, SlackClientEventsUserState};
 use crate::AnyStdResult;
 
 pub use command_events::*;
@@ -21,15 +21,34 @@ mod interaction_events;
 mod oauth;
 mod push_events;
 
-pub struct SlackClientEventsHyperListener<H: 'static + Send + Sync + Connect + Clone> {
-    pub environment: Arc<SlackClientEventsListenerEnvironment<SlackClientHyperConnector<H>>>,
+pub struct SlackClientEventsHyperListener<
+    H: 'static + Send + Sync + Connect + Clone,
+    S: Clone + Send + Sync + 'static = SlackClientEventsUserState,
+> {
+    pub environment: Arc<SlackClientEventsListenerEnvironment<SlackClientHyperConnector<H>, S>>,
+    pub background_executor: Option<Arc<SlackEventsBackgroundExecutor>>,
 }
 
-impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<H> {
+impl<H: 'static + Send + Sync + Connect + Clone, S: Clone + Send + Sync + 'static>
+    SlackClientEventsHyperListener<H, S>
+{
     pub fn new(
-        environment: Arc<SlackClientEventsListenerEnvironment<SlackClientHyperConnector<H>>>,
+        environment: Arc<SlackClientEventsListenerEnvironment<SlackClientHyperConnector<H>, S>>,
     ) -> Self {
-        Self { environment }
+        Self {
+            environment,
+            background_executor: None,
+        }
+    }
+
+    /// Enables the ack-first mode for push events: the events are acknowledged right after
+    /// the signature verification and parsing, and the callbacks run on the background executor.
+    /// Callback errors are reported to the error handler.
+    pub fn with_ack_first(self, background_executor: Arc<SlackEventsBackgroundExecutor>) -> Self {
+        Self {
+            background_executor: Some(background_executor),
+            ..self
+        }
     }
 }
 
@@ -16,7 +16,9 @@ use std::future::Future;
 use std::sync::Arc;
 use tracing::*;
 
-impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<H> {
+impl<H: 'static + Send + Sync + Connect + Clone, S: Clone + Send + Sync + 'static>
+    SlackClientEventsHyperListener<H, S>
+{
     pub(crate) async fn slack_oauth_install_service(
         _: Request<Incoming>,
         config: &SlackOAuthListenerConfig,
@@ -37,18 +39,25 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<
         HyperExtensions::hyper_redirect_to(full_uri.as_ref())
     }
 
-    pub(crate) async fn slack_oauth_callback_service(
+    pub(crate) async fn slack_oauth_callback_service<IF, IFR>(
         req: Request<Incoming>,
         config: &SlackOAuthListenerConfig,
-        client: Arc<SlackClient<SlackClientHyperConnector<H>>>,
-        user_state_storage: SlackClientEventsUserState,
-        install_service_fn: UserCallbackFunction<
-            SlackOAuthV2AccessTokenResponse,
-            impl Future<Output = ()> + 'static + Send,
-            SlackClientHyperConnector<H>,
-        >,
-        error_handler: BoxedErrorHandler<SlackClientHyperConnector<H>>,
-    ) -> AnyStdResult<Response<Body>> {
+        environment: Arc<SlackClientEventsListenerEnvironment<SlackClientHyperConnector<H>, S>>,
+        install_service_fn: Arc<IF>,
+    ) -> AnyStdResult<Response<Body>>
+    where
+        IF: Fn(
+                SlackOAuthV2AccessTokenResponse,
+                Arc<SlackClient<SlackClientHyperConnector<H>>>,
+                S,
+            ) -> IFR
+            + 'static
+            + Send
+            + Sync,
+        IFR: Future<Output = ()> + 'static + Send,
+    {
+        let client = environment.client.clone();
+        let user_state_storage = environment.user_state.clone();
         let params = HyperExtensions::parse_query_params(req.uri());
         debug!("Received Slack OAuth callback: {:?}", &params);
 
@@ -83,20 +92,27 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<
                     }
                     Err(err) => {
                         error!("Slack OAuth error: {}", &err);
-                        error_handler(Box::new(err), client, user_state_storage);
+                        environment
+                            .handle_error_with_status(
+                                Box::new(err),
+                                SlackEventErrorContext::new(SlackEventKind::OAuth),
+                            )
+                            .await;
                         HyperExtensions::hyper_redirect_to(&config.redirect_error_redirect_url)
                     }
                 }
             }
             (None, Some(err)) => {
                 info!("Slack OAuth cancelled with the reason: {}", err);
-                error_handler(
-                    Box::new(SlackClientError::ApiError(SlackClientApiError::new(
-                        err.clone(),
-                    ))),
-                    client,
-                    user_state_storage,
-                );
+                environment
+                    .handle_error_with_status(
+                        Box::new(SlackClientError::ApiError(SlackClientApiError::new(
+                            err.clone(),
+                        ))),
+                        SlackEventErrorContext::new(SlackEventKind::OAuth)
+                            .opt_raw_payload(req.uri().query().map(|q| q.to_string())),
+                    )
+                    .await;
                 let redirect_error_url = format!(
                     "{}{}",
                     config.redirect_error_redirect_url,
@@ -106,41 +122,46 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<
             }
             _ => {
                 error!("Slack OAuth cancelled with unknown reason");
-                error_handler(
-                    Box::new(SlackClientError::SystemError(
-                        SlackClientSystemError::new()
-                            .with_message("OAuth cancelled with unknown reason".into()),
-                    )),
-                    client,
-                    user_state_storage,
-                );
+                environment
+                    .handle_error_with_status(
+                        Box::new(SlackClientError::SystemError(
+                            SlackClientSystemError::new()
+                                .with_message("OAuth cancelled with unknown reason".into()),
+                        )),
+                        SlackEventErrorContext::new(SlackEventKind::OAuth)
+                            .opt_raw_payload(req.uri().query().map(|q| q.to_string())),
+                    )
+                    .await;
                 HyperExtensions::hyper_redirect_to(&config.redirect_error_redirect_url)
             }
         }
     }
 
-    pub fn oauth_service_fn<'a, D, F>(
+    pub fn oauth_service_fn<'a, D, F, IF, IFR>(
         &self,
         config: Arc<SlackOAuthListenerConfig>,
-        install_service_fn: UserCallbackFunction<
-            SlackOAuthV2AccessTokenResponse,
-            impl Future<Output = ()> + 'static + Send,
-            SlackClientHyperConnector<H>,
-        >,
+        install_service_fn: IF,
     ) -> impl Fn(Request<Incoming>, D) -> BoxFuture<'a, AnyStdResult<Response<Body>>> + 'a + Send + Clone
     where
         D: Fn(Request<Incoming>) -> F + 'a + Send + Sync + Clone,
         F: Future<Output = AnyStdResult<Response<Body>>> + 'a + Send,
+        IF: Fn(
+                SlackOAuthV2AccessTokenResponse,
+                Arc<SlackClient<SlackClientHyperConnector<H>>>,
+                S,
+            ) -> IFR
+            + 'static
+            + Send
+            + Sync,
+        IFR: Future<Output = ()> + 'static + Send,
     {
-        let client = self.environment.client.clone();
-        let listener_error_handler = self.environment.error_handler.clone();
-        let user_state_storage = self.environment.user_state.clone();
+        let environment = self.environment.clone();
+        let install_service_fn = Arc::new(install_service_fn);
 
         move |req: Request<Incoming>, chain: D| {
             let cfg = config.clone();
-            let sc = client.clone();
-            let error_handler = listener_error_handler.clone();
-            let thread_user_state_storage = user_state_storage.clone();
+            let thread_environment = environment.clone();
+            let thread_install_service_fn = install_service_fn.clone();
             async move {
                 match (req.method(), req.uri().path()) {
                     (&Method::GET, url) if url == cfg.install_path => {
@@ -150,10 +171,8 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<
                         Self::slack_oauth_callback_service(
                             req,
                             &cfg,
-                            sc,
-                            thread_user_state_storage,
-                            install_service_fn,
-                            error_handler,
+                            thread_environment,
+                            thread_install_service_fn,
                         )
                         .await
                     }
@@ -15,52 +15,83 @@ use tracing::*;
 
 pub use crate::models::events::*;
 
-impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<H> {
-    pub fn push_events_service_fn<'a, D, F>(
+impl<H: 'static + Send + Sync + Connect + Clone, S: Clone + Send + Sync + 'static>
+    SlackClientEventsHyperListener<H, S>
+{
+    pub fn push_events_service_fn<'a, D, F, PF, PFR>(
         &self,
         config: Arc<SlackPushEventsListenerConfig>,
-        push_service_fn: UserCallbackFunction<
-            SlackPushEvent,
-            impl Future<Output = UserCallbackResult<()>> + 'static + Send,
-            SlackClientHyperConnector<H>,
-        >,
+        push_service_fn: PF,
     ) -> impl Fn(Request<Incoming>, D) -> BoxFuture<'a, AnyStdResult<Response<Body>>> + 'a + Send + Clone
     where
         D: Fn(Request<Incoming>) -> F + 'a + Send + Sync + Clone,
         F: Future<Output = AnyStdResult<Response<Body>>> + 'a + Send,
+        PF: Fn(SlackPushEvent, Arc<SlackClient<SlackClientHyperConnector<H>>>, S) -> PFR
+            + 'static
+            + Send
+            + Sync,
+        PFR: Future<Output = UserCallbackResult<()>> + 'static + Send,
     {
         let signature_verifier: Arc<SlackEventSignatureVerifier> = Arc::new(
             SlackEventSignatureVerifier::new(&config.events_signing_secret),
         );
         let client = self.environment.client.clone();
-        let error_handler = self.environment.error_handler.clone();
+        let environment = self.environment.clone();
         let user_state_storage = self.environment.user_state.clone();
+        let push_service_fn = Arc::new(push_service_fn);
+        let background_executor = self.background_executor.clone();
+        let deduplicator = self.environment.deduplicator.clone();
 
         move |req: Request<Incoming>, chain: D| {
             let cfg = config.clone();
             let sign_verifier = signature_verifier.clone();
             let sc = client.clone();
-            let thread_error_handler = error_handler.clone();
+            let thread_environment = environment.clone();
             let thread_user_state_storage = user_state_storage.clone();
+            let thread_push_service_fn = push_service_fn.clone();
+            let thread_background_executor = background_executor.clone();
+            let thread_deduplicator = deduplicator.clone();
             async move {
                 match (req.method(), req.uri().path()) {
                     (&Method::POST, url) if url == cfg.events_path => {
                         HyperExtensions::decode_signed_response(req, &sign_verifier)
-                            .map_ok(|(body, _)| {
-                                serde_json::from_str::<SlackPushEvent>(body.as_str()).map_err(|e| {
-                                    SlackClientProtocolError::new(e)
-                                        .with_json_body(body.clone())
-                                        .into()
-                                })
+                            .map_ok(|(body, parts)| {
+                                let event = serde_json::from_str::<SlackPushEvent>(body.as_str())
+                                    .map(|event| match event {
+                                        SlackPushEvent::EventCallback(event_callback) => {
+                                            SlackPushEvent::EventCallback(SlackPushEventCallback {
+                                                retry: SlackEventRetryInfo::from_headers(
+                                                    &parts.headers,
+                                                ),
+                                                ..event_callback
+                                            })
+                                        }
+                                        other => other,
+                                    })
+                                    .map_err(|e| {
+                                        SlackClientProtocolError::new(e)
+                                            .with_json_body(body.clone())
+                                            .into()
+                                    });
+                                (body, event)
                             })
-                            .and_then(|event| async move {
+                            .and_then(|(body, event)| async move {
+                                if let (
+                                    Ok(SlackPushEvent::EventCallback(event_callback)),
+                                    Some(deduplicator),
+                                ) = (&event, &thread_deduplicator)
+                                {
+                                    if deduplicator.is_duplicate_push_event(event_callback).await {
+                                        return Ok(Response::new(Empty::new().boxed()));
+                                    }
+                                }
                                 match event {
                                     Ok(SlackPushEvent::UrlVerification(url_ver)) => {
                                         debug!(
                                             "Received Slack URL push verification challenge: {}",
                                             url_ver.challenge
                                         );
-                                        match push_service_fn(
+                                        match thread_push_service_fn(
                                             SlackPushEvent::UrlVerification(url_ver.clone()),
                                             sc.clone(),
                                             thread_user_state_storage.clone(),
@@ -71,11 +102,15 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<
                                                 Full::new(url_ver.challenge.into()).boxed(),
                                             )),
                                             Err(err) => {
-                                                let status_code = thread_error_handler(
-                                                    err,
-                                                    sc,
-                                                    thread_user_state_storage,
-                                                );
+                                                let status_code = thread_environment
+                                                    .handle_error_with_status(
+                                                        err,
+                                                        SlackEventErrorContext::new(
+                                                            SlackEventKind::PushEvent,
+                                                        )
+                                                        .with_raw_payload(body),
+                                                    )
+                                                    .await;
                                                 Response::builder()
                                                     .status(status_code)
                                                     .body(Empty::new().boxed())
@@ -83,9 +118,46 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<
                                             }
                                         }
                                     }
-                                    other => match other {
-                                        Ok(push_event) => {
-                                            match push_service_fn(
+                                    other => match (other, thread_background_executor) {
+                                        (Ok(push_event), Some(executor)) => {
+                                            let error_context =
+                                                SlackEventErrorContext::from_push_event(
+                                                    &push_event,
+                                                )
+                                                .with_raw_payload(body);
+                                            let event_id = push_event_id(&push_event);
+                                            let handler_future = thread_push_service_fn(
+                                                push_event,
+                                                sc.clone(),
+                                                thread_user_state_storage.clone(),
+                                            );
+                                            executor
+                                                .spawn(async move {
+                                                    if let Err(err) = handler_future.await {
+                                                        if let Some(event_id) = &event_id {
+                                                            thread_environment
+                                                                .forget_failed_push_event(event_id)
+                                                                .await;
+                                                        }
+                                                        thread_environment
+                                                            .handle_error_with_status(
+                                                                err,
+                                                                error_context,
+                                                            )
+                                                            .await;
+                                                    }
+                                                })
+                                                .await;
+                                            Ok(Response::new(Empty::new().boxed()))
+                                        }
+                                        (Ok(push_event), None) => {
+                                            let error_context =
+                                                SlackEventErrorContext::from_push_event(
+                                                    &push_event,
+                                                )
+                                                .with_raw_payload(body);
+                                            let event_id = push_event_id(&push_event);
+                                            match thread_push_service_fn(
                                                 push_event,
                                                 sc.clone(),
                                                 thread_user_state_storage.clone(),
@@ -94,11 +166,17 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<
                                             {
                                                 Ok(_) => Ok(Response::new(Empty::new().boxed())),
                                                 Err(err) => {
-                                                    let status_code = thread_error_handler(
-                                                        err,
-                                                        sc,
-                                                        thread_user_state_storage,
-                                                    );
+                                                    if let Some(event_id) = &event_id {
+                                                        thread_environment
+                                                            .forget_failed_push_event(event_id)
+                                                            .await;
+                                                    }
+                                                    let status_code = thread_environment
+                                                        .handle_error_with_status(
+                                                            err,
+                                                            error_context,
+                                                        )
+                                                        .await;
                                                     Response::builder()
                                                         .status(status_code)
                                                         .body(Empty::new().boxed())
@@ -106,12 +184,16 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<
                                                 }
                                             }
                                         }
-                                        Err(err_oush_event) => {
-                                            let status_code = thread_error_handler(
-                                                err_oush_event,
-                                                sc,
-                                                thread_user_state_storage,
-                                            );
+                                        (Err(err_oush_event), _) => {
+                                            let status_code = thread_environment
+                                                .handle_error_with_status(
+                                                    err_oush_event,
+                                                    SlackEventErrorContext::new(
+                                                        SlackEventKind::PushEvent,
+                                                    )
+                                                    .with_raw_payload(body),
+                                                )
+                                                .await;
                                             Response::builder()
                                                 .status(status_code)
                                                 .body(Empty::new().boxed())
@@ -129,3 +211,210 @@ impl<H: 'static + Send + Sync + Connect + Clone> SlackClientEventsHyperListener<
         }
     }
 }
+
+fn push_event_id(event: &SlackPushEvent) -> Option<SlackEventId> {
+    match event {
+        SlackPushEvent::EventCallback(event_callback) => Some(event_callback.event_id.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use hyper::server::conn::http1;
+    use hyper::service::service_fn;
+    use hyper::StatusCode;
+    use hyper_util::client::legacy::connect::HttpConnector;
+    use hyper_util::rt::TokioIo;
+    use std::net::SocketAddr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::net::{TcpListener, TcpStream};
+
+    const TEST_SIGNING_SECRET: &str = "test-signing-secret";
+
+    type TestClient = Arc<SlackClient<SlackClientHyperConnector<HttpConnector>>>;
+
+    fn test_environment(
+    ) -> SlackClientEventsListenerEnvironment<SlackClientHyperConnector<HttpConnector>> {
+        SlackClientEventsListenerEnvironment::new(Arc::new(SlackClient::new(
+            SlackClientHyperConnector::with_connector(HttpConnector::new()),
+        )))
+    }
+
+    async fn not_found(_req: Request<Incoming>) -> AnyStdResult<Response<Body>> {
+        Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(Empty::new().boxed())
+            .map_err(|e| e.into())
+    }
+
+    async fn serve<PF, PFR>(
+        environment: SlackClientEventsListenerEnvironment<SlackClientHyperConnector<HttpConnector>>,
+        push_service_fn: PF,
+    ) -> SocketAddr
+    where
+        PF: Fn(SlackPushEvent, TestClient, SlackClientEventsUserState) -> PFR
+            + 'static
+            + Send
+            + Sync,
+        PFR: Future<Output = UserCallbackResult<()>> + 'static + Send,
+    {
+        serve_listener(
+            SlackClientEventsHyperListener::new(Arc::new(environment)),
+            push_service_fn,
+        )
+        .await
+    }
+
+    async fn serve_listener<PF, PFR>(
+        listener: SlackClientEventsHyperListener<HttpConnector>,
+        push_service_fn: PF,
+    ) -> SocketAddr
+    where
+        PF: Fn(SlackPushEvent, TestClient, SlackClientEventsUserState) -> PFR
+            + 'static
+            + Send
+            + Sync,
+        PFR: Future<Output = UserCallbackResult<()>> + 'static + Send,
+    {
+        let routes = chain_service_routes_fn(
+            listener.push_events_service_fn(
+                Arc::new(SlackPushEventsListenerConfig::new(
+                    TEST_SIGNING_SECRET.to_string().into(),
+                )),
+                push_service_fn,
+            ),
+            not_found,
+        );
+        let tcp_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = tcp_listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = tcp_listener.accept().await {
+                tokio::spawn(
+                    http1::Builder::new()
+                        .serve_connection(TokioIo::new(stream), service_fn(routes.clone())),
+                );
+            }
+        });
+        addr
+    }
+
+    async fn send_push_event(addr: SocketAddr, body: &str) -> StatusCode {
+        let ts = chrono::Utc::now().timestamp().to_string();
+        let signature = SlackEventSignatureVerifier::new(&TEST_SIGNING_SECRET.to_string().into())
+            .sign(body, &ts)
+            .unwrap();
+
+        let stream = TcpStream::connect(addr).await.unwrap();
+        let (mut sender, connection) = hyper::client::conn::http1::handshake(TokioIo::new(stream))
+            .await
+            .unwrap();
+        tokio::spawn(connection);
+
+        let request = Request::post("/push")
+            .header(hyper::header::HOST, addr.to_string())
+            .header(
+                SlackEventSignatureVerifier::SLACK_SIGNED_HASH_HEADER,
+                signature,
+            )
+            .header(SlackEventSignatureVerifier::SLACK_SIGNED_TIMESTAMP, ts)
+            .body(Full::new(hyper::body::Bytes::from(body.to_string())))
+            .unwrap();
+        sender.send_request(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn test_closure_handler_captures_context() {
+        let handled_events = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let captured_events = handled_events.clone();
+        let addr = serve(test_environment(), move |event, _client, _states| {
+            let captured_events = captured_events.clone();
+            async move {
+                if let SlackPushEvent::EventCallback(event_callback) = event {
+                    captured_events
+                        .lock()
+                        .unwrap()
+                        .push(event_callback.event_id);
+                }
+                Ok(())
+            }
+        })
+        .await;
+
+        let status = send_push_event(
+            addr,
+            include_str!("../../models/events/fixtures/call_rejected.json"),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(*handled_events.lock().unwrap(), vec!["Ev0123ABCDEF".into()]);
+    }
+
+    #[tokio::test]
+    async fn test_deduplicator_passes_retries_of_failed_events() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let handler_calls = calls.clone();
+        let addr = serve(
+            test_environment().with_deduplicator(SlackEventsDeduplicator::new()),
+            move |_event, _client, _states| {
+                let handler_calls = handler_calls.clone();
+                async move {
+                    if handler_calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                        Err("temporary failure".into())
+                    } else {
+                        Ok(())
+                    }
+                }
+            },
+        )
+        .await;
+        let body = include_str!("../../models/events/fixtures/call_rejected.json");
+
+        assert_eq!(send_push_event(addr, body).await, StatusCode::BAD_REQUEST);
+        assert_eq!(send_push_event(addr, body).await, StatusCode::OK);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        assert_eq!(send_push_event(addr, body).await, StatusCode::OK);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_ack_first_responds_with_saturated_executor() {
+        let executor = Arc::new(SlackEventsBackgroundExecutor::with_max_concurrent_tasks(1));
+        let gate = Arc::new(tokio::sync::Semaphore::new(0));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let handler_gate = gate.clone();
+        let handler_calls = calls.clone();
+        let addr = serve_listener(
+            SlackClientEventsHyperListener::new(Arc::new(test_environment()))
+                .with_ack_first(executor.clone()),
+            move |_event, _client, _states| {
+                let gate = handler_gate.clone();
+                let calls = handler_calls.clone();
+                async move {
+                    gate.acquire().await.unwrap().forget();
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    Ok(())
+                }
+            },
+        )
+        .await;
+        let body = include_str!("../../models/events/fixtures/call_rejected.json");
+
+        for _ in 0..3 {
+            let status = tokio::time::timeout(
+                std::time::Duration::from_secs(1),
+                send_push_event(addr, body),
+            )
+            .await
+            .expect("the event wasn't acknowledged with a saturated executor");
+            assert_eq!(status, StatusCode::OK);
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        gate.add_permits(3);
+        assert!(executor.shutdown(std::time::Duration::from_secs(5)).await);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+}
@@ -0,0 +1,299 @@
+use crate::api::*;
+use crate::models::blocks::SlackBlock;
+use crate::models::*;
+use crate::*;
+use futures::{Stream, StreamExt};
+use rsb_derive::Builder;
+use std::time::Duration;
+
+/// Slack limits the size of the text (in characters) in a single `chat.appendStream` call
+pub const SLACK_STREAM_MAX_MARKDOWN_TEXT_LEN: usize = 12000;
+
+#[derive(Debug, PartialEq, Clone, Builder)]
+pub struct SlackMessageStreamerConfig {
+    /// How often buffered chunks are sent to Slack with `chat.appendStream`
+    #[default = "Duration::from_secs(1)"]
+    pub flush_interval: Duration,
+    /// Flush without waiting for the interval when the buffer grows over this size (in characters).
+    /// It can't be larger than `SLACK_STREAM_MAX_MARKDOWN_TEXT_LEN`.
+    #[default = "SLACK_STREAM_MAX_MARKDOWN_TEXT_LEN"]
+    pub max_buffer_len: usize,
+}
+
+/// Content used to finalize a streamed message with `chat.stopStream`
+#[derive(Debug, PartialEq, Clone, Builder)]
+pub struct SlackMessageStreamFinish {
+    pub blocks: Option<Vec<SlackBlock>>,
+    pub metadata: Option<SlackMessageMetadata>,
+}
+
+///
+/// Streams text chunks (e.g. LLM output) into a Slack message using
+/// `chat.startStream`, `chat.appendStream` and `chat.stopStream`.
+/// Chunks are buffered and sent on a configured cadence instead of on every chunk,
+/// so long responses don't exhaust the method rate limits.
+///
+pub struct SlackMessageStreamer<'a, 's, SCHC>
+where
+    SCHC: SlackClientHttpConnector + Send,
+{
+    session: &'a SlackClientSession<'s, SCHC>,
+    config: SlackMessageStreamerConfig,
+}
+
+impl<'a, 's, SCHC> SlackMessageStreamer<'a, 's, SCHC>
+where
+    SCHC: SlackClientHttpConnector + Send + Sync,
+{
+    pub fn new(session: &'a SlackClientSession<'s, SCHC>) -> Self {
+        Self::with_config(session, SlackMessageStreamerConfig::new())
+    }
+
+    pub fn with_config(
+        session: &'a SlackClientSession<'s, SCHC>,
+        config: SlackMessageStreamerConfig,
+    ) -> Self {
+        let max_buffer_len = config
+            .max_buffer_len
+            .min(SLACK_STREAM_MAX_MARKDOWN_TEXT_LEN);
+        Self {
+            session,
+            config: config.with_max_buffer_len(max_buffer_len),
+        }
+    }
+
+    /// Starts a new streamed message, appends all chunks from the stream and
+    /// finalizes the message when the stream ends.
+    pub async fn stream<S>(
+        &self,
+        start: SlackApiChatStartStreamRequest,
+        chunks: S,
+        finish: SlackMessageStreamFinish,
+    ) -> ClientResult<SlackApiChatStopStreamResponse>
+    where
+        S: Stream<Item = String> + Send,
+    {
+        let started = self.session.chat_start_stream(&start).await?;
+        let mut chunks = std::pin::pin!(chunks);
+        let mut buffer = String::new();
+        let mut buffer_len = 0;
+
+        let mut interval = tokio::time::interval(self.config.flush_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        // The first tick completes immediately
+        interval.tick().await;
+
+        loop {
+            tokio::select! {
+                chunk = chunks.next() => {
+                    match chunk {
+                        Some(chunk) => {
+                            buffer.push_str(&chunk);
+                            buffer_len += chunk.chars().count();
+                            if buffer_len >= self.config.max_buffer_len {
+                                self.append(&started, &std::mem::take(&mut buffer)).await?;
+                                buffer_len = 0;
+                                interval.reset();
+                            }
+                        }
+                        None => break,
+                    }
+                }
+                _ = interval.tick() => {
+                    self.append(&started, &std::mem::take(&mut buffer)).await?;
+                    buffer_len = 0;
+                }
+            }
+        }
+
+        // The last piece of the remaining text is sent with the final request, saving one more call
+        let mut pieces = split_markdown_text(&buffer, SLACK_STREAM_MAX_MARKDOWN_TEXT_LEN);
+        let markdown_text = pieces.pop().map(|piece| piece.to_string());
+        for piece in pieces {
+            self.append_piece(&started, piece).await?;
+        }
+
+        self.session
+            .chat_stop_stream(&SlackApiChatStopStreamRequest {
+                channel: started.channel,
+                ts: started.ts,
+                markdown_text,
+                blocks: finish.blocks,
+                metadata: finish.metadata,
+            })
+            .await
+    }
+
+    async fn append(
+        &self,
+        started: &SlackApiChatStartStreamResponse,
+        text: &str,
+    ) -> ClientResult<()> {
+        for piece in split_markdown_text(text, SLACK_STREAM_MAX_MARKDOWN_TEXT_LEN) {
+            self.append_piece(started, piece).await?;
+        }
+        Ok(())
+    }
+
+    async fn append_piece(
+        &self,
+        started: &SlackApiChatStartStreamResponse,
+        piece: &str,
+    ) -> ClientResult<()> {
+        self.session
+            .chat_append_stream(&SlackApiChatAppendStreamRequest::new(
+                started.channel.clone(),
+                started.ts.clone(),
+                piece.to_string(),
+            ))
+            .await?;
+        Ok(())
+    }
+}
+
+/// Splits the text into pieces of up to `max_len` characters, preferably after a newline
+fn split_markdown_text(text: &str, max_len: usize) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let end = match rest.char_indices().nth(max_len) {
+            Some((limit, _)) => rest[..limit]
+                .rfind('\n')
+                .map(|newline| newline + 1)
+                .unwrap_or(limit),
+            None => rest.len(),
+        };
+        pieces.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_connector::SlackTestHttpConnector;
+    use futures::stream;
+
+    fn test_client() -> SlackClient<SlackTestHttpConnector> {
+        let client = SlackClient::new(SlackTestHttpConnector::new());
+        let started = serde_json::json!({ "channel": "C1", "ts": "1.1" });
+        client
+            .http_api
+            .connector
+            .respond("chat.startStream", started.clone());
+        for _ in 0..5 {
+            client
+                .http_api
+                .connector
+                .respond("chat.appendStream", started.clone());
+        }
+        client
+            .http_api
+            .connector
+            .respond("chat.stopStream", started.clone());
+        client
+    }
+
+    fn start_request() -> SlackApiChatStartStreamRequest {
+        SlackApiChatStartStreamRequest::new("C1".into(), "1.0".into())
+    }
+
+    fn appended_texts(client: &SlackClient<SlackTestHttpConnector>) -> Vec<String> {
+        client
+            .http_api
+            .connector
+            .requests("chat.appendStream")
+            .iter()
+            .map(|request| request["markdown_text"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_split_markdown_text() {
+        assert_eq!(split_markdown_text("", 3), Vec::<&str>::new());
+        assert_eq!(split_markdown_text("ab\ncdef", 4), vec!["ab\n", "cdef"]);
+        assert_eq!(split_markdown_text("ééééé", 2), vec!["éé", "éé", "é"]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_flushes_chunks_on_interval() -> ClientResult<()> {
+        let client = test_client();
+        let token = SlackApiToken::new("xoxb-test".into());
+        let session = client.open_session(&token);
+        let chunks = stream::iter(vec![("a", 0), ("b", 500), ("c", 1000), ("d", 100)]).then(
+            |(chunk, delay)| async move {
+                tokio::time::sleep(Duration::from_millis(delay)).await;
+                chunk.to_string()
+            },
+        );
+
+        SlackMessageStreamer::new(&session)
+            .stream(start_request(), chunks, SlackMessageStreamFinish::new())
+            .await?;
+
+        assert_eq!(appended_texts(&client), vec!["ab"]);
+        assert_eq!(
+            client.http_api.connector.requests("chat.stopStream")[0]["markdown_text"],
+            "cd"
+        );
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_splits_oversize_chunks() -> ClientResult<()> {
+        let client = test_client();
+        let token = SlackApiToken::new("xoxb-test".into());
+        let session = client.open_session(&token);
+        let first_line = format!("{}\n", "é".repeat(SLACK_STREAM_MAX_MARKDOWN_TEXT_LEN - 10));
+        let second_line = "é".repeat(20);
+        let chunks = stream::iter(vec![format!("{first_line}{second_line}")]);
+
+        let streamer = SlackMessageStreamer::with_config(
+            &session,
+            SlackMessageStreamerConfig::new().with_max_buffer_len(usize::MAX),
+        );
+        assert_eq!(
+            streamer.config.max_buffer_len,
+            SLACK_STREAM_MAX_MARKDOWN_TEXT_LEN
+        );
+        streamer
+            .stream(start_request(), chunks, SlackMessageStreamFinish::new())
+            .await?;
+
+        assert_eq!(appended_texts(&client), vec![first_line, second_line]);
+        assert_eq!(
+            client.http_api.connector.requests("chat.stopStream")[0].get("markdown_text"),
+            None
+        );
+        Ok(())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_sends_remaining_text_on_finish() -> ClientResult<()> {
+        let client = test_client();
+        let token = SlackApiToken::new("xoxb-test".into());
+        let session = client.open_session(&token);
+        let chunks = stream::iter(vec!["Hello, ".to_string(), "world".to_string()]);
+
+        SlackMessageStreamer::new(&session)
+            .stream(
+                start_request(),
+                chunks,
+                SlackMessageStreamFinish::new().with_blocks(vec![]),
+            )
+            .await?;
+
+        assert!(appended_texts(&client).is_empty());
+        assert_eq!(
+            client.http_api.connector.requests("chat.stopStream"),
+            vec![serde_json::json!({
+                "channel": "C1",
+                "ts": "1.1",
+                "markdown_text": "Hello, world",
+                "blocks": []
+            })]
+        );
+        Ok(())
+    }
+}
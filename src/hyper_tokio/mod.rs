@@ -11,24 +11,28 @@ use std::convert::Infallible;
 
 use crate::*;
 
+mod background_executor;
 pub mod connector;
 pub mod hyper_errors;
 pub(crate) mod hyper_ext;
 pub mod listener;
+mod message_streamer;
 mod ratectl;
 pub mod scroller_ext;
 mod socket_mode;
 
-use crate::listener::SlackClientEventsListenerEnvironment;
+use crate::listener::{SlackClientEventsListenerEnvironment, SlackClientEventsUserState};
+pub use background_executor::*;
 pub use listener::chain_service_routes_fn;
 pub use listener::SlackClientEventsHyperListener;
+pub use message_streamer::*;
 pub use scroller_ext::SlackApiResponseScrollerExt;
 pub use socket_mode::*;
 
 pub type SlackHyperClient = SlackClient<SlackClientHyperHttpsConnector>;
 
-pub type SlackHyperListenerEnvironment =
-    SlackClientEventsListenerEnvironment<SlackClientHyperHttpsConnector>;
+pub type SlackHyperListenerEnvironment<S = SlackClientEventsUserState> =
+    SlackClientEventsListenerEnvironment<SlackClientHyperHttpsConnector, S>;
 
 pub type SlackHyperHttpsConnector =
     hyper_rustls::HttpsConnector<hyper_util::client::legacy::connect::HttpConnector>;
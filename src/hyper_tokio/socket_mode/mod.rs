@@ -12,14 +12,17 @@ impl<H: Send + Sync + Clone + Connect + 'static>
     SlackSocketModeClientsManagerFactory<SlackClientHyperConnector<H>>
     for SlackClientHyperConnector<H>
 {
-    fn new_clients_manager(
+    fn new_clients_manager<S>(
         &self,
         listener_environment: Arc<
-            SlackClientEventsListenerEnvironment<SlackClientHyperConnector<H>>,
+            SlackClientEventsListenerEnvironment<SlackClientHyperConnector<H>, S>,
         >,
-    ) -> Arc<dyn SlackSocketModeClientsManager + Send + Sync> {
+    ) -> Arc<dyn SlackSocketModeClientsManager + Send + Sync>
+    where
+        S: Clone + Send + Sync + 'static,
+    {
         Arc::new(SlackSocketModeTokioClientsManager::new(
-            listener_environment,
+            listener_environment.client.clone(),
         ))
     }
 }
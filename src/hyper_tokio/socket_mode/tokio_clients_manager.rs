@@ -9,7 +9,6 @@ use futures::future;
 
 use crate::clients_manager::SlackSocketModeClientsManager;
 use crate::hyper_tokio::SlackClientHyperConnector;
-use crate::listener::SlackClientEventsListenerEnvironment;
 use tokio::sync::RwLock;
 use tracing::*;
 
@@ -17,7 +16,7 @@ pub struct SlackSocketModeTokioClientsManager<SCHC>
 where
     SCHC: SlackClientHttpConnector + Send + Sync,
 {
-    listener_environment: Arc<SlackClientEventsListenerEnvironment<SCHC>>,
+    client: Arc<SlackClient<SCHC>>,
     active_clients: Arc<RwLock<Vec<SlackTungsteniteWssClient<SCHC>>>>,
 }
 
@@ -25,9 +24,9 @@ impl<SCHC> SlackSocketModeTokioClientsManager<SCHC>
 where
     SCHC: SlackClientHttpConnector + Send + Sync,
 {
-    pub fn new(listener_environment: Arc<SlackClientEventsListenerEnvironment<SCHC>>) -> Self {
+    pub fn new(client: Arc<SlackClient<SCHC>>) -> Self {
         Self {
-            listener_environment,
+            client,
             active_clients: Arc::new(RwLock::new(vec![])),
         }
     }
@@ -60,7 +59,7 @@ impl<H: Send + Sync + Clone + Connect + 'static> SlackSocketModeClientsManager
                     client_listener.clone(),
                     &token,
                     config,
-                    self.listener_environment.clone(),
+                    self.client.clone(),
                 );
                 clients_write.push(wss_client_result);
             }
@@ -106,7 +105,7 @@ impl<H: Send + Sync + Clone + Connect + 'static> SlackSocketModeClientsManager
                 removed_client.identity.client_listener.clone(),
                 &removed_client.identity.token,
                 &removed_client.identity.config,
-                self.listener_environment.clone(),
+                self.client.clone(),
             );
 
             client.start(0).await;
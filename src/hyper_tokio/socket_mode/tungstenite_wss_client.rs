@@ -1,6 +1,5 @@
 use crate::api::SlackApiAppsConnectionOpenRequest;
 use crate::errors::*;
-use crate::listener::SlackClientEventsListenerEnvironment;
 use crate::*;
 use futures::{SinkExt, StreamExt};
 use rvstruct::*;
@@ -31,7 +30,7 @@ where
     command_writer: Arc<RwLock<UnboundedSender<SlackTungsteniteWssClientCommand>>>,
     command_reader: Arc<RwLock<Option<UnboundedReceiver<SlackTungsteniteWssClientCommand>>>>,
     destroyed: Arc<AtomicBool>,
-    listener_environment: Arc<SlackClientEventsListenerEnvironment<SCHC>>,
+    client: Arc<SlackClient<SCHC>>,
 }
 
 #[derive(Clone, Debug)]
@@ -49,7 +48,7 @@ where
         client_listener: Arc<dyn SlackSocketModeClientListener + Sync + Send>,
         token: &SlackApiToken,
         config: &SlackClientSocketModeConfig,
-        listener_environment: Arc<SlackClientEventsListenerEnvironment<SCHC>>,
+        client: Arc<SlackClient<SCHC>>,
     ) -> Self {
         let identity = SlackTungsteniteWssClientIdentity {
             id,
@@ -68,15 +67,15 @@ where
             command_writer: Arc::new(RwLock::new(tx)),
             command_reader: Arc::new(RwLock::new(Some(rx))),
             destroyed: Arc::new(AtomicBool::new(false)),
-            listener_environment,
+            client,
         }
     }
 
     async fn try_to_connect(
         identity: &SlackTungsteniteWssClientIdentity,
-        listener_environment: Arc<SlackClientEventsListenerEnvironment<SCHC>>,
+        client: Arc<SlackClient<SCHC>>,
     ) -> Option<WebSocketStream<MaybeTlsStream<TcpStream>>> {
-        let session = listener_environment.client.open_session(&identity.token);
+        let session = client.open_session(&identity.token);
 
         trace!(
             "[{}] Receiving WSS URL to connect through Slack app.connections.open()",
@@ -152,10 +151,10 @@ where
 
     async fn connect_with_reconnections(
         identity: &SlackTungsteniteWssClientIdentity,
-        listener_environment: Arc<SlackClientEventsListenerEnvironment<SCHC>>,
+        client: Arc<SlackClient<SCHC>>,
         destroyed: Arc<AtomicBool>,
     ) -> ClientResult<WebSocketStream<MaybeTlsStream<TcpStream>>> {
-        let mut maybe_stream = Self::try_to_connect(identity, listener_environment.clone()).await;
+        let mut maybe_stream = Self::try_to_connect(identity, client.clone()).await;
         loop {
             if let Some(wss_stream) = maybe_stream {
                 return Ok(wss_stream);
@@ -173,7 +172,7 @@ where
                 interval.tick().await;
                 interval.tick().await;
 
-                maybe_stream = Self::try_to_connect(identity, listener_environment.clone()).await;
+                maybe_stream = Self::try_to_connect(identity, client.clone()).await;
             } else {
                 return Err(SlackClientError::EndOfStream(
                     SlackClientEndOfStreamError::new(),
@@ -186,7 +185,7 @@ where
         mut rx: UnboundedReceiver<SlackTungsteniteWssClientCommand>,
         tx: UnboundedSender<SlackTungsteniteWssClientCommand>,
         identity: SlackTungsteniteWssClientIdentity,
-        listener_environment: Arc<SlackClientEventsListenerEnvironment<SCHC>>,
+        client: Arc<SlackClient<SCHC>>,
         destroyed: Arc<AtomicBool>,
         initial_wait_timeout: u64,
     ) -> ClientResult<()> {
@@ -205,8 +204,7 @@ where
         }
 
         let wss_stream =
-            Self::connect_with_reconnections(&identity, listener_environment, destroyed.clone())
-                .await?;
+            Self::connect_with_reconnections(&identity, client, destroyed.clone()).await?;
 
         let (mut writer, mut reader) = wss_stream.split();
 
@@ -451,7 +449,7 @@ where
                     rx,
                     tx,
                     self.identity.clone(),
-                    self.listener_environment.clone(),
+                    self.client.clone(),
                     self.destroyed.clone(),
                     initial_wait_timeout,
                 ));
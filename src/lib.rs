@@ -84,10 +84,14 @@
     unused_imports
 )]
 
+// Allows the derive macros to refer to the crate in its own tests
+extern crate self as slack_morphism;
+
 pub use client::*;
 pub use scroller::*;
 pub use socket_mode::*;
 pub use token::*;
+pub use views_manager::*;
 
 mod models;
 pub use models::*;
@@ -103,7 +107,10 @@ pub mod signature_verifier;
 pub mod socket_mode;
 
 pub mod multipart_form;
+#[cfg(test)]
+pub(crate) mod test_connector;
 mod token;
+mod views_manager;
 
 #[cfg(feature = "hyper-base")]
 pub mod hyper_tokio;
@@ -112,3 +119,6 @@ pub mod hyper_tokio;
 pub mod axum_support;
 
 pub mod prelude;
+
+#[cfg(feature = "derive")]
+pub use slack_morphism_derive::*;
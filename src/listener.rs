@@ -1,3 +1,5 @@
+use crate::api::SlackApiPostWebhookMessageRequest;
+use crate::models::events::SlackEventId;
 use crate::models::*;
 use crate::{BoxError, ClientResult, SlackClient, SlackClientHttpConnector};
 use futures::executor::block_on;
@@ -6,21 +8,39 @@ use rsb_derive::Builder;
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::future::Future;
 use std::sync::Arc;
 use tracing::*;
 use url::Url;
 
+mod dedup;
+mod error_context;
+mod events_router;
+mod function_router;
+pub use dedup::*;
+pub use error_context::*;
+pub use events_router::*;
+pub use function_router::*;
+
 type UserStatesMap = HashMap<TypeId, Box<dyn Any + Send + Sync + 'static>>;
 
 pub type HttpStatusCode = http::StatusCode;
 
-pub struct SlackClientEventsListenerEnvironment<SCHC>
+///
+/// The environment shared by the listeners. The application state `S` is cloned and passed
+/// to the callbacks the same way as it is done for axum `State`.
+/// By default, it is the type-keyed storage `SlackClientEventsUserState`.
+///
+pub struct SlackClientEventsListenerEnvironment<SCHC, S = SlackClientEventsUserState>
 where
     SCHC: SlackClientHttpConnector + Send + Sync,
+    S: Clone + Send + Sync + 'static,
 {
     pub client: Arc<SlackClient<SCHC>>,
-    pub error_handler: BoxedErrorHandler<SCHC>,
-    pub user_state: SlackClientEventsUserState,
+    pub error_handler: BoxedErrorHandler<SCHC, S>,
+    pub async_error_handler: Option<AsyncErrorHandler<SCHC, S>>,
+    pub user_state: S,
+    pub deduplicator: Option<Arc<SlackEventsDeduplicator>>,
 }
 
 pub type SlackClientEventsUserState = futures_locks::RwLock<SlackClientEventsUserStateStorage>;
@@ -30,37 +50,137 @@ where
     SCHC: SlackClientHttpConnector + Send + Sync,
 {
     pub fn new(client: Arc<SlackClient<SCHC>>) -> Self {
+        Self::from_state(
+            client,
+            SlackClientEventsUserState::new(SlackClientEventsUserStateStorage::new()),
+        )
+    }
+
+    pub fn with_user_state<T: Send + Sync + 'static>(self, state: T) -> Self {
+        let future_init_state = self
+            .user_state
+            .write()
+            .map(|mut guard| guard.set_user_state(state));
+        block_on(future_init_state);
+        self
+    }
+}
+
+impl<SCHC, S> SlackClientEventsListenerEnvironment<SCHC, S>
+where
+    SCHC: SlackClientHttpConnector + Send + Sync,
+    S: Clone + Send + Sync + 'static,
+{
+    /// Creates an environment with your own application state type
+    pub fn from_state(client: Arc<SlackClient<SCHC>>, state: S) -> Self {
         Self {
             client,
             error_handler: Box::new(Self::empty_error_handler),
-            user_state: SlackClientEventsUserState::new(SlackClientEventsUserStateStorage::new()),
+            async_error_handler: None,
+            user_state: state,
+            deduplicator: None,
+        }
+    }
+
+    /// Enables skipping the events redelivered by Slack
+    pub fn with_deduplicator(self, deduplicator: SlackEventsDeduplicator) -> Self {
+        Self {
+            deduplicator: Some(Arc::new(deduplicator)),
+            ..self
+        }
+    }
+
+    /// Lets the deduplicator pass through Slack retries of a push event, which handler failed
+    pub(crate) async fn forget_failed_push_event(&self, event_id: &SlackEventId) {
+        if let Some(deduplicator) = &self.deduplicator {
+            deduplicator.forget_push_event(event_id).await;
         }
     }
 
-    pub fn with_error_handler(self, error_handler: ErrorHandler<SCHC>) -> Self {
+    pub fn with_error_handler(self, error_handler: ErrorHandler<SCHC, S>) -> Self {
         Self {
             error_handler: Box::new(error_handler),
             ..self
         }
     }
 
+    /// The async error handler receives the context of the failed event
+    /// and takes precedence over the error handler specified with `with_error_handler`.
+    pub fn with_async_error_handler<F, FR>(self, async_error_handler: F) -> Self
+    where
+        F: Fn(BoxError, SlackEventErrorContext, Arc<SlackClient<SCHC>>, S) -> FR
+            + Send
+            + Sync
+            + 'static,
+        FR: Future<Output = SlackEventErrorResponse> + Send + 'static,
+    {
+        Self {
+            async_error_handler: Some(Box::new(move |err, context, client, user_state| {
+                async_error_handler(err, context, client, user_state).boxed()
+            })),
+            ..self
+        }
+    }
+
+    pub async fn handle_error(
+        &self,
+        err: BoxError,
+        context: SlackEventErrorContext,
+    ) -> SlackEventErrorResponse {
+        match &self.async_error_handler {
+            Some(async_error_handler) => {
+                async_error_handler(err, context, self.client.clone(), self.user_state.clone())
+                    .await
+            }
+            None => SlackEventErrorResponse::Status((self.error_handler)(
+                err,
+                self.client.clone(),
+                self.user_state.clone(),
+            )),
+        }
+    }
+
+    /// Handles the error for the events which can be responded only with a status code.
+    /// Ephemeral replies are sent using `response_url` of the event.
+    pub async fn handle_error_with_status(
+        &self,
+        err: BoxError,
+        context: SlackEventErrorContext,
+    ) -> HttpStatusCode {
+        let response_url = context.response_url.clone();
+        match self.handle_error(err, context).await {
+            SlackEventErrorResponse::Status(status_code) => status_code,
+            SlackEventErrorResponse::EphemeralReply(content) => {
+                match response_url {
+                    Some(response_url) => {
+                        if let Err(err) = self
+                            .client
+                            .respond_to_event(
+                                &response_url,
+                                &SlackApiPostWebhookMessageRequest::new(content),
+                            )
+                            .await
+                        {
+                            error!("Unable to send Slack error reply: {:?}", err);
+                        }
+                    }
+                    None => {
+                        warn!("Slack error reply is ignored, since the event has no response_url")
+                    }
+                }
+                HttpStatusCode::OK
+            }
+        }
+    }
+
     fn empty_error_handler(
         err: BoxError,
         _client: Arc<SlackClient<SCHC>>,
-        _user_state_storage: SlackClientEventsUserState,
+        _user_state_storage: S,
     ) -> http::StatusCode {
         error!("Slack listener error occurred: {:?}", err);
         http::StatusCode::BAD_REQUEST
     }
-
-    pub fn with_user_state<T: Send + Sync + 'static>(self, state: T) -> Self {
-        let future_init_state = self
-            .user_state
-            .write()
-            .map(|mut guard| guard.set_user_state(state));
-        block_on(future_init_state);
-        self
-    }
 }
 
 pub struct SlackClientEventsUserStateStorage {
@@ -94,10 +214,10 @@ impl SlackClientEventsUserStateStorage {
     }
 }
 
-pub type BoxedErrorHandler<SCHC> = Box<ErrorHandler<SCHC>>;
+pub type BoxedErrorHandler<SCHC, S = SlackClientEventsUserState> = Box<ErrorHandler<SCHC, S>>;
 
-pub type ErrorHandler<SCHC> =
-    fn(BoxError, Arc<SlackClient<SCHC>>, SlackClientEventsUserState) -> HttpStatusCode;
+pub type ErrorHandler<SCHC, S = SlackClientEventsUserState> =
+    fn(BoxError, Arc<SlackClient<SCHC>>, S) -> HttpStatusCode;
 
 #[derive(Debug, PartialEq, Eq, Clone, Builder)]
 pub struct SlackCommandEventsListenerConfig {
@@ -172,5 +292,7 @@ impl SlackOAuthListenerConfig {
     }
 }
 
+/// The plain function form of the listener callbacks.
+/// Listeners accept any `Fn` with the same signature, including closures capturing their context.
 pub type UserCallbackFunction<E, IF, SCHC> =
     fn(E, Arc<SlackClient<SCHC>>, SlackClientEventsUserState) -> IF;
@@ -0,0 +1,152 @@
+use crate::models::events::*;
+use crate::models::socket_mode::SlackSocketModeEnvelopeId;
+use async_trait::async_trait;
+use rvstruct::ValueStruct;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::*;
+
+/// A store for the keys of the processed events, which can be shared between instances
+/// of an app (e.g. implemented with Redis `SET NX PX`).
+#[async_trait]
+pub trait SlackEventsDedupStore {
+    /// Marks the key as seen for the specified TTL.
+    /// Returns `false` if the key has been already seen and its TTL hasn't expired yet.
+    async fn mark_seen(&self, key: &str, ttl: Duration) -> bool;
+
+    /// Removes the key, so a redelivery of an event that failed to be processed isn't skipped.
+    async fn forget(&self, key: &str);
+}
+
+/// The in-memory dedup store, suitable for a single instance of an app
+pub struct SlackEventsInMemoryDedupStore {
+    seen: Mutex<SlackEventsInMemorySeenKeys>,
+}
+
+struct SlackEventsInMemorySeenKeys {
+    expires_at: HashMap<String, Instant>,
+    next_eviction_at: Instant,
+}
+
+impl SlackEventsInMemoryDedupStore {
+    /// Expired keys are evicted at most once per this interval
+    pub const EVICTION_INTERVAL: Duration = Duration::from_secs(60);
+
+    pub fn new() -> Self {
+        Self {
+            seen: Mutex::new(SlackEventsInMemorySeenKeys {
+                expires_at: HashMap::new(),
+                next_eviction_at: Instant::now() + Self::EVICTION_INTERVAL,
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl SlackEventsDedupStore for SlackEventsInMemoryDedupStore {
+    async fn mark_seen(&self, key: &str, ttl: Duration) -> bool {
+        let now = Instant::now();
+        let mut seen = self.seen.lock().unwrap();
+        if now >= seen.next_eviction_at {
+            seen.expires_at.retain(|_, expires_at| *expires_at > now);
+            seen.next_eviction_at = now + Self::EVICTION_INTERVAL;
+        }
+        match seen.expires_at.get(key) {
+            Some(expires_at) if *expires_at > now => false,
+            _ => {
+                seen.expires_at.insert(key.to_string(), now + ttl);
+                true
+            }
+        }
+    }
+
+    async fn forget(&self, key: &str) {
+        self.seen.lock().unwrap().expires_at.remove(key);
+    }
+}
+
+///
+/// Detects redelivered events, so the listeners call the handlers only once for each of them.
+/// Push events are identified by `event_id`, and Socket Mode envelopes by `envelope_id`.
+///
+pub struct SlackEventsDeduplicator {
+    store: Arc<dyn SlackEventsDedupStore + Send + Sync>,
+    ttl: Duration,
+}
+
+impl SlackEventsDeduplicator {
+    /// Slack retries events for up to an hour after the first delivery
+    pub const DEFAULT_TTL: Duration = Duration::from_secs(60 * 60);
+
+    pub fn new() -> Self {
+        Self::with_store(Arc::new(SlackEventsInMemoryDedupStore::new()))
+    }
+
+    pub fn with_store(store: Arc<dyn SlackEventsDedupStore + Send + Sync>) -> Self {
+        Self {
+            store,
+            ttl: Self::DEFAULT_TTL,
+        }
+    }
+
+    pub fn with_ttl(self, ttl: Duration) -> Self {
+        Self { ttl, ..self }
+    }
+
+    pub async fn is_duplicate_push_event(&self, event: &SlackPushEventCallback) -> bool {
+        self.is_duplicate(format!("event:{}", event.event_id.value()))
+            .await
+    }
+
+    pub async fn is_duplicate_envelope(&self, envelope_id: &SlackSocketModeEnvelopeId) -> bool {
+        self.is_duplicate(format!("envelope:{}", envelope_id.value()))
+            .await
+    }
+
+    /// Forgets a push event after its handler failed, so Slack retries of it are processed
+    pub async fn forget_push_event(&self, event_id: &SlackEventId) {
+        self.forget(format!("event:{}", event_id.value())).await
+    }
+
+    /// Forgets a Socket Mode envelope after its handler failed
+    pub async fn forget_envelope(&self, envelope_id: &SlackSocketModeEnvelopeId) {
+        self.forget(format!("envelope:{}", envelope_id.value()))
+            .await
+    }
+
+    async fn is_duplicate(&self, key: String) -> bool {
+        let duplicate = !self.store.mark_seen(&key, self.ttl).await;
+        if duplicate {
+            debug!("Skipping a duplicate Slack event: {}", key);
+        }
+        duplicate
+    }
+
+    async fn forget(&self, key: String) {
+        debug!("Forgetting a failed Slack event: {}", key);
+        self.store.forget(&key).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_in_memory_dedup_store() {
+        let store = SlackEventsInMemoryDedupStore::new();
+        futures::executor::block_on(async {
+            assert!(store.mark_seen("event:Ev1", Duration::from_secs(60)).await);
+            assert!(!store.mark_seen("event:Ev1", Duration::from_secs(60)).await);
+            assert!(store.mark_seen("event:Ev2", Duration::from_secs(60)).await);
+
+            assert!(store.mark_seen("event:Ev3", Duration::ZERO).await);
+            assert!(store.mark_seen("event:Ev3", Duration::ZERO).await);
+
+            store.forget("event:Ev1").await;
+            assert!(store.mark_seen("event:Ev1", Duration::from_secs(60)).await);
+            assert!(!store.mark_seen("event:Ev1", Duration::from_secs(60)).await);
+        });
+    }
+}
@@ -0,0 +1,164 @@
+use crate::models::events::*;
+use crate::models::*;
+use crate::{BoxError, SlackClient};
+use futures::future::BoxFuture;
+use rsb_derive::Builder;
+use std::sync::Arc;
+
+use super::{HttpStatusCode, SlackClientEventsUserState};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SlackEventKind {
+    PushEvent,
+    Command,
+    Interaction,
+    OAuth,
+    Unknown,
+}
+
+/// What the listeners know about the failed event
+#[derive(Debug, PartialEq, Clone, Builder)]
+pub struct SlackEventErrorContext {
+    pub event_kind: SlackEventKind,
+    pub raw_payload: Option<String>,
+    pub team_id: Option<SlackTeamId>,
+    pub user_id: Option<SlackUserId>,
+    pub channel_id: Option<SlackChannelId>,
+    pub response_url: Option<SlackResponseUrl>,
+}
+
+impl SlackEventErrorContext {
+    pub fn from_push_event(event: &SlackPushEvent) -> Self {
+        match event {
+            SlackPushEvent::EventCallback(event_callback) => {
+                Self::from_push_event_callback(event_callback)
+            }
+            _ => Self::new(SlackEventKind::PushEvent),
+        }
+    }
+
+    pub fn from_push_event_callback(event: &SlackPushEventCallback) -> Self {
+        let (user_id, channel_id) = match &event.event {
+            SlackEventCallbackBody::Message(message) => {
+                (message.sender.user.clone(), message.origin.channel.clone())
+            }
+            SlackEventCallbackBody::AppMention(mention) => {
+                (Some(mention.user.clone()), Some(mention.channel.clone()))
+            }
+            SlackEventCallbackBody::AppHomeOpened(home_opened) => (
+                Some(home_opened.user.clone()),
+                Some(home_opened.channel.clone()),
+            ),
+            _ => (None, None),
+        };
+
+        Self::new(SlackEventKind::PushEvent)
+            .with_team_id(event.team_id.clone())
+            .opt_user_id(user_id)
+            .opt_channel_id(channel_id)
+    }
+
+    pub fn from_command_event(event: &SlackCommandEvent) -> Self {
+        Self::new(SlackEventKind::Command)
+            .with_team_id(event.team_id.clone())
+            .with_user_id(event.user_id.clone())
+            .with_channel_id(event.channel_id.clone())
+            .with_response_url(event.response_url.clone())
+    }
+
+    pub fn from_interaction_event(event: &SlackInteractionEvent) -> Self {
+        let context = Self::new(SlackEventKind::Interaction);
+        match event {
+            SlackInteractionEvent::BlockActions(block_actions) => context
+                .with_team_id(block_actions.team.id.clone())
+                .opt_user_id(block_actions.user.as_ref().map(|user| user.id.clone()))
+                .opt_channel_id(
+                    block_actions
+                        .channel
+                        .as_ref()
+                        .map(|channel| channel.id.clone()),
+                )
+                .opt_response_url(block_actions.response_url.clone()),
+            SlackInteractionEvent::BlockSuggestion(block_suggestion) => context
+                .with_team_id(block_suggestion.team.id.clone())
+                .with_user_id(block_suggestion.user.id.clone()),
+            SlackInteractionEvent::DialogSubmission(dialog_submission) => context
+                .with_team_id(dialog_submission.team.id.clone())
+                .with_user_id(dialog_submission.user.id.clone())
+                .opt_channel_id(
+                    dialog_submission
+                        .channel
+                        .as_ref()
+                        .map(|channel| channel.id.clone()),
+                ),
+            SlackInteractionEvent::MessageAction(message_action) => context
+                .with_team_id(message_action.team.id.clone())
+                .with_user_id(message_action.user.id.clone())
+                .opt_channel_id(
+                    message_action
+                        .channel
+                        .as_ref()
+                        .map(|channel| channel.id.clone()),
+                )
+                .with_response_url(message_action.response_url.clone()),
+            SlackInteractionEvent::Shortcut(shortcut) => context
+                .with_team_id(shortcut.team.id.clone())
+                .with_user_id(shortcut.user.id.clone()),
+            SlackInteractionEvent::ViewSubmission(view_submission) => context
+                .with_team_id(view_submission.team.id.clone())
+                .with_user_id(view_submission.user.id.clone()),
+            SlackInteractionEvent::ViewClosed(view_closed) => context
+                .with_team_id(view_closed.team.id.clone())
+                .with_user_id(view_closed.user.id.clone()),
+        }
+    }
+}
+
+/// What the listeners should do with the failed event
+#[derive(Debug, PartialEq, Clone)]
+pub enum SlackEventErrorResponse {
+    /// Respond to Slack with the status code
+    Status(HttpStatusCode),
+    /// Acknowledge the event and show the message only to the user.
+    /// It is sent as a command response for commands and using `response_url` for other events.
+    EphemeralReply(SlackMessageContent),
+}
+
+pub type AsyncErrorHandler<SCHC, S = SlackClientEventsUserState> = Box<
+    dyn Fn(
+            BoxError,
+            SlackEventErrorContext,
+            Arc<SlackClient<SCHC>>,
+            S,
+        ) -> BoxFuture<'static, SlackEventErrorResponse>
+        + Send
+        + Sync,
+>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_error_context_from_interaction_event() -> Result<(), Box<dyn std::error::Error>> {
+        let event = SlackInteractionEvent::MessageAction(
+            SlackInteractionMessageActionEvent::new(
+                SlackBasicTeamInfo::new("T1".into()),
+                SlackBasicUserInfo::new("U1".into()),
+                "callback".into(),
+                "trigger".into(),
+                url::Url::parse("https://hooks.slack.com/actions/T1/1/xyz")?.into(),
+            )
+            .with_channel(SlackBasicChannelInfo::new("C1".into())),
+        );
+
+        let context = SlackEventErrorContext::from_interaction_event(&event);
+
+        assert_eq!(context.event_kind, SlackEventKind::Interaction);
+        assert_eq!(context.team_id, Some("T1".into()));
+        assert_eq!(context.user_id, Some("U1".into()));
+        assert_eq!(context.channel_id, Some("C1".into()));
+        assert!(context.response_url.is_some());
+        Ok(())
+    }
+}
@@ -0,0 +1,872 @@
+use crate::errors::*;
+use crate::models::blocks::SlackView;
+use crate::models::events::*;
+use crate::models::*;
+use crate::*;
+use futures::future::BoxFuture;
+use futures::{FutureExt, TryFutureExt};
+#[cfg(feature = "regex")]
+use regex::Regex;
+use rvstruct::ValueStruct;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use tracing::*;
+
+use super::SlackClientEventsUserState;
+
+type BoxedSlackEventHandler<E, R, SCHC, S> = Box<
+    dyn Fn(E, Arc<SlackClient<SCHC>>, S) -> BoxFuture<'static, UserCallbackResult<R>> + Send + Sync,
+>;
+
+type BoxedSlackActionHandler<SCHC, S> = Box<
+    dyn Fn(
+            SlackInteractionActionInfo,
+            SlackInteractionBlockActionsEvent,
+            Arc<SlackClient<SCHC>>,
+            S,
+        ) -> BoxFuture<'static, UserCallbackResult<()>>
+        + Send
+        + Sync,
+>;
+
+type SlackPushEventRoute<SCHC, S> = Box<
+    dyn Fn(
+            &SlackPushEventCallback,
+            &Arc<SlackClient<SCHC>>,
+            &S,
+        ) -> Option<BoxFuture<'static, UserCallbackResult<()>>>
+        + Send
+        + Sync,
+>;
+
+/// A pattern to match action ids, block ids and message texts in the router.
+/// Regular expressions are available with the `regex` feature.
+#[derive(Debug, Clone)]
+pub enum SlackEventsRouterPattern {
+    Exact(String),
+    Prefix(String),
+    #[cfg(feature = "regex")]
+    Regex(Regex),
+}
+
+impl SlackEventsRouterPattern {
+    pub fn matches(&self, value: &str) -> bool {
+        match self {
+            SlackEventsRouterPattern::Exact(expected) => value == expected,
+            SlackEventsRouterPattern::Prefix(prefix) => value.starts_with(prefix.as_str()),
+            #[cfg(feature = "regex")]
+            SlackEventsRouterPattern::Regex(regex) => regex.is_match(value),
+        }
+    }
+}
+
+impl From<&str> for SlackEventsRouterPattern {
+    fn from(value: &str) -> Self {
+        SlackEventsRouterPattern::Exact(value.to_string())
+    }
+}
+
+impl From<String> for SlackEventsRouterPattern {
+    fn from(value: String) -> Self {
+        SlackEventsRouterPattern::Exact(value)
+    }
+}
+
+#[cfg(feature = "regex")]
+impl From<Regex> for SlackEventsRouterPattern {
+    fn from(value: Regex) -> Self {
+        SlackEventsRouterPattern::Regex(value)
+    }
+}
+
+fn boxed_handler<E, R, SCHC, S, F, FR>(handler: F) -> BoxedSlackEventHandler<E, R, SCHC, S>
+where
+    SCHC: SlackClientHttpConnector + Send + Sync + 'static,
+    S: Clone + Send + Sync + 'static,
+    E: Send + 'static,
+    F: Fn(E, Arc<SlackClient<SCHC>>, S) -> FR + Send + Sync + 'static,
+    FR: Future<Output = UserCallbackResult<R>> + Send + 'static,
+{
+    Box::new(move |event, client, user_state| handler(event, client, user_state).boxed())
+}
+
+fn boxed_interaction_handler<E, R, SCHC, S, F, FR>(
+    handler: F,
+) -> BoxedSlackEventHandler<E, Option<SlackInteractionEventResponsePayload>, SCHC, S>
+where
+    SCHC: SlackClientHttpConnector + Send + Sync + 'static,
+    S: Clone + Send + Sync + 'static,
+    E: Send + 'static,
+    F: Fn(E, Arc<SlackClient<SCHC>>, S) -> FR + Send + Sync + 'static,
+    FR: Future<Output = UserCallbackResult<R>> + Send + 'static,
+    R: SlackInteractionEventResult + 'static,
+{
+    Box::new(move |event, client, user_state| {
+        handler(event, client, user_state)
+            .map_ok(SlackInteractionEventResult::into_response_payload)
+            .boxed()
+    })
+}
+
+macro_rules! push_event_routes {
+    ($($(#[$meta:meta])* $method:ident => $variant:ident($event:ty)),* $(,)?) => {
+        $(
+            $(#[$meta])*
+            pub fn $method<F, FR>(self, handler: F) -> Self
+            where
+                F: Fn($event, Arc<SlackClient<SCHC>>, S) -> FR
+                    + Send
+                    + Sync
+                    + 'static,
+                FR: Future<Output = UserCallbackResult<()>> + Send + 'static,
+            {
+                self.on_push_event(
+                    |event| match &event.event {
+                        SlackEventCallbackBody::$variant(ev) => Some(ev.clone()),
+                        _ => None,
+                    },
+                    handler,
+                )
+            }
+        )*
+    };
+}
+
+///
+/// Routes push events, slash commands and interactions to the handlers registered
+/// for them, so there is no need to match all the events in a single callback.
+///
+/// Push event routes are checked in the order they were registered and only the first matching
+/// route is called. Events without a matching route go to the fallback handlers.
+///
+/// The router plugs into Socket Mode with `SlackSocketModeListenerCallbacks::with_events_router`
+/// and into the HTTP listeners using the callback functions it provides (e.g. `push_events_fn`).
+///
+pub struct SlackEventsRouter<SCHC, S = SlackClientEventsUserState>
+where
+    SCHC: SlackClientHttpConnector + Send + Sync,
+    S: Clone + Send + Sync + 'static,
+{
+    push_event_routes: Vec<SlackPushEventRoute<SCHC, S>>,
+    push_events_fallback: BoxedSlackEventHandler<SlackPushEventCallback, (), SCHC, S>,
+    command_handlers: HashMap<
+        SlackCommandId,
+        BoxedSlackEventHandler<SlackCommandEvent, SlackCommandEventResponse, SCHC, S>,
+    >,
+    command_fallback: BoxedSlackEventHandler<SlackCommandEvent, SlackCommandEventResponse, SCHC, S>,
+    action_id_handlers: Vec<(SlackEventsRouterPattern, BoxedSlackActionHandler<SCHC, S>)>,
+    block_id_handlers: Vec<(SlackEventsRouterPattern, BoxedSlackActionHandler<SCHC, S>)>,
+    block_suggestion_handlers: Vec<(
+        SlackEventsRouterPattern,
+        BoxedSlackEventHandler<
+            SlackInteractionBlockSuggestionEvent,
+            SlackBlockSuggestionResponse,
+            SCHC,
+            S,
+        >,
+    )>,
+    view_submission_handlers: HashMap<
+        SlackCallbackId,
+        BoxedSlackEventHandler<
+            SlackInteractionViewSubmissionEvent,
+            Option<SlackInteractionEventResponsePayload>,
+            SCHC,
+            S,
+        >,
+    >,
+    view_closed_handlers: HashMap<
+        SlackCallbackId,
+        BoxedSlackEventHandler<SlackInteractionViewClosedEvent, (), SCHC, S>,
+    >,
+    shortcut_handlers: HashMap<
+        SlackCallbackId,
+        BoxedSlackEventHandler<SlackInteractionShortcutEvent, (), SCHC, S>,
+    >,
+    message_action_handlers: HashMap<
+        SlackCallbackId,
+        BoxedSlackEventHandler<SlackInteractionMessageActionEvent, (), SCHC, S>,
+    >,
+    interaction_fallback: BoxedSlackEventHandler<
+        SlackInteractionEvent,
+        Option<SlackInteractionEventResponsePayload>,
+        SCHC,
+        S,
+    >,
+}
+
+impl<SCHC, S> SlackEventsRouter<SCHC, S>
+where
+    SCHC: SlackClientHttpConnector + Send + Sync + 'static,
+    S: Clone + Send + Sync + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            push_event_routes: Vec::new(),
+            push_events_fallback: boxed_handler(Self::empty_push_events_fallback),
+            command_handlers: HashMap::new(),
+            command_fallback: boxed_handler(Self::empty_command_fallback),
+            action_id_handlers: Vec::new(),
+            block_id_handlers: Vec::new(),
+            block_suggestion_handlers: Vec::new(),
+            view_submission_handlers: HashMap::new(),
+            view_closed_handlers: HashMap::new(),
+            shortcut_handlers: HashMap::new(),
+            message_action_handlers: HashMap::new(),
+            interaction_fallback: boxed_handler(Self::empty_interaction_fallback),
+        }
+    }
+
+    /// Registers a handler for push events selected by the `extract` function.
+    /// This is useful for the events without a dedicated method or when the handler needs
+    /// more than the event body (e.g. `team_id` from the envelope).
+    pub fn on_push_event<E, EX, F, FR>(mut self, extract: EX, handler: F) -> Self
+    where
+        E: Send + 'static,
+        EX: Fn(&SlackPushEventCallback) -> Option<E> + Send + Sync + 'static,
+        F: Fn(E, Arc<SlackClient<SCHC>>, S) -> FR + Send + Sync + 'static,
+        FR: Future<Output = UserCallbackResult<()>> + Send + 'static,
+    {
+        self.push_event_routes
+            .push(Box::new(move |event, client, user_state| {
+                extract(event).map(|typed_event| {
+                    handler(typed_event, client.clone(), user_state.clone()).boxed()
+                })
+            }));
+        self
+    }
+
+    push_event_routes! {
+        on_message => Message(SlackMessageEvent),
+        on_app_home_opened => AppHomeOpened(SlackAppHomeOpenedEvent),
+        on_app_mention => AppMention(SlackAppMentionEvent),
+        on_app_uninstalled => AppUninstalled(SlackAppUninstalledEvent),
+        on_link_shared => LinkShared(SlackLinkSharedEvent),
+        on_emoji_changed => EmojiChanged(SlackEmojiChangedEvent),
+        on_member_joined_channel => MemberJoinedChannel(SlackMemberJoinedChannelEvent),
+        on_member_left_channel => MemberLeftChannel(SlackMemberLeftChannelEvent),
+        on_channel_created => ChannelCreated(SlackChannelCreatedEvent),
+        on_channel_deleted => ChannelDeleted(SlackChannelDeletedEvent),
+        on_channel_archive => ChannelArchive(SlackChannelArchiveEvent),
+        on_channel_rename => ChannelRename(SlackChannelRenameEvent),
+        on_channel_unarchive => ChannelUnarchive(SlackChannelUnarchiveEvent),
+        on_team_join => TeamJoin(SlackTeamJoinEvent),
+        on_file_created => FileCreated(SlackFileCreatedEvent),
+        on_file_change => FileChange(SlackFileChangedEvent),
+        on_file_deleted => FileDeleted(SlackFileDeletedEvent),
+        on_file_shared => FileShared(SlackFileSharedEvent),
+        on_file_unshared => FileUnshared(SlackFileUnsharedEvent),
+        on_file_public => FilePublic(SlackFilePublicEvent),
+        on_reaction_added => ReactionAdded(SlackReactionAddedEvent),
+        on_reaction_removed => ReactionRemoved(SlackReactionRemovedEvent),
+        on_star_added => StarAdded(SlackStarAddedEvent),
+        on_star_removed => StarRemoved(SlackStarRemovedEvent),
+        on_user_change => UserChange(SlackUserChangeEvent),
+        on_user_status_changed => UserStatusChanged(SlackUserStatusChangedEvent),
+        on_assistant_thread_started => AssistantThreadStarted(SlackAssistantThreadStartedEvent),
+        on_assistant_thread_context_changed => AssistantThreadContextChanged(SlackAssistantThreadContextChangedEvent),
+        on_call_rejected => CallRejected(SlackCallRejectedEvent),
+        on_function_executed => FunctionExecuted(SlackFunctionExecutedEvent),
+    }
+
+    /// Registers a handler for message events with a text matching the pattern
+    pub fn on_message_matching<P, F, FR>(self, pattern: P, handler: F) -> Self
+    where
+        P: Into<SlackEventsRouterPattern>,
+        F: Fn(SlackMessageEvent, Arc<SlackClient<SCHC>>, S) -> FR + Send + Sync + 'static,
+        FR: Future<Output = UserCallbackResult<()>> + Send + 'static,
+    {
+        let pattern = pattern.into();
+        self.on_push_event(
+            move |event| match &event.event {
+                SlackEventCallbackBody::Message(message)
+                    if message
+                        .content
+                        .as_ref()
+                        .and_then(|content| content.text.as_ref())
+                        .iter()
+                        .any(|text| pattern.matches(text)) =>
+                {
+                    Some(message.clone())
+                }
+                _ => None,
+            },
+            handler,
+        )
+    }
+
+    /// Handles push events without a matching route. By default, they are logged and ignored.
+    pub fn with_push_events_fallback<F, FR>(mut self, handler: F) -> Self
+    where
+        F: Fn(SlackPushEventCallback, Arc<SlackClient<SCHC>>, S) -> FR + Send + Sync + 'static,
+        FR: Future<Output = UserCallbackResult<()>> + Send + 'static,
+    {
+        self.push_events_fallback = boxed_handler(handler);
+        self
+    }
+
+    pub fn on_command<F, FR>(mut self, command: SlackCommandId, handler: F) -> Self
+    where
+        F: Fn(SlackCommandEvent, Arc<SlackClient<SCHC>>, S) -> FR + Send + Sync + 'static,
+        FR: Future<Output = UserCallbackResult<SlackCommandEventResponse>> + Send + 'static,
+    {
+        self.command_handlers
+            .insert(command, boxed_handler(handler));
+        self
+    }
+
+    /// Handles commands without a registered handler. By default, it returns an error.
+    pub fn with_command_fallback<F, FR>(mut self, handler: F) -> Self
+    where
+        F: Fn(SlackCommandEvent, Arc<SlackClient<SCHC>>, S) -> FR + Send + Sync + 'static,
+        FR: Future<Output = UserCallbackResult<SlackCommandEventResponse>> + Send + 'static,
+    {
+        self.command_fallback = boxed_handler(handler);
+        self
+    }
+
+    /// Registers a handler for block actions with an `action_id` matching the pattern.
+    /// The handler receives the matched action and the whole block actions event.
+    pub fn on_action_id<P, F, FR>(mut self, pattern: P, handler: F) -> Self
+    where
+        P: Into<SlackEventsRouterPattern>,
+        F: Fn(
+                SlackInteractionActionInfo,
+                SlackInteractionBlockActionsEvent,
+                Arc<SlackClient<SCHC>>,
+                S,
+            ) -> FR
+            + Send
+            + Sync
+            + 'static,
+        FR: Future<Output = UserCallbackResult<()>> + Send + 'static,
+    {
+        self.action_id_handlers.push((
+            pattern.into(),
+            Box::new(move |action, event, client, user_state| {
+                handler(action, event, client, user_state).boxed()
+            }),
+        ));
+        self
+    }
+
+    /// Registers a handler for block actions with a `block_id` matching the pattern.
+    /// The handler receives the matched action and the whole block actions event.
+    pub fn on_block_id<P, F, FR>(mut self, pattern: P, handler: F) -> Self
+    where
+        P: Into<SlackEventsRouterPattern>,
+        F: Fn(
+                SlackInteractionActionInfo,
+                SlackInteractionBlockActionsEvent,
+                Arc<SlackClient<SCHC>>,
+                S,
+            ) -> FR
+            + Send
+            + Sync
+            + 'static,
+        FR: Future<Output = UserCallbackResult<()>> + Send + 'static,
+    {
+        self.block_id_handlers.push((
+            pattern.into(),
+            Box::new(move |action, event, client, user_state| {
+                handler(action, event, client, user_state).boxed()
+            }),
+        ));
+        self
+    }
+
+    /// Registers a handler for the options requests of external selects with an `action_id` matching the pattern
+    pub fn on_block_suggestion<P, F, FR>(mut self, pattern: P, handler: F) -> Self
+    where
+        P: Into<SlackEventsRouterPattern>,
+        F: Fn(SlackInteractionBlockSuggestionEvent, Arc<SlackClient<SCHC>>, S) -> FR
+            + Send
+            + Sync
+            + 'static,
+        FR: Future<Output = UserCallbackResult<SlackBlockSuggestionResponse>> + Send + 'static,
+    {
+        self.block_suggestion_handlers
+            .push((pattern.into(), boxed_handler(handler)));
+        self
+    }
+
+    /// Registers a handler for view submissions. It may return `SlackViewSubmissionResponse` or `()`.
+    pub fn on_view_submission<F, FR, R>(mut self, callback_id: SlackCallbackId, handler: F) -> Self
+    where
+        F: Fn(SlackInteractionViewSubmissionEvent, Arc<SlackClient<SCHC>>, S) -> FR
+            + Send
+            + Sync
+            + 'static,
+        FR: Future<Output = UserCallbackResult<R>> + Send + 'static,
+        R: SlackInteractionEventResult + 'static,
+    {
+        self.view_submission_handlers
+            .insert(callback_id, boxed_interaction_handler(handler));
+        self
+    }
+
+    pub fn on_view_closed<F, FR>(mut self, callback_id: SlackCallbackId, handler: F) -> Self
+    where
+        F: Fn(SlackInteractionViewClosedEvent, Arc<SlackClient<SCHC>>, S) -> FR
+            + Send
+            + Sync
+            + 'static,
+        FR: Future<Output = UserCallbackResult<()>> + Send + 'static,
+    {
+        self.view_closed_handlers
+            .insert(callback_id, boxed_handler(handler));
+        self
+    }
+
+    /// Registers a handler for global shortcuts
+    pub fn on_shortcut<F, FR>(mut self, callback_id: SlackCallbackId, handler: F) -> Self
+    where
+        F: Fn(SlackInteractionShortcutEvent, Arc<SlackClient<SCHC>>, S) -> FR
+            + Send
+            + Sync
+            + 'static,
+        FR: Future<Output = UserCallbackResult<()>> + Send + 'static,
+    {
+        self.shortcut_handlers
+            .insert(callback_id, boxed_handler(handler));
+        self
+    }
+
+    /// Registers a handler for message shortcuts
+    pub fn on_message_action<F, FR>(mut self, callback_id: SlackCallbackId, handler: F) -> Self
+    where
+        F: Fn(SlackInteractionMessageActionEvent, Arc<SlackClient<SCHC>>, S) -> FR
+            + Send
+            + Sync
+            + 'static,
+        FR: Future<Output = UserCallbackResult<()>> + Send + 'static,
+    {
+        self.message_action_handlers
+            .insert(callback_id, boxed_handler(handler));
+        self
+    }
+
+    /// Handles interactions without a registered handler. By default, they are logged and ignored.
+    pub fn with_interaction_fallback<F, FR, R>(mut self, handler: F) -> Self
+    where
+        F: Fn(SlackInteractionEvent, Arc<SlackClient<SCHC>>, S) -> FR + Send + Sync + 'static,
+        FR: Future<Output = UserCallbackResult<R>> + Send + 'static,
+        R: SlackInteractionEventResult + 'static,
+    {
+        self.interaction_fallback = boxed_interaction_handler(handler);
+        self
+    }
+
+    /// Routes an HTTP push event. URL verification and rate limited events are ignored here,
+    /// since the listeners handle them on their own.
+    pub async fn route_push_event(
+        &self,
+        event: SlackPushEvent,
+        client: Arc<SlackClient<SCHC>>,
+        user_state: S,
+    ) -> UserCallbackResult<()> {
+        match event {
+            SlackPushEvent::EventCallback(event_callback) => {
+                self.route_push_event_callback(event_callback, client, user_state)
+                    .await
+            }
+            other => {
+                debug!("Slack events router ignored a push event: {:?}", other);
+                Ok(())
+            }
+        }
+    }
+
+    pub async fn route_push_event_callback(
+        &self,
+        event: SlackPushEventCallback,
+        client: Arc<SlackClient<SCHC>>,
+        user_state: S,
+    ) -> UserCallbackResult<()> {
+        match self
+            .push_event_routes
+            .iter()
+            .find_map(|route| route(&event, &client, &user_state))
+        {
+            Some(handler_future) => handler_future.await,
+            None => (self.push_events_fallback)(event, client, user_state).await,
+        }
+    }
+
+    pub async fn route_command_event(
+        &self,
+        event: SlackCommandEvent,
+        client: Arc<SlackClient<SCHC>>,
+        user_state: S,
+    ) -> UserCallbackResult<SlackCommandEventResponse> {
+        match self.command_handlers.get(&event.command) {
+            Some(handler) => handler(event, client, user_state).await,
+            None => (self.command_fallback)(event, client, user_state).await,
+        }
+    }
+
+    pub async fn route_interaction_event(
+        &self,
+        event: SlackInteractionEvent,
+        client: Arc<SlackClient<SCHC>>,
+        user_state: S,
+    ) -> UserCallbackResult<Option<SlackInteractionEventResponsePayload>> {
+        match event {
+            SlackInteractionEvent::BlockActions(block_actions) => {
+                match self.find_action_handler(&block_actions) {
+                    Some((action, handler)) => handler(action, block_actions, client, user_state)
+                        .await
+                        .map(|_| None),
+                    None => {
+                        (self.interaction_fallback)(
+                            SlackInteractionEvent::BlockActions(block_actions),
+                            client,
+                            user_state,
+                        )
+                        .await
+                    }
+                }
+            }
+            SlackInteractionEvent::BlockSuggestion(block_suggestion) => {
+                match self
+                    .block_suggestion_handlers
+                    .iter()
+                    .find(|(pattern, _)| pattern.matches(block_suggestion.action_id.value()))
+                {
+                    Some((_, handler)) => handler(block_suggestion, client, user_state)
+                        .await
+                        .map(|response| Some(response.into())),
+                    None => {
+                        (self.interaction_fallback)(
+                            SlackInteractionEvent::BlockSuggestion(block_suggestion),
+                            client,
+                            user_state,
+                        )
+                        .await
+                    }
+                }
+            }
+            SlackInteractionEvent::ViewSubmission(view_submission) => {
+                match Self::view_callback_id(&view_submission.view.view)
+                    .and_then(|callback_id| self.view_submission_handlers.get(callback_id))
+                {
+                    Some(handler) => handler(view_submission, client, user_state).await,
+                    None => {
+                        (self.interaction_fallback)(
+                            SlackInteractionEvent::ViewSubmission(view_submission),
+                            client,
+                            user_state,
+                        )
+                        .await
+                    }
+                }
+            }
+            SlackInteractionEvent::ViewClosed(view_closed) => {
+                match Self::view_callback_id(&view_closed.view.view)
+                    .and_then(|callback_id| self.view_closed_handlers.get(callback_id))
+                {
+                    Some(handler) => handler(view_closed, client, user_state).await.map(|_| None),
+                    None => {
+                        (self.interaction_fallback)(
+                            SlackInteractionEvent::ViewClosed(view_closed),
+                            client,
+                            user_state,
+                        )
+                        .await
+                    }
+                }
+            }
+            SlackInteractionEvent::Shortcut(shortcut) => {
+                match self.shortcut_handlers.get(&shortcut.callback_id) {
+                    Some(handler) => handler(shortcut, client, user_state).await.map(|_| None),
+                    None => {
+                        (self.interaction_fallback)(
+                            SlackInteractionEvent::Shortcut(shortcut),
+                            client,
+                            user_state,
+                        )
+                        .await
+                    }
+                }
+            }
+            SlackInteractionEvent::MessageAction(message_action) => {
+                match self
+                    .message_action_handlers
+                    .get(&message_action.callback_id)
+                {
+                    Some(handler) => handler(message_action, client, user_state)
+                        .await
+                        .map(|_| None),
+                    None => {
+                        (self.interaction_fallback)(
+                            SlackInteractionEvent::MessageAction(message_action),
+                            client,
+                            user_state,
+                        )
+                        .await
+                    }
+                }
+            }
+            other => (self.interaction_fallback)(other, client, user_state).await,
+        }
+    }
+
+    /// The push events callback for the HTTP listeners
+    pub fn push_events_fn(
+        self: &Arc<Self>,
+    ) -> impl Fn(
+        SlackPushEvent,
+        Arc<SlackClient<SCHC>>,
+        S,
+    ) -> BoxFuture<'static, UserCallbackResult<()>>
+           + Send
+           + Sync
+           + 'static {
+        let router = self.clone();
+        move |event, client, user_state| {
+            let router = router.clone();
+            async move { router.route_push_event(event, client, user_state).await }.boxed()
+        }
+    }
+
+    /// The command events callback for the HTTP listeners
+    pub fn command_events_fn(
+        self: &Arc<Self>,
+    ) -> impl Fn(
+        SlackCommandEvent,
+        Arc<SlackClient<SCHC>>,
+        S,
+    ) -> BoxFuture<'static, UserCallbackResult<SlackCommandEventResponse>>
+           + Send
+           + Sync
+           + 'static {
+        let router = self.clone();
+        move |event, client, user_state| {
+            let router = router.clone();
+            async move { router.route_command_event(event, client, user_state).await }.boxed()
+        }
+    }
+
+    /// The interaction events callback for the HTTP listeners
+    pub fn interaction_events_fn(
+        self: &Arc<Self>,
+    ) -> impl Fn(
+        SlackInteractionEvent,
+        Arc<SlackClient<SCHC>>,
+        S,
+    ) -> BoxFuture<
+        'static,
+        UserCallbackResult<Option<SlackInteractionEventResponsePayload>>,
+    > + Send
+           + Sync
+           + 'static {
+        let router = self.clone();
+        move |event, client, user_state| {
+            let router = router.clone();
+            async move {
+                router
+                    .route_interaction_event(event, client, user_state)
+                    .await
+            }
+            .boxed()
+        }
+    }
+
+    fn find_action_handler(
+        &self,
+        event: &SlackInteractionBlockActionsEvent,
+    ) -> Option<(
+        SlackInteractionActionInfo,
+        &BoxedSlackActionHandler<SCHC, S>,
+    )> {
+        let actions = event.actions.as_ref()?;
+        actions.iter().find_map(|action| {
+            self.action_id_handlers
+                .iter()
+                .find(|(pattern, _)| pattern.matches(action.action_id.value()))
+                .or_else(|| {
+                    action.block_id.as_ref().and_then(|block_id| {
+                        self.block_id_handlers
+                            .iter()
+                            .find(|(pattern, _)| pattern.matches(block_id.value()))
+                    })
+                })
+                .map(|(_, handler)| (action.clone(), handler))
+        })
+    }
+
+    fn view_callback_id(view: &SlackView) -> Option<&SlackCallbackId> {
+        match view {
+            SlackView::Home(home_view) => home_view.callback_id.as_ref(),
+            SlackView::Modal(modal_view) => modal_view.callback_id.as_ref(),
+        }
+    }
+
+    async fn empty_push_events_fallback(
+        event: SlackPushEventCallback,
+        _client: Arc<SlackClient<SCHC>>,
+        _user_state: S,
+    ) -> UserCallbackResult<()> {
+        debug!("No route is specified for a push event: {:?}", event);
+        Ok(())
+    }
+
+    async fn empty_command_fallback(
+        event: SlackCommandEvent,
+        _client: Arc<SlackClient<SCHC>>,
+        _user_state: S,
+    ) -> UserCallbackResult<SlackCommandEventResponse> {
+        warn!("No route is specified for a command event: {:?}", event);
+        Err(Box::new(SlackClientError::SystemError(
+            SlackClientSystemError::new().with_message(format!(
+                "No route is specified for the command: {}",
+                event.command
+            )),
+        )))
+    }
+
+    async fn empty_interaction_fallback(
+        event: SlackInteractionEvent,
+        _client: Arc<SlackClient<SCHC>>,
+        _user_state: S,
+    ) -> UserCallbackResult<Option<SlackInteractionEventResponsePayload>> {
+        debug!(
+            "No route is specified for an interaction event: {:?}",
+            event
+        );
+        Ok(None)
+    }
+}
+
+#[cfg(all(test, feature = "hyper"))]
+mod test {
+    use super::*;
+    use crate::blocks::*;
+    use crate::hyper_tokio::SlackClientHyperHttpsConnector;
+
+    fn block_actions_event(action_id: &str, block_id: &str) -> SlackInteractionBlockActionsEvent {
+        serde_json::from_value(serde_json::json!({
+            "team": { "id": "T123" },
+            "api_app_id": "A123",
+            "container": { "type": "view", "view_id": "V123" },
+            "trigger_id": "123.456",
+            "actions": [
+                { "type": "button", "action_id": action_id, "block_id": block_id }
+            ]
+        }))
+        .unwrap()
+    }
+
+    async fn ok_action_handler(
+        _action: SlackInteractionActionInfo,
+        _event: SlackInteractionBlockActionsEvent,
+        _client: Arc<SlackClient<SlackClientHyperHttpsConnector>>,
+        _user_state: SlackClientEventsUserState,
+    ) -> UserCallbackResult<()> {
+        Ok(())
+    }
+
+    #[derive(Clone)]
+    struct TestAppState {
+        greeting: Arc<String>,
+    }
+
+    #[test]
+    fn test_router_with_app_state() {
+        let router: SlackEventsRouter<SlackClientHyperHttpsConnector, TestAppState> =
+            SlackEventsRouter::new().on_command(
+                "/hello".into(),
+                |_event, _client, state: TestAppState| async move {
+                    Ok(SlackCommandEventResponse::new(
+                        SlackMessageContent::new().with_text(state.greeting.to_string()),
+                    ))
+                },
+            );
+        assert!(router.command_handlers.contains_key(&"/hello".into()));
+    }
+
+    #[test]
+    fn test_router_patterns() {
+        assert!(SlackEventsRouterPattern::from("approve").matches("approve"));
+        assert!(!SlackEventsRouterPattern::from("approve").matches("approve-1"));
+        assert!(SlackEventsRouterPattern::Prefix("approve-".into()).matches("approve-1"));
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_router_regex_patterns() {
+        assert!(
+            SlackEventsRouterPattern::from(Regex::new(r"^deploy \w+$").unwrap())
+                .matches("deploy prod")
+        );
+        assert!(
+            !SlackEventsRouterPattern::from(Regex::new(r"^deploy \w+$").unwrap())
+                .matches("rollback prod")
+        );
+    }
+
+    #[test]
+    fn test_router_finds_action_handlers() {
+        let router: SlackEventsRouter<SlackClientHyperHttpsConnector> = SlackEventsRouter::new()
+            .on_action_id(
+                SlackEventsRouterPattern::Prefix("approve-".into()),
+                ok_action_handler,
+            )
+            .on_block_id("settings-block", ok_action_handler);
+
+        let (action, _) = router
+            .find_action_handler(&block_actions_event("approve-42", "any-block"))
+            .unwrap();
+        assert_eq!(action.action_id, "approve-42".into());
+
+        let (action, _) = router
+            .find_action_handler(&block_actions_event("toggle", "settings-block"))
+            .unwrap();
+        assert_eq!(action.action_id, "toggle".into());
+
+        assert!(router
+            .find_action_handler(&block_actions_event("reject-42", "other-block"))
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_router_responds_to_block_suggestions() -> Result<(), Box<dyn std::error::Error>> {
+        let router: SlackEventsRouter<SlackClientHyperHttpsConnector, ()> = SlackEventsRouter::new(
+        )
+        .on_block_suggestion("services", |event, _client, _states| async move {
+            Ok(SlackBlockSuggestionResponse::Options(
+                SlackBlockSuggestionOptions::new(vec![SlackBlockChoiceItem::new(
+                    pt!(event.value.clone()),
+                    event.value,
+                )]),
+            ))
+        });
+
+        let event: SlackInteractionEvent = serde_json::from_value(serde_json::json!({
+            "type": "block_suggestion",
+            "team": { "id": "T123" },
+            "user": { "id": "U123" },
+            "api_app_id": "A123",
+            "block_id": "service-block",
+            "action_id": "services",
+            "container": { "type": "view", "view_id": "V123" },
+            "value": "billing"
+        }))?;
+
+        let client = Arc::new(SlackClient::new(
+            crate::hyper_tokio::SlackClientHyperConnector::new()?,
+        ));
+        let response = router
+            .route_interaction_event(event, client, ())
+            .await
+            .map_err(|err| err.to_string())?;
+
+        assert_eq!(
+            serde_json::to_value(response)?,
+            serde_json::json!({
+                "options": [{ "text": { "type": "plain_text", "text": "billing" }, "value": "billing" }]
+            })
+        );
+        Ok(())
+    }
+}
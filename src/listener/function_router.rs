@@ -0,0 +1,306 @@
+use crate::api::*;
+use crate::errors::*;
+use crate::models::events::*;
+use crate::models::*;
+use crate::*;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use tracing::*;
+
+use super::SlackClientEventsUserState;
+
+type SlackFunctionOutputs = HashMap<String, serde_json::Value>;
+
+type BoxedSlackFunctionHandler<SCHC, S> = Box<
+    dyn Fn(
+            SlackFunctionExecutedEvent,
+            Arc<SlackClient<SCHC>>,
+            S,
+        ) -> BoxFuture<'static, UserCallbackResult<SlackFunctionOutputs>>
+        + Send
+        + Sync,
+>;
+
+/// Routes `function_executed` events to typed handlers by the function `callback_id`.
+///
+/// Inputs are deserialized into the handler input type and handler outputs are reported back
+/// with `functions.completeSuccess` (handlers without outputs can return `()`). Handler errors (including invalid inputs) are reported
+/// with `functions.completeError`. Both calls use the `bot_access_token` of the execution.
+pub struct SlackFunctionExecutedRouter<SCHC, S = SlackClientEventsUserState>
+where
+    SCHC: SlackClientHttpConnector + Send + Sync,
+    S: Clone + Send + Sync + 'static,
+{
+    handlers: HashMap<SlackCallbackId, BoxedSlackFunctionHandler<SCHC, S>>,
+}
+
+impl<SCHC, S> Default for SlackFunctionExecutedRouter<SCHC, S>
+where
+    SCHC: SlackClientHttpConnector + Send + Sync + 'static,
+    S: Clone + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<SCHC, S> SlackFunctionExecutedRouter<SCHC, S>
+where
+    SCHC: SlackClientHttpConnector + Send + Sync + 'static,
+    S: Clone + Send + Sync + 'static,
+{
+    pub fn new() -> Self {
+        Self {
+            handlers: HashMap::new(),
+        }
+    }
+
+    pub fn on<I, O, F, FR>(mut self, callback_id: SlackCallbackId, handler: F) -> Self
+    where
+        I: DeserializeOwned + Send + 'static,
+        O: Serialize + Send + 'static,
+        F: Fn(I, SlackFunctionExecutedEvent, Arc<SlackClient<SCHC>>, S) -> FR
+            + Send
+            + Sync
+            + 'static,
+        FR: Future<Output = UserCallbackResult<O>> + Send + 'static,
+    {
+        let handler = Arc::new(handler);
+        self.handlers.insert(
+            callback_id,
+            Box::new(move |event, client, user_state| {
+                let handler = handler.clone();
+                async move {
+                    let inputs: I = event.inputs_as()?;
+                    let outputs = handler(inputs, event, client, user_state).await?;
+                    Ok(function_outputs(outputs)?)
+                }
+                .boxed()
+            }),
+        );
+        self
+    }
+
+    pub fn has_handler(&self, callback_id: &SlackCallbackId) -> bool {
+        self.handlers.contains_key(callback_id)
+    }
+
+    /// Returns `Ok(false)` if there is no handler registered for the function callback id.
+    pub async fn route(
+        &self,
+        event: SlackFunctionExecutedEvent,
+        client: Arc<SlackClient<SCHC>>,
+        user_state: S,
+    ) -> UserCallbackResult<bool> {
+        let handler = match self.handlers.get(&event.function.callback_id) {
+            Some(handler) => handler,
+            None => return Ok(false),
+        };
+
+        let token = match event.bot_access_token.clone() {
+            Some(token_value) => SlackApiToken::new(token_value),
+            None => {
+                return Err(SlackClientError::SystemError(
+                    SlackClientSystemError::new().with_message(
+                        "function_executed event doesn't contain bot_access_token".into(),
+                    ),
+                )
+                .into())
+            }
+        };
+        let function_execution_id = event.function_execution_id.clone();
+
+        let result = handler(event, client.clone(), user_state).await;
+        let session = client.open_session(&token);
+
+        match result {
+            Ok(outputs) => {
+                session
+                    .functions_complete_success(&SlackApiFunctionsCompleteSuccessRequest::new(
+                        function_execution_id,
+                        outputs,
+                    ))
+                    .await?;
+            }
+            Err(err) => {
+                debug!("Slack function execution failed: {}", err);
+                session
+                    .functions_complete_error(&SlackApiFunctionsCompleteErrorRequest::new(
+                        function_execution_id,
+                        err.to_string(),
+                    ))
+                    .await?;
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+/// Handlers without outputs (e.g. returning `()`) complete the function with empty outputs
+fn function_outputs<O: Serialize>(outputs: O) -> ClientResult<SlackFunctionOutputs> {
+    match serde_json::to_value(outputs).map_err(|err| map_serde_error(err, None))? {
+        serde_json::Value::Null => Ok(HashMap::new()),
+        serde_json::Value::Object(outputs) => Ok(outputs.into_iter().collect()),
+        other => Err(SlackClientError::SystemError(
+            SlackClientSystemError::new().with_message(format!(
+                "Slack function outputs must be serialized to a JSON object, got: {}",
+                other
+            )),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_connector::SlackTestHttpConnector;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct SampleInputs {
+        user_id: SlackUserId,
+    }
+
+    #[derive(Serialize)]
+    struct SampleOutputs {
+        message: String,
+    }
+
+    fn sample_router() -> SlackFunctionExecutedRouter<SlackTestHttpConnector, ()> {
+        SlackFunctionExecutedRouter::new().on(
+            "sample_function".into(),
+            |inputs: SampleInputs, _event, _client, _state| async move {
+                Ok(SampleOutputs {
+                    message: format!("Hello, <@{}>", inputs.user_id),
+                })
+            },
+        )
+    }
+
+    fn function_executed_event() -> SlackFunctionExecutedEvent {
+        let payload = include_str!("../models/events/fixtures/function_executed.json");
+        match serde_json::from_str::<SlackPushEventCallback>(payload)
+            .unwrap()
+            .event
+        {
+            SlackEventCallbackBody::FunctionExecuted(event) => event,
+            other => panic!("Unexpected event: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_routes_by_callback_id_with_typed_inputs() {
+        let client = Arc::new(SlackClient::new(SlackTestHttpConnector::new()));
+        client
+            .http_api
+            .connector
+            .respond("functions.completeSuccess", serde_json::json!({}));
+
+        let routed = sample_router()
+            .route(function_executed_event(), client.clone(), ())
+            .await
+            .unwrap();
+
+        assert!(routed);
+        assert_eq!(
+            client
+                .http_api
+                .connector
+                .requests("functions.completeSuccess"),
+            vec![serde_json::json!({
+                "function_execution_id": "Fx05RXD4PS9Y",
+                "outputs": { "message": "Hello, <@U01F1234567>" }
+            })]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_skips_unknown_callback_ids() {
+        let client = Arc::new(SlackClient::new(SlackTestHttpConnector::new()));
+        let mut event = function_executed_event();
+        event.function.callback_id = "other_function".into();
+
+        let router = sample_router();
+        assert!(!router.has_handler(&event.function.callback_id));
+        assert!(!router.route(event, client.clone(), ()).await.unwrap());
+        assert!(client
+            .http_api
+            .connector
+            .requests("functions.completeSuccess")
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reports_invalid_inputs_as_errors() {
+        let client = Arc::new(SlackClient::new(SlackTestHttpConnector::new()));
+        client
+            .http_api
+            .connector
+            .respond("functions.completeError", serde_json::json!({}));
+        let mut event = function_executed_event();
+        event.inputs = HashMap::from([("user_id".to_string(), serde_json::json!(42))]);
+
+        assert!(sample_router()
+            .route(event, client.clone(), ())
+            .await
+            .unwrap());
+        let requests = client
+            .http_api
+            .connector
+            .requests("functions.completeError");
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0]["function_execution_id"], "Fx05RXD4PS9Y");
+        assert!(requests[0]["error"]
+            .as_str()
+            .is_some_and(|error| !error.is_empty()));
+    }
+
+    #[tokio::test]
+    async fn test_completes_handlers_without_outputs() {
+        let client = Arc::new(SlackClient::new(SlackTestHttpConnector::new()));
+        client
+            .http_api
+            .connector
+            .respond("functions.completeSuccess", serde_json::json!({}));
+        let router: SlackFunctionExecutedRouter<SlackTestHttpConnector, ()> =
+            SlackFunctionExecutedRouter::new().on(
+                "sample_function".into(),
+                |_inputs: SampleInputs, _event, _client, _state| async move { Ok(()) },
+            );
+
+        assert!(router
+            .route(function_executed_event(), client.clone(), ())
+            .await
+            .unwrap());
+        assert_eq!(
+            client
+                .http_api
+                .connector
+                .requests("functions.completeSuccess"),
+            vec![serde_json::json!({
+                "function_execution_id": "Fx05RXD4PS9Y",
+                "outputs": {}
+            })]
+        );
+        assert!(client
+            .http_api
+            .connector
+            .requests("functions.completeError")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_rejects_non_object_outputs() {
+        assert!(function_outputs(()).unwrap().is_empty());
+        assert!(matches!(
+            function_outputs(vec!["a", "b"]),
+            Err(SlackClientError::SystemError(_))
+        ));
+    }
+}
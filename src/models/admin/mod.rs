@@ -0,0 +1,137 @@
+use crate::*;
+
+use rsb_derive::Builder;
+use rvstruct::*;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use url::Url;
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct SlackAdminAppRequestId(pub String);
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlackAdminTeamDiscoverability {
+    Open,
+    InviteOnly,
+    Closed,
+    Unlisted,
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAdminConversation {
+    pub id: SlackChannelId,
+    pub name: Option<String>,
+    pub purpose: Option<String>,
+    pub member_count: Option<u64>,
+    pub created: Option<SlackDateTime>,
+    pub creator_id: Option<SlackUserId>,
+    pub is_private: Option<bool>,
+    pub is_archived: Option<bool>,
+    pub is_general: Option<bool>,
+    pub is_frozen: Option<bool>,
+    pub is_ext_shared: Option<bool>,
+    pub is_org_shared: Option<bool>,
+    pub is_org_default: Option<bool>,
+    pub is_org_mandatory: Option<bool>,
+    pub is_global_shared: Option<bool>,
+    pub connected_team_ids: Option<Vec<SlackTeamId>>,
+    pub internal_team_ids: Option<Vec<SlackTeamId>>,
+    pub internal_team_ids_count: Option<u64>,
+    pub connected_limited_team_ids: Option<Vec<SlackTeamId>>,
+    pub pending_connected_team_ids: Option<Vec<SlackTeamId>>,
+    pub last_activity_ts: Option<SlackTs>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAdminUser {
+    pub id: SlackUserId,
+    pub email: Option<EmailAddress>,
+    pub username: Option<String>,
+    pub full_name: Option<String>,
+    pub is_admin: Option<bool>,
+    pub is_owner: Option<bool>,
+    pub is_primary_owner: Option<bool>,
+    pub is_restricted: Option<bool>,
+    pub is_ultra_restricted: Option<bool>,
+    pub is_bot: Option<bool>,
+    pub is_active: Option<bool>,
+    pub has_2fa: Option<bool>,
+    pub date_created: Option<SlackDateTime>,
+    pub deactivated_ts: Option<SlackDateTime>,
+    pub expiration_ts: Option<SlackDateTime>,
+    pub workspaces: Option<Vec<SlackTeamId>>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAdminTeamPrimaryOwner {
+    pub user_id: SlackUserId,
+    pub email: Option<EmailAddress>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAdminTeam {
+    pub id: SlackTeamId,
+    pub name: Option<String>,
+    pub domain: Option<String>,
+    pub email_domain: Option<String>,
+    pub enterprise_id: Option<SlackEnterpriseId>,
+    pub enterprise_name: Option<String>,
+    pub discoverability: Option<SlackAdminTeamDiscoverability>,
+    pub primary_owner: Option<SlackAdminTeamPrimaryOwner>,
+    pub team_url: Option<Url>,
+    pub icon: Option<SlackIcon>,
+    pub is_verified: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAdminAppInfo {
+    pub id: SlackAppId,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub help_url: Option<String>,
+    pub privacy_policy_url: Option<String>,
+    pub app_homepage_url: Option<String>,
+    pub app_directory_url: Option<String>,
+    pub is_app_directory_approved: Option<bool>,
+    pub is_internal: Option<bool>,
+    pub additional_info: Option<String>,
+    pub icons: Option<SlackIcon>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAdminAppScope {
+    pub name: String,
+    pub description: Option<String>,
+    pub is_sensitive: Option<bool>,
+    pub token_type: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAdminAppRequestUser {
+    pub id: SlackUserId,
+    pub name: Option<String>,
+    pub email: Option<EmailAddress>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAdminAppRequest {
+    pub id: SlackAdminAppRequestId,
+    pub app: SlackAdminAppInfo,
+    pub user: Option<SlackAdminAppRequestUser>,
+    pub team: Option<SlackBasicTeamInfo>,
+    pub scopes: Option<Vec<SlackAdminAppScope>>,
+    pub message: Option<String>,
+    pub is_user_app_collaborator: Option<bool>,
+    pub date_created: Option<SlackDateTime>,
+}
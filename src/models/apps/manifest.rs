@@ -1,9 +1,13 @@
 use rsb_derive::Builder;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::collections::HashMap;
 use url::Url;
 
-use crate::{SlackApiTokenScope, SlackCallbackId, SlackEventType, SlackShortcutType};
+use crate::{
+    SlackApiTokenScope, SlackCallbackId, SlackEventType, SlackFunctionParameterType,
+    SlackShortcutType,
+};
 
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
@@ -45,6 +49,7 @@ pub struct SlackAppManifestSettings {
     pub interactivity: Option<SlackAppManifestSettingsInteractivity>,
     pub org_deploy_enabled: Option<bool>,
     pub socket_mode_enabled: Option<bool>,
+    pub function_runtime: Option<String>,
 }
 
 #[skip_serializing_none]
@@ -100,6 +105,33 @@ pub struct SlackAppManifestFeatures {
     pub workflow_steps: Option<Vec<SlackAppManifestFeaturesWorkflowStep>>,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAppManifestFunctionParameter {
+    #[serde(rename = "type")]
+    pub parameter_type: SlackFunctionParameterType,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub hint: Option<String>,
+    pub default: Option<serde_json::Value>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAppManifestFunctionParameters {
+    pub properties: HashMap<String, SlackAppManifestFunctionParameter>,
+    pub required: Option<Vec<String>>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAppManifestFunction {
+    pub title: String,
+    pub description: Option<String>,
+    pub input_parameters: Option<SlackAppManifestFunctionParameters>,
+    pub output_parameters: Option<SlackAppManifestFunctionParameters>,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
 pub struct SlackAppManifestOAuthConfigScopes {
@@ -123,4 +155,5 @@ pub struct SlackAppManifest {
     pub settings: Option<SlackAppManifestSettings>,
     pub features: Option<SlackAppManifestFeatures>,
     pub oauth_config: Option<SlackAppManifestOAuthConfig>,
+    pub functions: Option<HashMap<SlackCallbackId, SlackAppManifestFunction>>,
 }
@@ -0,0 +1,165 @@
+use crate::*;
+
+use rsb_derive::Builder;
+use rvstruct::*;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use std::collections::HashMap;
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct SlackAuditLogEntryId(pub String);
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct SlackAuditLogAction(pub String);
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlackAuditLogEntityType {
+    User,
+    Channel,
+    File,
+    App,
+    Workspace,
+    Enterprise,
+    Message,
+    Workflow,
+    Usergroup,
+    Barrier,
+    Role,
+    AccountTypeRole,
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAuditLogUser {
+    pub id: SlackUserId,
+    pub name: Option<String>,
+    pub email: Option<EmailAddress>,
+    pub team: Option<SlackTeamId>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAuditLogChannel {
+    pub id: SlackChannelId,
+    pub name: Option<String>,
+    pub privacy: Option<String>,
+    pub is_shared: Option<bool>,
+    pub is_org_shared: Option<bool>,
+    pub teams_shared_with: Option<Vec<SlackTeamId>>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAuditLogFile {
+    pub id: SlackFileId,
+    pub name: Option<String>,
+    pub filetype: Option<String>,
+    pub title: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAuditLogApp {
+    pub id: SlackAppId,
+    pub name: Option<String>,
+    pub is_distributed: Option<bool>,
+    pub is_directory_approved: Option<bool>,
+    pub is_workflow_app: Option<bool>,
+    pub scopes: Option<Vec<String>>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAuditLogWorkspace {
+    pub id: SlackTeamId,
+    pub name: Option<String>,
+    pub domain: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAuditLogEnterprise {
+    pub id: SlackEnterpriseId,
+    pub name: Option<String>,
+    pub domain: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAuditLogUsergroup {
+    pub id: SlackUserGroupId,
+    pub name: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAuditLogActor {
+    #[serde(rename = "type")]
+    pub actor_type: String,
+    pub user: Option<SlackAuditLogUser>,
+}
+
+/// The entity affected by an audit log action.
+/// Only the field matching `entity_type` is expected to be present.
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAuditLogEntity {
+    #[serde(rename = "type")]
+    pub entity_type: SlackAuditLogEntityType,
+    pub user: Option<SlackAuditLogUser>,
+    pub channel: Option<SlackAuditLogChannel>,
+    pub file: Option<SlackAuditLogFile>,
+    pub app: Option<SlackAuditLogApp>,
+    pub workspace: Option<SlackAuditLogWorkspace>,
+    pub enterprise: Option<SlackAuditLogEnterprise>,
+    pub usergroup: Option<SlackAuditLogUsergroup>,
+    pub message: Option<serde_json::Value>,
+    pub workflow: Option<serde_json::Value>,
+    pub barrier: Option<serde_json::Value>,
+    pub role: Option<serde_json::Value>,
+    pub account_type_role: Option<serde_json::Value>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAuditLogLocation {
+    #[serde(rename = "type")]
+    pub location_type: String,
+    pub id: String,
+    pub name: Option<String>,
+    pub domain: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAuditLogContext {
+    pub location: Option<SlackAuditLogLocation>,
+    pub ua: Option<String>,
+    pub ip_address: Option<String>,
+    pub session_id: Option<i64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAuditLogEntry {
+    pub id: SlackAuditLogEntryId,
+    pub date_create: SlackDateTime,
+    pub action: SlackAuditLogAction,
+    pub actor: SlackAuditLogActor,
+    pub entity: SlackAuditLogEntity,
+    pub context: Option<SlackAuditLogContext>,
+    /// Action-specific details, which vary a lot between actions
+    pub details: Option<serde_json::Value>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackAuditLogSchema {
+    #[serde(rename = "type")]
+    pub schema_type: String,
+    #[serde(flatten)]
+    pub definitions: HashMap<String, serde_json::Value>,
+}
@@ -0,0 +1,180 @@
+use crate::blocks::{SlackBlock, SlackView};
+use crate::SlackMessageContent;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use url::Url;
+
+///
+/// Helpers to exchange blocks with Slack [Block Kit Builder](https://app.slack.com/block-kit-builder).
+///
+pub struct SlackBlockKitBuilder;
+
+/// The typed value imported from Block Kit Builder JSON
+#[derive(Debug, PartialEq, Clone)]
+pub struct SlackBlockKitBuilderImport<T> {
+    pub value: T,
+    /// JSON paths of the fields the typed model doesn't support, so they would be dropped when sending it
+    pub unknown_fields: Vec<String>,
+}
+
+impl SlackBlockKitBuilder {
+    pub const BUILDER_URL: &'static str = "https://app.slack.com/block-kit-builder/";
+
+    /// The preview URL for the message blocks
+    pub fn blocks_url(blocks: &[SlackBlock]) -> serde_json::Result<Url> {
+        Self::preview_url(&serde_json::json!({ "blocks": blocks }))
+    }
+
+    /// The preview URL for the modal or home tab view
+    pub fn view_url(view: &SlackView) -> serde_json::Result<Url> {
+        Self::preview_url(view)
+    }
+
+    fn preview_url<T: Serialize>(payload: &T) -> serde_json::Result<Url> {
+        let json = serde_json::to_string(payload)?;
+        // The builder URL is constant and always valid with the encoded fragment
+        Ok(Url::parse(&format!(
+            "{}#{}",
+            Self::BUILDER_URL,
+            Self::encode_uri_component(&json)
+        ))
+        .expect("Block Kit Builder URL"))
+    }
+
+    /// Encodes the same way as `encodeURIComponent` that Block Kit Builder uses
+    fn encode_uri_component(value: &str) -> String {
+        value
+            .bytes()
+            .map(|byte| match byte {
+                b'A'..=b'Z'
+                | b'a'..=b'z'
+                | b'0'..=b'9'
+                | b'-'
+                | b'_'
+                | b'.'
+                | b'!'
+                | b'~'
+                | b'*'
+                | b'\''
+                | b'('
+                | b')' => (byte as char).to_string(),
+                _ => format!("%{:02X}", byte),
+            })
+            .collect()
+    }
+
+    /// Parses the message JSON exported from Block Kit Builder (`{"blocks": [...]}`)
+    pub fn parse_message(
+        json: &str,
+    ) -> serde_json::Result<SlackBlockKitBuilderImport<SlackMessageContent>> {
+        Self::parse(json)
+    }
+
+    /// Parses the modal or home tab JSON exported from Block Kit Builder
+    pub fn parse_view(json: &str) -> serde_json::Result<SlackBlockKitBuilderImport<SlackView>> {
+        Self::parse(json)
+    }
+
+    fn parse<T: Serialize + DeserializeOwned>(
+        json: &str,
+    ) -> serde_json::Result<SlackBlockKitBuilderImport<T>> {
+        let original: Value = serde_json::from_str(json)?;
+        let value: T = serde_json::from_value(original.clone())?;
+        let typed = serde_json::to_value(&value)?;
+
+        let mut unknown_fields = Vec::new();
+        Self::find_unknown_fields("$", &original, &typed, &mut unknown_fields);
+
+        Ok(SlackBlockKitBuilderImport {
+            value,
+            unknown_fields,
+        })
+    }
+
+    fn find_unknown_fields(path: &str, original: &Value, typed: &Value, result: &mut Vec<String>) {
+        match (original, typed) {
+            (Value::Object(original_fields), Value::Object(typed_fields)) => {
+                for (name, original_value) in original_fields {
+                    let field_path = format!("{}.{}", path, name);
+                    match typed_fields.get(name) {
+                        Some(typed_value) => Self::find_unknown_fields(
+                            &field_path,
+                            original_value,
+                            typed_value,
+                            result,
+                        ),
+                        // Empty values are skipped by the models on purpose
+                        None if Self::is_empty(original_value) => {}
+                        None => result.push(field_path),
+                    }
+                }
+            }
+            (Value::Array(original_items), Value::Array(typed_items)) => {
+                for (index, (original_item, typed_item)) in
+                    original_items.iter().zip(typed_items).enumerate()
+                {
+                    Self::find_unknown_fields(
+                        &format!("{}[{}]", path, index),
+                        original_item,
+                        typed_item,
+                        result,
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn is_empty(value: &Value) -> bool {
+        match value {
+            Value::Null => true,
+            Value::String(str) => str.is_empty(),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::blocks::*;
+    use crate::*;
+
+    #[test]
+    fn test_blocks_url() -> Result<(), Box<dyn std::error::Error>> {
+        let blocks: Vec<SlackBlock> = slack_blocks![some_into(
+            SlackSectionBlock::new().with_text(md!("Hi & bye"))
+        )];
+        let url = SlackBlockKitBuilder::blocks_url(&blocks)?;
+
+        assert_eq!(
+            url.as_str(),
+            "https://app.slack.com/block-kit-builder/#%7B%22blocks%22%3A%5B%7B%22text%22%3A%7B%22text%22%3A%22Hi%20%26%20bye%22%2C%22type%22%3A%22mrkdwn%22%7D%2C%22type%22%3A%22section%22%7D%5D%7D"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_message_with_unknown_fields() -> Result<(), Box<dyn std::error::Error>> {
+        let import = SlackBlockKitBuilder::parse_message(
+            r#"{
+                "blocks": [
+                    {
+                        "type": "section",
+                        "text": { "type": "mrkdwn", "text": "Hello", "future_flag": true }
+                    }
+                ]
+            }"#,
+        )?;
+
+        assert_eq!(
+            import.value.blocks,
+            Some(slack_blocks![some_into(
+                SlackSectionBlock::new().with_text(md!("Hello"))
+            )])
+        );
+        assert_eq!(import.unknown_fields, vec!["$.blocks[0].text.future_flag"]);
+        Ok(())
+    }
+}
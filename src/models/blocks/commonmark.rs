@@ -0,0 +1,1263 @@
+use crate::blocks::*;
+use crate::{
+    escape_mrkdwn, escape_mrkdwn_markers, mrkdwn_code_block, mrkdwn_quote, SlackRelaxedUrl,
+};
+
+///
+/// Converts CommonMark (e.g. produced by LLMs or CI tools) to Slack `mrkdwn` text and rich text blocks.
+///
+/// Supported syntax: paragraphs, headings, emphasis, strikethrough, code spans and blocks, links,
+/// block quotes, thematic breaks, and (nested) bullet and ordered lists.
+/// Headings are rendered as bold text, since Slack doesn't support them.
+/// Literal `mrkdwn` markers (e.g. `\*`) are replaced with lookalikes, since `mrkdwn` can't escape them.
+///
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SlackMarkdownConverter {
+    /// The max number of characters in a single rich text block
+    pub max_block_length: usize,
+}
+
+impl SlackMarkdownConverter {
+    pub const DEFAULT_MAX_BLOCK_LENGTH: usize = 3000;
+
+    pub fn new() -> Self {
+        Self {
+            max_block_length: Self::DEFAULT_MAX_BLOCK_LENGTH,
+        }
+    }
+
+    pub fn with_max_block_length(self, max_block_length: usize) -> Self {
+        Self {
+            max_block_length: max_block_length.max(1),
+        }
+    }
+
+    pub fn to_mrkdwn(&self, markdown: &str) -> String {
+        parse_markdown(markdown)
+            .iter()
+            .map(|block| block_to_mrkdwn(block, 0))
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+
+    /// Converts to rich text blocks, splitting them to stay within `max_block_length`
+    pub fn to_rich_text_blocks(&self, markdown: &str) -> Vec<SlackRichTextBlock> {
+        let blocks = parse_markdown(markdown);
+
+        let mut elements = Vec::new();
+        for (index, block) in blocks.iter().enumerate() {
+            let separated = index + 1 < blocks.len();
+            block_to_rich_text(block, 0, separated, &mut elements);
+        }
+
+        let mut result: Vec<Vec<SlackRichTextElement>> = Vec::new();
+        let mut current_length = 0;
+        for element in elements
+            .into_iter()
+            .flat_map(|element| split_rich_text_element(element, self.max_block_length))
+        {
+            let length = rich_text_element_length(&element);
+            match result.last_mut() {
+                Some(current) if current_length + length <= self.max_block_length => {
+                    current.push(element);
+                    current_length += length;
+                }
+                _ => {
+                    result.push(vec![element]);
+                    current_length = length;
+                }
+            }
+        }
+
+        result.into_iter().map(SlackRichTextBlock::new).collect()
+    }
+}
+
+impl Default for SlackMarkdownConverter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum MarkdownBlock {
+    Paragraph(String),
+    Heading(String),
+    Code {
+        language: Option<String>,
+        text: String,
+    },
+    Quote(Vec<MarkdownBlock>),
+    List {
+        ordered: bool,
+        start: u64,
+        items: Vec<Vec<MarkdownBlock>>,
+    },
+    Rule,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+struct InlineStyle {
+    bold: bool,
+    italic: bool,
+    strike: bool,
+    code: bool,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct InlineSpan {
+    text: String,
+    style: InlineStyle,
+    link: Option<String>,
+}
+
+const RULE_TEXT: &str = "———";
+
+fn parse_markdown(markdown: &str) -> Vec<MarkdownBlock> {
+    let lines: Vec<String> = markdown
+        .lines()
+        .map(|line| line.replace('\t', "    "))
+        .collect();
+    parse_blocks(&lines)
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+fn is_rule(trimmed: &str) -> bool {
+    let chars: Vec<char> = trimmed.chars().filter(|c| !c.is_whitespace()).collect();
+    chars.len() >= 3 && matches!(chars[0], '-' | '*' | '_') && chars.iter().all(|c| *c == chars[0])
+}
+
+fn heading_text(trimmed: &str) -> Option<&str> {
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    let rest = &trimmed[level..];
+    if (1..=6).contains(&level) && (rest.is_empty() || rest.starts_with(' ')) {
+        Some(rest.trim().trim_end_matches('#').trim_end())
+    } else {
+        None
+    }
+}
+
+fn fence_start(trimmed: &str) -> Option<(String, Option<String>)> {
+    let fence_char = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let length = trimmed.chars().take_while(|c| *c == fence_char).count();
+    if length < 3 {
+        return None;
+    }
+    let info = trimmed[length..].trim();
+    if fence_char == '`' && info.contains('`') {
+        return None;
+    }
+    Some((
+        fence_char.to_string().repeat(length),
+        info.split_whitespace().next().map(|lang| lang.to_string()),
+    ))
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct ListMarker {
+    ordered: bool,
+    delimiter: char,
+    start: u64,
+    indent: usize,
+    content_offset: usize,
+}
+
+fn list_marker(line: &str) -> Option<ListMarker> {
+    let indent = indent_of(line);
+    let rest = &line[indent..];
+    if is_rule(rest) {
+        return None;
+    }
+
+    let (ordered, delimiter, start, marker_length) = match rest.chars().next()? {
+        bullet @ ('-' | '*' | '+') => (false, bullet, 1, 1),
+        _ => {
+            let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+            let delimiter = rest[digits..].chars().next()?;
+            if !(1..=9).contains(&digits) || !matches!(delimiter, '.' | ')') {
+                return None;
+            }
+            (true, delimiter, rest[..digits].parse().ok()?, digits + 1)
+        }
+    };
+
+    let after_marker = &rest[marker_length..];
+    if !after_marker.is_empty() && !after_marker.starts_with(' ') {
+        return None;
+    }
+    let spaces = indent_of(after_marker);
+    let content_spaces = if spaces == 0 || spaces > 4 || is_blank(after_marker) {
+        1
+    } else {
+        spaces
+    };
+
+    Some(ListMarker {
+        ordered,
+        delimiter,
+        start,
+        indent,
+        content_offset: indent + marker_length + content_spaces,
+    })
+}
+
+fn starts_block(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    is_rule(trimmed)
+        || heading_text(trimmed).is_some()
+        || fence_start(trimmed).is_some()
+        || trimmed.starts_with('>')
+        || list_marker(line).is_some()
+}
+
+fn parse_blocks(lines: &[String]) -> Vec<MarkdownBlock> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<String> = Vec::new();
+
+    fn flush(paragraph: &mut Vec<String>, blocks: &mut Vec<MarkdownBlock>) {
+        if !paragraph.is_empty() {
+            blocks.push(MarkdownBlock::Paragraph(paragraph.join("\n")));
+            paragraph.clear();
+        }
+    }
+
+    let mut index = 0;
+    while index < lines.len() {
+        let line = &lines[index];
+        let trimmed = line.trim_start();
+        let indent = indent_of(line);
+
+        if is_blank(line) {
+            flush(&mut paragraph, &mut blocks);
+            index += 1;
+        } else if indent >= 4 && paragraph.is_empty() {
+            let mut code_lines = Vec::new();
+            while index < lines.len() && (is_blank(&lines[index]) || indent_of(&lines[index]) >= 4)
+            {
+                code_lines.push(lines[index].get(4..).unwrap_or_default().to_string());
+                index += 1;
+            }
+            while code_lines.last().is_some_and(|line| is_blank(line)) {
+                code_lines.pop();
+            }
+            blocks.push(MarkdownBlock::Code {
+                language: None,
+                text: code_lines.join("\n"),
+            });
+        } else if let Some((fence, language)) = fence_start(trimmed) {
+            flush(&mut paragraph, &mut blocks);
+            let mut code_lines = Vec::new();
+            index += 1;
+            while index < lines.len() {
+                let code_line = &lines[index];
+                index += 1;
+                if code_line.trim().starts_with(&fence)
+                    && code_line.trim().chars().all(|c| fence.starts_with(c))
+                {
+                    break;
+                }
+                let strip = indent_of(code_line).min(indent);
+                code_lines.push(code_line[strip..].to_string());
+            }
+            blocks.push(MarkdownBlock::Code {
+                language,
+                text: code_lines.join("\n"),
+            });
+        } else if !paragraph.is_empty()
+            && (trimmed.trim_end().chars().all(|c| c == '=')
+                || trimmed.trim_end().chars().all(|c| c == '-'))
+        {
+            blocks.push(MarkdownBlock::Heading(paragraph.join("\n")));
+            paragraph.clear();
+            index += 1;
+        } else if is_rule(trimmed) {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(MarkdownBlock::Rule);
+            index += 1;
+        } else if let Some(text) = heading_text(trimmed) {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(MarkdownBlock::Heading(text.to_string()));
+            index += 1;
+        } else if trimmed.starts_with('>') {
+            flush(&mut paragraph, &mut blocks);
+            let mut quote_lines = Vec::new();
+            while index < lines.len() && lines[index].trim_start().starts_with('>') {
+                let quote_line = &lines[index].trim_start()[1..];
+                quote_lines.push(
+                    quote_line
+                        .strip_prefix(' ')
+                        .unwrap_or(quote_line)
+                        .to_string(),
+                );
+                index += 1;
+            }
+            blocks.push(MarkdownBlock::Quote(parse_blocks(&quote_lines)));
+        } else if let Some(marker) = list_marker(line) {
+            flush(&mut paragraph, &mut blocks);
+            let (list, next_index) = parse_list(lines, index, marker);
+            blocks.push(list);
+            index = next_index;
+        } else {
+            let text = trimmed.trim_end();
+            paragraph.push(text.strip_suffix('\\').unwrap_or(text).to_string());
+            index += 1;
+        }
+    }
+    flush(&mut paragraph, &mut blocks);
+
+    blocks
+}
+
+fn parse_list(lines: &[String], start: usize, first: ListMarker) -> (MarkdownBlock, usize) {
+    let is_sibling = |marker: &ListMarker| {
+        marker.ordered == first.ordered
+            && marker.delimiter == first.delimiter
+            && marker.indent < first.content_offset
+    };
+
+    let mut items = Vec::new();
+    let mut index = start;
+    while let Some(marker) = lines
+        .get(index)
+        .and_then(|line| list_marker(line))
+        .filter(is_sibling)
+    {
+        let line = &lines[index];
+        let mut item_lines = vec![line
+            .get(marker.content_offset..)
+            .unwrap_or_default()
+            .to_string()];
+        index += 1;
+
+        while index < lines.len() {
+            let line = &lines[index];
+            if is_blank(line) {
+                match (index..lines.len()).find(|next| !is_blank(&lines[*next])) {
+                    Some(next) if indent_of(&lines[next]) >= marker.content_offset => {
+                        item_lines.extend((index..next).map(|_| String::new()));
+                        index = next;
+                    }
+                    Some(next) => {
+                        index = next;
+                        break;
+                    }
+                    None => {
+                        index = lines.len();
+                        break;
+                    }
+                }
+            } else if indent_of(line) >= marker.content_offset {
+                item_lines.push(line[marker.content_offset..].to_string());
+                index += 1;
+            } else if starts_block(line) {
+                break;
+            } else {
+                item_lines.push(line.trim_start().to_string());
+                index += 1;
+            }
+        }
+
+        items.push(parse_blocks(&item_lines));
+    }
+
+    (
+        MarkdownBlock::List {
+            ordered: first.ordered,
+            start: first.start,
+            items,
+        },
+        index,
+    )
+}
+
+fn parse_inlines(text: &str) -> Vec<InlineSpan> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut spans = Vec::new();
+    parse_inline_range(&chars, InlineStyle::default(), None, &mut spans);
+
+    let mut merged: Vec<InlineSpan> = Vec::new();
+    for span in spans.into_iter().filter(|span| !span.text.is_empty()) {
+        match merged.last_mut() {
+            Some(last) if last.style == span.style && last.link == span.link => {
+                last.text.push_str(&span.text)
+            }
+            _ => merged.push(span),
+        }
+    }
+    merged
+}
+
+fn find_run(chars: &[char], from: usize, run: &[char]) -> Option<usize> {
+    (from..chars.len()).find(|index| chars[*index..].starts_with(run))
+}
+
+fn closing_delimiter(chars: &[char], from: usize, delimiter: char, length: usize) -> Option<usize> {
+    (from..chars.len()).find(|index| {
+        let index = *index;
+        index > from
+            && chars[index..].starts_with(&vec![delimiter; length])
+            && !chars[index - 1].is_whitespace()
+            && chars.get(index + length) != Some(&delimiter)
+            && chars[index - 1] != delimiter
+            && (delimiter != '_'
+                || !chars
+                    .get(index + length)
+                    .is_some_and(|c| c.is_alphanumeric()))
+    })
+}
+
+fn link_target(chars: &[char], open: usize) -> Option<(usize, String, usize)> {
+    let mut depth = 0;
+    let mut text_end = None;
+    let mut index = open;
+    while index < chars.len() {
+        match chars[index] {
+            '\\' => index += 1,
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    text_end = Some(index);
+                    break;
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    let text_end = text_end?;
+    if chars.get(text_end + 1) != Some(&'(') {
+        return None;
+    }
+    let url_end = find_run(chars, text_end + 2, &[')'])?;
+    let target: String = chars[text_end + 2..url_end].iter().collect();
+    let url = target
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .trim_start_matches('<')
+        .trim_end_matches('>')
+        .to_string();
+
+    Some((text_end, url, url_end + 1))
+}
+
+fn parse_inline_range(
+    chars: &[char],
+    style: InlineStyle,
+    link: Option<&str>,
+    spans: &mut Vec<InlineSpan>,
+) {
+    let mut text = String::new();
+    let flush = |text: &mut String, spans: &mut Vec<InlineSpan>| {
+        if !text.is_empty() {
+            spans.push(InlineSpan {
+                text: std::mem::take(text),
+                style,
+                link: link.map(|link| link.to_string()),
+            });
+        }
+    };
+
+    let mut index = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        match c {
+            '\\' if chars
+                .get(index + 1)
+                .is_some_and(|next| next.is_ascii_punctuation()) =>
+            {
+                text.push(chars[index + 1]);
+                index += 2;
+            }
+            '`' => {
+                let length = chars[index..].iter().take_while(|c| **c == '`').count();
+                let run = vec!['`'; length];
+                match (index + length..chars.len()).find(|from| {
+                    chars[*from..].starts_with(&run) && chars.get(from + length) != Some(&'`')
+                }) {
+                    Some(close) => {
+                        flush(&mut text, spans);
+                        let code: String = chars[index + length..close].iter().collect();
+                        let code = match code.strip_prefix(' ').and_then(|c| c.strip_suffix(' ')) {
+                            Some(stripped) if !stripped.trim().is_empty() => stripped.to_string(),
+                            _ => code,
+                        };
+                        spans.push(InlineSpan {
+                            text: code.replace('\n', " "),
+                            style: InlineStyle {
+                                code: true,
+                                ..style
+                            },
+                            link: link.map(|link| link.to_string()),
+                        });
+                        index = close + length;
+                    }
+                    None => {
+                        text.extend(run);
+                        index += length;
+                    }
+                }
+            }
+            '!' if chars.get(index + 1) == Some(&'[') && link.is_none() => {
+                match link_target(chars, index + 1) {
+                    Some((text_end, url, end)) => {
+                        flush(&mut text, spans);
+                        let alt: String = chars[index + 2..text_end].iter().collect();
+                        spans.push(InlineSpan {
+                            text: if alt.is_empty() { url.clone() } else { alt },
+                            style,
+                            link: Some(url),
+                        });
+                        index = end;
+                    }
+                    None => {
+                        text.push(c);
+                        index += 1;
+                    }
+                }
+            }
+            '[' if link.is_none() => match link_target(chars, index) {
+                Some((text_end, url, end)) => {
+                    flush(&mut text, spans);
+                    parse_inline_range(&chars[index + 1..text_end], style, Some(&url), spans);
+                    index = end;
+                }
+                None => {
+                    text.push(c);
+                    index += 1;
+                }
+            },
+            '<' if link.is_none() => {
+                let autolink = find_run(chars, index + 1, &['>'])
+                    .map(|end| (end, chars[index + 1..end].iter().collect::<String>()))
+                    .filter(|(_, url)| {
+                        !url.contains(char::is_whitespace)
+                            && (url.starts_with("http://")
+                                || url.starts_with("https://")
+                                || url.starts_with("mailto:"))
+                    });
+                match autolink {
+                    Some((end, url)) => {
+                        flush(&mut text, spans);
+                        spans.push(InlineSpan {
+                            text: url.clone(),
+                            style,
+                            link: Some(url),
+                        });
+                        index = end + 1;
+                    }
+                    None => {
+                        text.push(c);
+                        index += 1;
+                    }
+                }
+            }
+            '*' | '_' | '~' => {
+                let run_length = chars[index..].iter().take_while(|next| **next == c).count();
+                let length = match c {
+                    '~' if run_length >= 2 => 2,
+                    '~' => 0,
+                    _ => run_length.min(3),
+                };
+                let opens = length > 0
+                    && chars
+                        .get(index + run_length)
+                        .is_some_and(|next| !next.is_whitespace())
+                    && (c != '_' || index == 0 || !chars[index - 1].is_alphanumeric());
+
+                match closing_delimiter(chars, index + length, c, length).filter(|_| opens) {
+                    Some(close) => {
+                        flush(&mut text, spans);
+                        let inner_style = match (c, length) {
+                            ('~', _) => InlineStyle {
+                                strike: true,
+                                ..style
+                            },
+                            (_, 1) => InlineStyle {
+                                italic: true,
+                                ..style
+                            },
+                            (_, 2) => InlineStyle {
+                                bold: true,
+                                ..style
+                            },
+                            _ => InlineStyle {
+                                bold: true,
+                                italic: true,
+                                ..style
+                            },
+                        };
+                        parse_inline_range(&chars[index + length..close], inner_style, link, spans);
+                        index = close + length;
+                    }
+                    None => {
+                        text.extend(&chars[index..index + run_length]);
+                        index += run_length;
+                    }
+                }
+            }
+            _ => {
+                text.push(c);
+                index += 1;
+            }
+        }
+    }
+    flush(&mut text, spans);
+}
+
+impl InlineStyle {
+    /// The `mrkdwn` markers from the outermost
+    fn markers(&self) -> Vec<char> {
+        [(self.bold, '*'), (self.italic, '_'), (self.strike, '~')]
+            .iter()
+            .filter(|(enabled, _)| *enabled)
+            .map(|(_, marker)| *marker)
+            .collect()
+    }
+}
+
+fn span_to_mrkdwn(span: &InlineSpan) -> String {
+    let text = if span.style.code {
+        format!(
+            "`{}`",
+            escape_mrkdwn_markers(&escape_mrkdwn(&span.text), &['`'], false)
+        )
+    } else {
+        escape_mrkdwn_markers(&escape_mrkdwn(&span.text), &span.style.markers(), true)
+    };
+    match &span.link {
+        Some(link) => {
+            let url = link.replace('|', "%7C").replace('>', "%3E");
+            if span.text == *link {
+                format!("<{}>", url)
+            } else {
+                format!("<{}|{}>", url, text)
+            }
+        }
+        None => text,
+    }
+}
+
+/// Adjacent spans share the style markers, and the markers are kept next to the text, not whitespace
+fn spans_to_mrkdwn(spans: &[InlineSpan]) -> String {
+    let mut result = String::new();
+    let mut open_markers: Vec<char> = Vec::new();
+
+    let close_markers = |result: &mut String, open_markers: &mut Vec<char>, keep: usize| {
+        if keep < open_markers.len() {
+            let trailing = result.split_off(result.trim_end().len());
+            result.extend(open_markers.drain(keep..).rev());
+            result.push_str(&trailing);
+        }
+    };
+
+    for span in spans {
+        let text = span_to_mrkdwn(span);
+        if !span.style.code && span.link.is_none() && text.trim().is_empty() {
+            result.push_str(&text);
+            continue;
+        }
+
+        let markers = span.style.markers();
+        let keep = open_markers
+            .iter()
+            .take_while(|marker| markers.contains(marker))
+            .count();
+        close_markers(&mut result, &mut open_markers, keep);
+
+        let trimmed = text.trim_start();
+        result.push_str(&text[..text.len() - trimmed.len()]);
+        for marker in markers {
+            if !open_markers.contains(&marker) {
+                result.push(marker);
+                open_markers.push(marker);
+            }
+        }
+        result.push_str(trimmed);
+    }
+    close_markers(&mut result, &mut open_markers, 0);
+
+    result
+}
+
+fn bold_spans(mut spans: Vec<InlineSpan>) -> Vec<InlineSpan> {
+    for span in spans.iter_mut() {
+        span.style.bold = true;
+    }
+    spans
+}
+
+fn block_to_mrkdwn(block: &MarkdownBlock, level: usize) -> String {
+    match block {
+        MarkdownBlock::Paragraph(text) => spans_to_mrkdwn(&parse_inlines(text)),
+        MarkdownBlock::Heading(text) => spans_to_mrkdwn(&bold_spans(parse_inlines(text))),
+        MarkdownBlock::Code { text, .. } => mrkdwn_code_block(&escape_mrkdwn(text)),
+        MarkdownBlock::Quote(blocks) => mrkdwn_quote(
+            &blocks
+                .iter()
+                .map(|block| block_to_mrkdwn(block, 0))
+                .collect::<Vec<String>>()
+                .join("\n\n"),
+        ),
+        MarkdownBlock::List {
+            ordered,
+            start,
+            items,
+        } => items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let marker = if *ordered {
+                    format!("{}.", *start + index as u64)
+                } else {
+                    ["•", "◦", "▪"][level % 3].to_string()
+                };
+                let content = item
+                    .iter()
+                    .map(|block| match block {
+                        MarkdownBlock::List { .. } => block_to_mrkdwn(block, level + 1),
+                        _ => block_to_mrkdwn(block, level),
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                content
+                    .lines()
+                    .enumerate()
+                    .map(|(line_index, line)| {
+                        if line_index == 0 {
+                            format!("{} {}", marker, line)
+                        } else {
+                            format!("    {}", line)
+                        }
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+        MarkdownBlock::Rule => RULE_TEXT.to_string(),
+    }
+}
+
+fn rich_text_style(style: &InlineStyle) -> Option<SlackRichTextStyle> {
+    if *style == InlineStyle::default() {
+        None
+    } else {
+        let flag = |value: bool| if value { Some(true) } else { None };
+        Some(SlackRichTextStyle {
+            bold: flag(style.bold),
+            italic: flag(style.italic),
+            strike: flag(style.strike),
+            code: flag(style.code),
+            underline: None,
+            highlight: None,
+            client_highlight: None,
+            unlink: None,
+        })
+    }
+}
+
+fn text_element(text: &str) -> SlackRichTextInlineElement {
+    SlackRichTextInlineElement::Text(SlackRichTextText::new(text.to_string()))
+}
+
+fn spans_to_rich_text(spans: &[InlineSpan]) -> Vec<SlackRichTextInlineElement> {
+    spans
+        .iter()
+        .map(|span| match &span.link {
+            Some(link) => SlackRichTextInlineElement::Link(
+                SlackRichTextLink::new(SlackRelaxedUrl(link.clone()))
+                    .opt_text(Some(span.text.clone()).filter(|text| text != link))
+                    .opt_style(rich_text_style(&span.style)),
+            ),
+            None => SlackRichTextInlineElement::Text(
+                SlackRichTextText::new(span.text.clone()).opt_style(rich_text_style(&span.style)),
+            ),
+        })
+        .collect()
+}
+
+/// The inline content of the blocks that can't be nested in rich text elements (e.g. lists in quotes)
+fn blocks_to_inline(blocks: &[MarkdownBlock]) -> Vec<SlackRichTextInlineElement> {
+    let mut elements = Vec::new();
+    for (index, block) in blocks.iter().enumerate() {
+        if index > 0 {
+            elements.push(text_element("\n"));
+        }
+        match block {
+            MarkdownBlock::Paragraph(text) => {
+                elements.extend(spans_to_rich_text(&parse_inlines(text)))
+            }
+            MarkdownBlock::Heading(text) => {
+                elements.extend(spans_to_rich_text(&bold_spans(parse_inlines(text))))
+            }
+            MarkdownBlock::Code { text, .. } => elements.push(SlackRichTextInlineElement::Text(
+                SlackRichTextText::new(text.clone())
+                    .with_style(SlackRichTextStyle::new().with_code(true)),
+            )),
+            MarkdownBlock::Quote(blocks) => elements.extend(blocks_to_inline(blocks)),
+            MarkdownBlock::List {
+                ordered,
+                start,
+                items,
+            } => {
+                for (item_index, item) in items.iter().enumerate() {
+                    if item_index > 0 {
+                        elements.push(text_element("\n"));
+                    }
+                    let marker = if *ordered {
+                        format!("{}. ", *start + item_index as u64)
+                    } else {
+                        "• ".to_string()
+                    };
+                    elements.push(text_element(&marker));
+                    elements.extend(blocks_to_inline(item));
+                }
+            }
+            MarkdownBlock::Rule => elements.push(text_element(RULE_TEXT)),
+        }
+    }
+    elements
+}
+
+fn block_to_rich_text(
+    block: &MarkdownBlock,
+    level: u64,
+    separated: bool,
+    elements: &mut Vec<SlackRichTextElement>,
+) {
+    let with_separator = |mut inline: Vec<SlackRichTextInlineElement>| {
+        if separated {
+            inline.push(text_element("\n"));
+        }
+        inline
+    };
+
+    match block {
+        MarkdownBlock::Paragraph(text) => elements.push(
+            SlackRichTextSection::new(with_separator(spans_to_rich_text(&parse_inlines(text))))
+                .into(),
+        ),
+        MarkdownBlock::Heading(text) => elements.push(
+            SlackRichTextSection::new(with_separator(spans_to_rich_text(&bold_spans(
+                parse_inlines(text),
+            ))))
+            .into(),
+        ),
+        MarkdownBlock::Rule => elements
+            .push(SlackRichTextSection::new(with_separator(vec![text_element(RULE_TEXT)])).into()),
+        MarkdownBlock::Code { language, text } => elements.push(
+            SlackRichTextPreformatted::new(vec![text_element(text)])
+                .opt_language(language.clone())
+                .into(),
+        ),
+        MarkdownBlock::Quote(blocks) => {
+            elements.push(SlackRichTextQuote::new(blocks_to_inline(blocks)).into())
+        }
+        MarkdownBlock::List {
+            ordered,
+            start,
+            items,
+        } => {
+            let style = if *ordered {
+                SlackRichTextListStyle::Ordered
+            } else {
+                SlackRichTextListStyle::Bullet
+            };
+            let mut sections = Vec::new();
+            let mut first_item = 0;
+
+            let flush_list =
+                |sections: &mut Vec<SlackRichTextListElement>,
+                 first_item: usize,
+                 elements: &mut Vec<SlackRichTextElement>| {
+                    if !sections.is_empty() {
+                        let offset = *start + first_item as u64 - 1;
+                        elements.push(
+                            SlackRichTextList::new(style.clone(), std::mem::take(sections))
+                                .opt_indent(Some(level).filter(|level| *level > 0))
+                                .opt_offset(Some(offset).filter(|offset| *ordered && *offset > 0))
+                                .into(),
+                        );
+                    }
+                };
+
+            for (index, item) in items.iter().enumerate() {
+                let (nested, content): (Vec<&MarkdownBlock>, Vec<&MarkdownBlock>) = item
+                    .iter()
+                    .partition(|block| matches!(block, MarkdownBlock::List { .. }));
+                let content: Vec<MarkdownBlock> = content.into_iter().cloned().collect();
+                if sections.is_empty() {
+                    first_item = index;
+                }
+                sections.push(SlackRichTextSection::new(blocks_to_inline(&content)).into());
+
+                if !nested.is_empty() {
+                    flush_list(&mut sections, first_item, elements);
+                    for nested_list in nested {
+                        block_to_rich_text(nested_list, level + 1, false, elements);
+                    }
+                }
+            }
+            flush_list(&mut sections, first_item, elements);
+        }
+    }
+}
+
+fn inline_element_length(element: &SlackRichTextInlineElement) -> usize {
+    match element {
+        SlackRichTextInlineElement::Text(text) => text.text.chars().count(),
+        SlackRichTextInlineElement::Link(link) => {
+            link.text.as_ref().unwrap_or(&link.url.0).chars().count()
+        }
+        _ => 1,
+    }
+}
+
+fn inline_elements_length(elements: &[SlackRichTextInlineElement]) -> usize {
+    elements.iter().map(inline_element_length).sum()
+}
+
+fn rich_text_element_length(element: &SlackRichTextElement) -> usize {
+    match element {
+        SlackRichTextElement::Section(section) => inline_elements_length(&section.elements),
+        SlackRichTextElement::Preformatted(preformatted) => {
+            inline_elements_length(&preformatted.elements)
+        }
+        SlackRichTextElement::Quote(quote) => inline_elements_length(&quote.elements),
+        SlackRichTextElement::List(list) => list.elements.iter().map(list_element_length).sum(),
+        SlackRichTextElement::Unknown(_) => 0,
+    }
+}
+
+fn list_element_length(element: &SlackRichTextListElement) -> usize {
+    match element {
+        SlackRichTextListElement::Section(section) => inline_elements_length(&section.elements),
+        SlackRichTextListElement::Unknown(_) => 0,
+    }
+}
+
+/// Splits the inline elements into chunks, splitting long texts by lines or characters
+fn split_inline_elements(
+    elements: Vec<SlackRichTextInlineElement>,
+    max_length: usize,
+) -> Vec<Vec<SlackRichTextInlineElement>> {
+    let mut chunks: Vec<Vec<SlackRichTextInlineElement>> = vec![Vec::new()];
+    let mut current_length = 0;
+
+    for element in elements {
+        let mut pending = Some(element);
+        while let Some(element) = pending.take() {
+            let length = inline_element_length(&element);
+            if current_length + length <= max_length {
+                chunks.last_mut().unwrap().push(element);
+                current_length += length;
+            } else if let SlackRichTextInlineElement::Text(text) = &element {
+                let available = max_length - current_length;
+                let head: String = text.text.chars().take(available).collect();
+                let head = match head.rfind('\n') {
+                    Some(newline) if newline > 0 => head[..=newline].to_string(),
+                    _ => head,
+                };
+                if !head.is_empty() {
+                    let tail = text.text[head.len()..].to_string();
+                    chunks
+                        .last_mut()
+                        .unwrap()
+                        .push(SlackRichTextInlineElement::Text(SlackRichTextText {
+                            text: head,
+                            style: text.style.clone(),
+                        }));
+                    pending = Some(SlackRichTextInlineElement::Text(SlackRichTextText {
+                        text: tail,
+                        style: text.style.clone(),
+                    }));
+                } else {
+                    pending = Some(element);
+                }
+                chunks.push(Vec::new());
+                current_length = 0;
+            } else if current_length > 0 {
+                chunks.push(Vec::new());
+                current_length = 0;
+                pending = Some(element);
+            } else {
+                chunks.last_mut().unwrap().push(element);
+                current_length += length;
+            }
+        }
+    }
+
+    chunks.retain(|chunk| !chunk.is_empty());
+    chunks
+}
+
+fn split_rich_text_element(
+    element: SlackRichTextElement,
+    max_length: usize,
+) -> Vec<SlackRichTextElement> {
+    if rich_text_element_length(&element) <= max_length {
+        return vec![element];
+    }
+
+    match element {
+        SlackRichTextElement::Section(section) => {
+            split_inline_elements(section.elements, max_length)
+                .into_iter()
+                .map(|elements| SlackRichTextSection::new(elements).into())
+                .collect()
+        }
+        SlackRichTextElement::Preformatted(preformatted) => {
+            split_inline_elements(preformatted.elements.clone(), max_length)
+                .into_iter()
+                .map(|elements| {
+                    SlackRichTextPreformatted {
+                        elements,
+                        ..preformatted.clone()
+                    }
+                    .into()
+                })
+                .collect()
+        }
+        SlackRichTextElement::Quote(quote) => {
+            split_inline_elements(quote.elements.clone(), max_length)
+                .into_iter()
+                .map(|elements| {
+                    SlackRichTextQuote {
+                        elements,
+                        ..quote.clone()
+                    }
+                    .into()
+                })
+                .collect()
+        }
+        SlackRichTextElement::List(list) => {
+            let mut lists: Vec<SlackRichTextList> = Vec::new();
+            let mut current_length = 0;
+            for (index, item) in list.elements.iter().enumerate() {
+                let length = list_element_length(item);
+                match lists.last_mut() {
+                    Some(current) if current_length + length <= max_length => {
+                        current.elements.push(item.clone());
+                        current_length += length;
+                    }
+                    _ => {
+                        let offset = list.offset.unwrap_or(0) + index as u64;
+                        lists.push(SlackRichTextList {
+                            elements: vec![item.clone()],
+                            offset: Some(offset).filter(|offset| {
+                                list.style == SlackRichTextListStyle::Ordered && *offset > 0
+                            }),
+                            ..list.clone()
+                        });
+                        current_length = length;
+                    }
+                }
+            }
+            lists.into_iter().map(|list| list.into()).collect()
+        }
+        SlackRichTextElement::Unknown(_) => vec![element],
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::SlackMrkdwnBuilder;
+
+    #[test]
+    fn test_markdown_to_mrkdwn() {
+        let markdown = r#"# Build report
+
+**Status**: _passed_ with ~~no~~ `1 < 2` warnings & [logs](https://ci.example.com/1).
+
+- first
+- second
+  1. nested
+
+> quoted text
+
+```rust
+let a = b && c;
+```"#;
+
+        assert_eq!(
+            SlackMarkdownConverter::new().to_mrkdwn(markdown),
+            r#"*Build report*
+
+*Status*: _passed_ with ~no~ `1 &lt; 2` warnings &amp; <https://ci.example.com/1|logs>.
+
+• first
+• second
+    1. nested
+
+> quoted text
+
+```
+let a = b &amp;&amp; c;
+```"#
+        );
+    }
+
+    #[test]
+    fn test_markdown_to_mrkdwn_nested_emphasis() {
+        let converter = SlackMarkdownConverter::new();
+
+        assert_eq!(converter.to_mrkdwn("**bold _it_ x**"), "*bold _it_ x*");
+        assert_eq!(
+            converter.to_mrkdwn("_it **bold**_ and ~~**gone**~~"),
+            "_it *bold*_ and *~gone~*"
+        );
+        assert_eq!(
+            converter.to_mrkdwn("***both*** **b *i* c**"),
+            "*_both_ b _i_ c*"
+        );
+        assert_eq!(converter.to_mrkdwn("**bold **"), "∗∗bold ∗∗");
+        assert_eq!(converter.to_mrkdwn("a **b** c"), "a *b* c");
+    }
+
+    #[test]
+    fn test_markdown_to_mrkdwn_headings() {
+        assert_eq!(
+            SlackMarkdownConverter::new()
+                .to_mrkdwn("## Run `cargo test` in [the repo](https://example.com/r) now"),
+            "*Run `cargo test` in <https://example.com/r|the repo> now*"
+        );
+    }
+
+    #[test]
+    fn test_markdown_to_mrkdwn_escaped_delimiters() {
+        let converter = SlackMarkdownConverter::new();
+
+        assert_eq!(
+            converter.to_mrkdwn(r"\*not bold\* and \_not it\_"),
+            "∗not bold∗ and ＿not it＿"
+        );
+        assert_eq!(
+            converter.to_mrkdwn("**a*b** snake_case 2 * 3"),
+            "*a∗b* snake_case 2 * 3"
+        );
+        assert_eq!(converter.to_mrkdwn("`` a`b ``"), "`aˋb`");
+        assert_eq!(
+            converter.to_mrkdwn("````\n```\nfenced\n````"),
+            "```\nˋˋˋ\nfenced\n```"
+        );
+    }
+
+    #[test]
+    fn test_markdown_to_mrkdwn_quotes() {
+        let converter = SlackMarkdownConverter::new();
+
+        assert_eq!(
+            converter.to_mrkdwn("> intro\n>\n> - one\n> - two\n>   - nested"),
+            "> intro\n>\n> • one\n> • two\n>     ◦ nested"
+        );
+        assert_eq!(
+            converter.to_mrkdwn("> quoted"),
+            SlackMrkdwnBuilder::new().quote("quoted").build()
+        );
+    }
+
+    #[test]
+    fn test_markdown_to_rich_text_blocks() {
+        let blocks = SlackMarkdownConverter::new()
+            .to_rich_text_blocks("Hello **world**\n\n1. one\n2. two\n\n```\ncode\n```");
+
+        assert_eq!(
+            blocks,
+            vec![SlackRichTextBlock::new(vec![
+                SlackRichTextSection::new(vec![
+                    text_element("Hello "),
+                    SlackRichTextInlineElement::Text(
+                        SlackRichTextText::new("world".into())
+                            .with_style(SlackRichTextStyle::new().with_bold(true))
+                    ),
+                    text_element("\n"),
+                ])
+                .into(),
+                SlackRichTextList::new(
+                    SlackRichTextListStyle::Ordered,
+                    vec![
+                        SlackRichTextSection::new(vec![text_element("one")]).into(),
+                        SlackRichTextSection::new(vec![text_element("two")]).into(),
+                    ]
+                )
+                .into(),
+                SlackRichTextPreformatted::new(vec![text_element("code")]).into(),
+            ])]
+        );
+    }
+
+    #[test]
+    fn test_markdown_to_rich_text_blocks_splitting() {
+        let markdown = format!("{}\n\n{}", "a".repeat(8), "b".repeat(12));
+        let blocks = SlackMarkdownConverter::new()
+            .with_max_block_length(10)
+            .to_rich_text_blocks(&markdown);
+
+        assert_eq!(blocks.len(), 3);
+        assert!(blocks.iter().all(|block| block
+            .elements
+            .iter()
+            .map(rich_text_element_length)
+            .sum::<usize>()
+            <= 10));
+    }
+
+    #[test]
+    fn test_markdown_to_rich_text_blocks_splitting_long_output() {
+        let paragraph = "word ".repeat(10);
+        let markdown = format!(
+            "{}\n\n1. first item\n2. second\n3. third",
+            paragraph.trim_end()
+        );
+        let blocks = SlackMarkdownConverter::new()
+            .with_max_block_length(12)
+            .to_rich_text_blocks(&markdown);
+
+        let texts: Vec<String> = blocks
+            .iter()
+            .flat_map(|block| block.elements.iter())
+            .filter_map(|element| match element {
+                SlackRichTextElement::Section(section) => Some(
+                    section
+                        .elements
+                        .iter()
+                        .map(|element| match element {
+                            SlackRichTextInlineElement::Text(text) => text.text.clone(),
+                            _ => String::new(),
+                        })
+                        .collect(),
+                ),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(texts.concat(), format!("{}\n", paragraph.trim_end()));
+        assert!(texts.iter().all(|text| text.chars().count() <= 12));
+
+        let lists: Vec<&SlackRichTextList> = blocks
+            .iter()
+            .flat_map(|block| block.elements.iter())
+            .filter_map(|element| match element {
+                SlackRichTextElement::List(list) => Some(list),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            lists
+                .iter()
+                .map(|list| (list.elements.len(), list.offset))
+                .collect::<Vec<_>>(),
+            vec![(1, None), (2, Some(1))]
+        );
+    }
+}
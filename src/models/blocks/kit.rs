@@ -2,6 +2,7 @@ use rsb_derive::Builder;
 use rvstruct::ValueStruct;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::collections::HashMap;
 use url::Url;
 
 use super::workflow::SlackBlockWorkflowButtonElement;
@@ -54,10 +55,15 @@ pub enum SlackBlock {
     Carousel(SlackCarouselBlock),
     #[serde(rename = "context_actions")]
     ContextActions(SlackContextActionsBlock),
+    #[serde(rename = "call")]
+    Call(SlackCallBlock),
     #[serde(rename = "share_shortcut")]
-    ShareShortcut(serde_json::Value),
+    ShareShortcut(SlackShareShortcutBlock),
     #[serde(rename = "event")]
-    Event(serde_json::Value),
+    Event(SlackEventBlock),
+    /// Blocks this version doesn't model yet, kept as is
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
 }
 
 #[skip_serializing_none]
@@ -220,8 +226,12 @@ pub enum SlackSectionBlockElement {
     RadioButtons(SlackBlockRadioButtonsElement),
     #[serde(rename = "checkboxes")]
     Checkboxes(SlackBlockCheckboxesElement),
+    #[serde(rename = "datetimepicker")]
+    DateTimePicker(SlackBlockDateTimePickerElement),
     #[serde(rename = "workflow_button")]
     WorkflowButton(SlackBlockWorkflowButtonElement),
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
@@ -249,16 +259,32 @@ pub enum SlackActionBlockElement {
     Checkboxes(SlackBlockCheckboxesElement),
     #[serde(rename = "static_select")]
     StaticSelect(SlackBlockStaticSelectElement),
+    #[serde(rename = "multi_static_select")]
+    MultiStaticSelect(SlackBlockMultiStaticSelectElement),
     #[serde(rename = "external_select")]
     ExternalSelect(SlackBlockExternalSelectElement),
+    #[serde(rename = "multi_external_select")]
+    MultiExternalSelect(SlackBlockMultiExternalSelectElement),
     #[serde(rename = "users_select")]
     UsersSelect(SlackBlockUsersSelectElement),
+    #[serde(rename = "multi_users_select")]
+    MultiUsersSelect(SlackBlockMultiUsersSelectElement),
     #[serde(rename = "conversations_select")]
     ConversationsSelect(SlackBlockConversationsSelectElement),
+    #[serde(rename = "multi_conversations_select")]
+    MultiConversationsSelect(SlackBlockMultiConversationsSelectElement),
     #[serde(rename = "channels_select")]
     ChannelsSelect(SlackBlockChannelsSelectElement),
+    #[serde(rename = "multi_channels_select")]
+    MultiChannelsSelect(SlackBlockMultiChannelsSelectElement),
+    #[serde(rename = "email_text_input")]
+    EmailInput(SlackBlockEmailInputElement),
+    #[serde(rename = "rich_text_input")]
+    RichTextInput(SlackBlockRichTextInputElement),
     #[serde(rename = "workflow_button")]
     WorkflowButton(SlackBlockWorkflowButtonElement),
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
@@ -270,6 +296,8 @@ pub enum SlackContextBlockElement {
     Plain(SlackBlockPlainText),
     #[serde(rename = "mrkdwn")]
     MarkDown(SlackBlockMarkDownText),
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
@@ -317,6 +345,8 @@ pub enum SlackInputBlockElement {
     RichTextInput(SlackBlockRichTextInputElement),
     #[serde(rename = "file_input")]
     FileInput(SlackBlockFileInputElement),
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
 }
 
 #[skip_serializing_none]
@@ -450,6 +480,12 @@ impl From<SlackBlockMultiStaticSelectElement> for SlackInputBlockElement {
     }
 }
 
+impl From<SlackBlockMultiStaticSelectElement> for SlackActionBlockElement {
+    fn from(element: SlackBlockMultiStaticSelectElement) -> Self {
+        SlackActionBlockElement::MultiStaticSelect(element)
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
 pub struct SlackBlockExternalSelectElement {
@@ -503,6 +539,12 @@ impl From<SlackBlockMultiExternalSelectElement> for SlackInputBlockElement {
     }
 }
 
+impl From<SlackBlockMultiExternalSelectElement> for SlackActionBlockElement {
+    fn from(element: SlackBlockMultiExternalSelectElement) -> Self {
+        SlackActionBlockElement::MultiExternalSelect(element)
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
 pub struct SlackBlockUsersSelectElement {
@@ -554,6 +596,12 @@ impl From<SlackBlockMultiUsersSelectElement> for SlackInputBlockElement {
     }
 }
 
+impl From<SlackBlockMultiUsersSelectElement> for SlackActionBlockElement {
+    fn from(element: SlackBlockMultiUsersSelectElement) -> Self {
+        SlackActionBlockElement::MultiUsersSelect(element)
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum SlackConversationFilterInclude {
     #[serde(rename = "im")]
@@ -630,6 +678,12 @@ impl From<SlackBlockMultiConversationsSelectElement> for SlackInputBlockElement
     }
 }
 
+impl From<SlackBlockMultiConversationsSelectElement> for SlackActionBlockElement {
+    fn from(element: SlackBlockMultiConversationsSelectElement) -> Self {
+        SlackActionBlockElement::MultiConversationsSelect(element)
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
 pub struct SlackBlockChannelsSelectElement {
@@ -682,6 +736,12 @@ impl From<SlackBlockMultiChannelsSelectElement> for SlackInputBlockElement {
     }
 }
 
+impl From<SlackBlockMultiChannelsSelectElement> for SlackActionBlockElement {
+    fn from(element: SlackBlockMultiChannelsSelectElement) -> Self {
+        SlackActionBlockElement::MultiChannelsSelect(element)
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
 pub struct SlackBlockOverflowElement {
@@ -768,6 +828,12 @@ pub struct SlackBlockDateTimePickerElement {
     pub focus_on_load: Option<bool>,
 }
 
+impl From<SlackBlockDateTimePickerElement> for SlackSectionBlockElement {
+    fn from(element: SlackBlockDateTimePickerElement) -> Self {
+        SlackSectionBlockElement::DateTimePicker(element)
+    }
+}
+
 impl From<SlackBlockDateTimePickerElement> for SlackInputBlockElement {
     fn from(element: SlackBlockDateTimePickerElement) -> Self {
         SlackInputBlockElement::DateTimePicker(element)
@@ -898,6 +964,12 @@ impl From<SlackBlockEmailInputElement> for SlackInputBlockElement {
     }
 }
 
+impl From<SlackBlockEmailInputElement> for SlackActionBlockElement {
+    fn from(element: SlackBlockEmailInputElement) -> Self {
+        SlackActionBlockElement::EmailInput(element)
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
 pub struct SlackBlockRadioButtonsElement {
@@ -1142,6 +1214,8 @@ impl From<SlackRichTextBlock> for SlackBlock {
 pub enum SlackRichTextInlineContent {
     #[serde(rename = "rich_text")]
     RichText(SlackRichTextBlock),
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
 }
 
 impl From<SlackRichTextBlock> for SlackRichTextInlineContent {
@@ -1161,6 +1235,8 @@ pub enum SlackRichTextElement {
     Preformatted(SlackRichTextPreformatted),
     #[serde(rename = "rich_text_quote")]
     Quote(SlackRichTextQuote),
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
 }
 
 impl From<SlackRichTextSection> for SlackRichTextElement {
@@ -1208,6 +1284,8 @@ pub struct SlackRichTextList {
 pub enum SlackRichTextListElement {
     #[serde(rename = "rich_text_section")]
     Section(SlackRichTextSection),
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
 }
 
 impl From<SlackRichTextSection> for SlackRichTextListElement {
@@ -1383,6 +1461,12 @@ impl From<SlackBlockRichTextInputElement> for SlackInputBlockElement {
     }
 }
 
+impl From<SlackBlockRichTextInputElement> for SlackActionBlockElement {
+    fn from(element: SlackBlockRichTextInputElement) -> Self {
+        SlackActionBlockElement::RichTextInput(element)
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum SlackImageUrlOrFile {
@@ -1445,6 +1529,8 @@ pub enum SlackTableCell {
     RawText(SlackTableRawTextCell),
     #[serde(rename = "rich_text")]
     RichText(SlackTableRichTextCell),
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
 }
 
 #[skip_serializing_none]
@@ -1504,6 +1590,8 @@ pub enum SlackTaskCardStatus {
     InProgress,
     Complete,
     Error,
+    #[serde(other)]
+    Unknown,
 }
 
 /**
@@ -1520,6 +1608,8 @@ pub struct SlackUrlSourceElement {
 pub enum SlackTaskCardSource {
     #[serde(rename = "url")]
     Url(SlackUrlSourceElement),
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
 }
 
 impl From<SlackUrlSourceElement> for SlackTaskCardSource {
@@ -1555,6 +1645,8 @@ pub enum SlackAlertLevel {
     Error,
     Info,
     Success,
+    #[serde(other)]
+    Unknown,
 }
 
 #[skip_serializing_none]
@@ -1579,6 +1671,8 @@ impl From<SlackAlertBlock> for SlackBlock {
 pub enum SlackCardImageElement {
     #[serde(rename = "image")]
     Image(SlackBlockImageElement),
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
 }
 
 impl From<SlackBlockImageElement> for SlackCardImageElement {
@@ -1592,6 +1686,8 @@ impl From<SlackBlockImageElement> for SlackCardImageElement {
 pub enum SlackCardActionBlockElement {
     #[serde(rename = "button")]
     Button(SlackBlockButtonElement),
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
 }
 
 impl From<SlackBlockButtonElement> for SlackCardActionBlockElement {
@@ -1675,6 +1771,8 @@ pub enum SlackContextActionBlockElement {
     FeedbackButtons(SlackBlockFeedbackButtonsElement),
     #[serde(rename = "icon_button")]
     IconButton(SlackBlockIconButtonElement),
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
 }
 
 impl From<SlackBlockFeedbackButtonsElement> for SlackContextActionBlockElement {
@@ -1702,6 +1800,72 @@ impl From<SlackContextActionsBlock> for SlackBlock {
     }
 }
 
+/**
+ * https://api.slack.com/apis/calls#post_to_channel
+ */
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackCallBlock {
+    pub call_id: SlackCallId,
+    pub block_id: Option<SlackBlockId>,
+    pub api_decoration_available: Option<bool>,
+    /// Call details that Slack adds when it renders the block in messages
+    pub call: Option<SlackCallBlockInfo>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackCallBlockInfo {
+    pub v1: Option<SlackCall>,
+    pub media_backend_type: Option<String>,
+}
+
+impl From<SlackCallBlock> for SlackBlock {
+    fn from(block: SlackCallBlock) -> Self {
+        SlackBlock::Call(block)
+    }
+}
+
+/**
+ * Workflow shortcuts shared in messages. Slack doesn't document this block,
+ * so only the common fields are typed and the rest are kept in `fields`.
+ */
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackShareShortcutBlock {
+    pub block_id: Option<SlackBlockId>,
+    pub app_id: Option<SlackAppId>,
+    pub bot_id: Option<SlackBotId>,
+    #[serde(flatten)]
+    #[default = "HashMap::new()"]
+    pub fields: HashMap<String, serde_json::Value>,
+}
+
+impl From<SlackShareShortcutBlock> for SlackBlock {
+    fn from(block: SlackShareShortcutBlock) -> Self {
+        SlackBlock::ShareShortcut(block)
+    }
+}
+
+/**
+ * Calendar events that Slack adds to messages. Slack doesn't document this block,
+ * so everything except `block_id` is kept in `fields`.
+ */
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackEventBlock {
+    pub block_id: Option<SlackBlockId>,
+    #[serde(flatten)]
+    #[default = "HashMap::new()"]
+    pub fields: HashMap<String, serde_json::Value>,
+}
+
+impl From<SlackEventBlock> for SlackBlock {
+    fn from(block: SlackEventBlock) -> Self {
+        SlackBlock::Event(block)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1965,6 +2129,7 @@ mod test {
         let output = task_card.output.expect("output should be present");
         let output_block = match output {
             SlackRichTextInlineContent::RichText(b) => b,
+            other => panic!("Expected RichText output, got {other:?}"),
         };
         assert_eq!(output_block.elements.len(), 1);
 
@@ -1972,6 +2137,7 @@ mod test {
         assert_eq!(sources.len(), 2);
         match &sources[0] {
             SlackTaskCardSource::Url(u) => assert_eq!(u.text, "weather.com"),
+            other => panic!("Expected Url source, got {other:?}"),
         }
 
         Ok(())
@@ -1987,6 +2153,50 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_slack_task_card_block_future_values() -> Result<(), Box<dyn std::error::Error>> {
+        let payload = serde_json::json!({
+            "type": "task_card",
+            "task_id": "task_1",
+            "title": "Fetching weather data",
+            "status": "paused",
+            "output": { "type": "markdown", "text": "*Done*" },
+            "sources": [
+                { "type": "file", "file_id": "F1" },
+                { "type": "url", "url": "https://weather.com", "text": "weather.com" }
+            ]
+        });
+        let task_card = match serde_json::from_value(payload)? {
+            SlackBlock::TaskCard(task_card) => task_card,
+            other => panic!("Expected TaskCard block, got {other:?}"),
+        };
+
+        assert_eq!(task_card.status, Some(SlackTaskCardStatus::Unknown));
+        assert!(matches!(
+            task_card.output,
+            Some(SlackRichTextInlineContent::Unknown(ref value)) if value["type"] == "markdown"
+        ));
+        let sources = task_card.sources.expect("sources should be present");
+        assert!(
+            matches!(&sources[0], SlackTaskCardSource::Unknown(value) if value["file_id"] == "F1")
+        );
+        assert!(matches!(&sources[1], SlackTaskCardSource::Url(_)));
+
+        let alert: SlackBlock = serde_json::from_value(serde_json::json!({
+            "type": "alert",
+            "text": { "type": "plain_text", "text": "Heads up" },
+            "level": "critical"
+        }))?;
+        assert!(matches!(
+            alert,
+            SlackBlock::Alert(SlackAlertBlock {
+                level: Some(SlackAlertLevel::Unknown),
+                ..
+            })
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_slack_alert_block_deserialize() -> Result<(), Box<dyn std::error::Error>> {
         let payload = include_str!("./fixtures/slack_alert_block.json");
@@ -2024,6 +2234,7 @@ mod test {
                     SlackCardActionBlockElement::Button(btn) => {
                         assert_eq!(btn.style, Some(SlackBlockButtonStyle::Primary));
                     }
+                    other => panic!("Expected Button element, got {other:?}"),
                 }
             }
             _ => panic!("Expected Card block"),
@@ -2176,4 +2387,121 @@ mod test {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_call_block_deserialize() -> Result<(), Box<dyn std::error::Error>> {
+        let payload = include_str!("./fixtures/slack_call_block.json");
+        let block: SlackBlock = serde_json::from_str(payload)?;
+        match block {
+            SlackBlock::Call(call) => {
+                assert_eq!(call.call_id, "R0123ABCDEF".into());
+                let info = call.call.expect("call details should be present");
+                assert_eq!(info.media_backend_type, Some("platform_call".to_string()));
+                let v1 = info.v1.expect("v1 call should be present");
+                assert_eq!(v1.id, "R0123ABCDEF".into());
+                assert_eq!(v1.name, Some("Daily standup".to_string()));
+                assert_eq!(v1.has_ended, Some(false));
+            }
+            _ => panic!("Expected Call block"),
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_blocks_and_elements_roundtrip() -> Result<(), Box<dyn std::error::Error>> {
+        let payload = serde_json::json!([
+            {
+                "type": "some_future_block",
+                "block_id": "b1"
+            },
+            {
+                "type": "actions",
+                "elements": [
+                    { "type": "rich_text_input", "action_id": "notes" },
+                    { "type": "some_future_element", "action_id": "a1" }
+                ]
+            },
+            {
+                "type": "section",
+                "text": { "type": "mrkdwn", "text": "When?" },
+                "accessory": { "type": "datetimepicker", "action_id": "when" }
+            },
+            {
+                "type": "share_shortcut",
+                "block_id": "b2",
+                "app_id": "A1",
+                "title": "Deploy"
+            },
+            {
+                "type": "rich_text",
+                "elements": [
+                    { "type": "rich_text_future" },
+                    {
+                        "type": "rich_text_list",
+                        "style": "bullet",
+                        "elements": [{ "type": "rich_text_future_item" }]
+                    }
+                ]
+            },
+            {
+                "type": "table",
+                "rows": [[{ "type": "raw_text", "text": "a" }, { "type": "future_cell" }]]
+            }
+        ]);
+        let blocks: Vec<SlackBlock> = serde_json::from_value(payload.clone())?;
+
+        assert!(
+            matches!(&blocks[0], SlackBlock::Unknown(value) if value["type"] == "some_future_block")
+        );
+        match &blocks[1] {
+            SlackBlock::Actions(actions) => {
+                assert!(matches!(
+                    actions.elements[0],
+                    SlackActionBlockElement::RichTextInput(_)
+                ));
+                assert!(matches!(
+                    actions.elements[1],
+                    SlackActionBlockElement::Unknown(_)
+                ));
+            }
+            other => panic!("Expected Actions block, got {other:?}"),
+        }
+        assert!(matches!(
+            &blocks[2],
+            SlackBlock::Section(SlackSectionBlock {
+                accessory: Some(SlackSectionBlockElement::DateTimePicker(_)),
+                ..
+            })
+        ));
+        match &blocks[3] {
+            SlackBlock::ShareShortcut(shortcut) => {
+                assert_eq!(shortcut.app_id, Some("A1".into()));
+                assert_eq!(shortcut.fields["title"], "Deploy");
+            }
+            other => panic!("Expected ShareShortcut block, got {other:?}"),
+        }
+        match &blocks[4] {
+            SlackBlock::RichText(rich_text) => {
+                assert!(matches!(
+                    rich_text.elements[0],
+                    SlackRichTextElement::Unknown(_)
+                ));
+                assert!(matches!(
+                    &rich_text.elements[1],
+                    SlackRichTextElement::List(list)
+                        if matches!(list.elements[0], SlackRichTextListElement::Unknown(_))
+                ));
+            }
+            other => panic!("Expected RichText block, got {other:?}"),
+        }
+        match &blocks[5] {
+            SlackBlock::Table(table) => {
+                assert!(matches!(table.rows[0][1], SlackTableCell::Unknown(_)));
+            }
+            other => panic!("Expected Table block, got {other:?}"),
+        }
+
+        assert_eq!(serde_json::to_value(&blocks)?, payload);
+        Ok(())
+    }
 }
@@ -1,11 +1,19 @@
+mod block_kit_builder;
+mod commonmark;
 mod datetime;
 mod dsl;
 mod kit;
+mod validation;
 mod view;
+mod view_state;
 mod workflow;
 
+pub use block_kit_builder::*;
+pub use commonmark::*;
 pub use datetime::*;
 pub use dsl::*;
 pub use kit::*;
+pub use validation::*;
 pub use view::*;
+pub use view_state::*;
 pub use workflow::*;
@@ -0,0 +1,798 @@
+use crate::blocks::{SlackBlock, SlackView};
+use crate::SlackMessageContent;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The surfaces where blocks are rendered. Some blocks and elements are only allowed in some of them.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SlackBlocksSurface {
+    Message,
+    Modal,
+    Home,
+}
+
+impl Display for SlackBlocksSurface {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            SlackBlocksSurface::Message => write!(f, "messages"),
+            SlackBlocksSurface::Modal => write!(f, "modals"),
+            SlackBlocksSurface::Home => write!(f, "home tabs"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SlackBlockValidationErrorKind {
+    TooLong { max: usize, actual: usize },
+    TooMany { max: usize, actual: usize },
+    TooFew { min: usize, actual: usize },
+    Duplicate { value: String },
+    NotAllowed { surface: SlackBlocksSurface },
+    NotAllowedInBlock { block_type: String },
+    Missing,
+}
+
+/// A violation of the Block Kit limits with the JSON path to the offending field
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SlackBlockValidationError {
+    pub path: String,
+    pub kind: SlackBlockValidationErrorKind,
+}
+
+impl Display for SlackBlockValidationError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match &self.kind {
+            SlackBlockValidationErrorKind::TooLong { max, actual } => write!(
+                f,
+                "{}: must be at most {} characters long, but it is {}",
+                self.path, max, actual
+            ),
+            SlackBlockValidationErrorKind::TooMany { max, actual } => write!(
+                f,
+                "{}: must have at most {} items, but it has {}",
+                self.path, max, actual
+            ),
+            SlackBlockValidationErrorKind::TooFew { min, actual } => write!(
+                f,
+                "{}: must have at least {} items, but it has {}",
+                self.path, min, actual
+            ),
+            SlackBlockValidationErrorKind::Duplicate { value } => {
+                write!(f, "{}: duplicate value '{}'", self.path, value)
+            }
+            SlackBlockValidationErrorKind::NotAllowed { surface } => {
+                write!(f, "{}: isn't allowed in {}", self.path, surface)
+            }
+            SlackBlockValidationErrorKind::NotAllowedInBlock { block_type } => {
+                write!(f, "{}: isn't allowed in {} blocks", self.path, block_type)
+            }
+            SlackBlockValidationErrorKind::Missing => {
+                write!(f, "{}: required value is missing", self.path)
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SlackBlocksValidationError {
+    pub errors: Vec<SlackBlockValidationError>,
+}
+
+impl Display for SlackBlocksValidationError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "Slack blocks validation failed: ")?;
+        for (index, error) in self.errors.iter().enumerate() {
+            if index > 0 {
+                write!(f, "; ")?;
+            }
+            error.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for SlackBlocksValidationError {}
+
+pub type SlackBlocksValidationResult = Result<(), SlackBlocksValidationError>;
+
+/// The block types that can contain the interactive elements, see the Block Kit reference
+fn element_block_types(element_type: &str) -> Option<&'static [&'static str]> {
+    match element_type {
+        "button" | "overflow" | "workflow_button" => Some(&["section", "actions"]),
+        "checkboxes"
+        | "radio_buttons"
+        | "datepicker"
+        | "timepicker"
+        | "static_select"
+        | "external_select"
+        | "users_select"
+        | "conversations_select"
+        | "channels_select"
+        | "multi_static_select"
+        | "multi_external_select"
+        | "multi_users_select"
+        | "multi_conversations_select"
+        | "multi_channels_select" => Some(&["section", "actions", "input"]),
+        "datetimepicker" => Some(&["actions", "input"]),
+        "plain_text_input" | "email_text_input" | "url_text_input" | "number_input"
+        | "file_input" | "rich_text_input" => Some(&["input"]),
+        "image" => Some(&["section"]),
+        _ => None,
+    }
+}
+
+impl SlackBlock {
+    /// Checks the block against the documented Block Kit limits:
+    /// - the lengths of texts, ids, values and URLs;
+    /// - the number of blocks, fields, elements, options and option groups;
+    /// - unique block ids in messages and views, and unique action ids in blocks;
+    /// - the block types that can contain each interactive element (e.g. text inputs only in input blocks);
+    /// - the surfaces of `file` and `markdown` blocks (messages), `file_input` elements
+    ///   and `response_url_enabled` selects (modals).
+    ///
+    /// The surface specific rules are checked only for messages and views.
+    pub fn validate(&self) -> SlackBlocksValidationResult {
+        let mut validator = SlackBlocksValidator::new(None);
+        validator.block("$", &serde_json::to_value(self).unwrap_or(Value::Null));
+        validator.result()
+    }
+}
+
+impl SlackMessageContent {
+    pub fn validate(&self) -> SlackBlocksValidationResult {
+        let mut validator = SlackBlocksValidator::new(Some(SlackBlocksSurface::Message));
+        if let Some(blocks) = &self.blocks {
+            validator.blocks(
+                "$.blocks",
+                &serde_json::to_value(blocks).unwrap_or(Value::Null),
+                50,
+            );
+        }
+        validator.result()
+    }
+}
+
+impl SlackView {
+    pub fn validate(&self) -> SlackBlocksValidationResult {
+        let (surface, view) = match self {
+            SlackView::Home(view) => (
+                SlackBlocksSurface::Home,
+                serde_json::to_value(view).unwrap_or(Value::Null),
+            ),
+            SlackView::Modal(view) => (
+                SlackBlocksSurface::Modal,
+                serde_json::to_value(view).unwrap_or(Value::Null),
+            ),
+        };
+
+        let mut validator = SlackBlocksValidator::new(Some(surface));
+        validator.text("$.title", &view["title"], 24);
+        validator.text("$.submit", &view["submit"], 24);
+        validator.text("$.close", &view["close"], 24);
+        validator.max_len("$.private_metadata", &view["private_metadata"], 3000);
+        validator.max_len("$.callback_id", &view["callback_id"], 255);
+        validator.max_len("$.external_id", &view["external_id"], 255);
+        validator.blocks("$.blocks", &view["blocks"], 100);
+        validator.result()
+    }
+}
+
+struct SlackBlocksValidator {
+    surface: Option<SlackBlocksSurface>,
+    errors: Vec<SlackBlockValidationError>,
+}
+
+impl SlackBlocksValidator {
+    fn new(surface: Option<SlackBlocksSurface>) -> Self {
+        Self {
+            surface,
+            errors: Vec::new(),
+        }
+    }
+
+    fn result(self) -> SlackBlocksValidationResult {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(SlackBlocksValidationError {
+                errors: self.errors,
+            })
+        }
+    }
+
+    fn error(&mut self, path: String, kind: SlackBlockValidationErrorKind) {
+        self.errors.push(SlackBlockValidationError { path, kind });
+    }
+
+    fn max_len(&mut self, path: &str, value: &Value, max: usize) {
+        if let Some(str) = value.as_str() {
+            let actual = str.chars().count();
+            if actual > max {
+                self.error(
+                    path.to_string(),
+                    SlackBlockValidationErrorKind::TooLong { max, actual },
+                );
+            }
+        }
+    }
+
+    /// Text objects are checked by their `text` field
+    fn text(&mut self, path: &str, value: &Value, max: usize) {
+        self.max_len(&format!("{}.text", path), &value["text"], max);
+    }
+
+    fn items<'a>(&mut self, path: &str, value: &'a Value, min: usize, max: usize) -> &'a [Value] {
+        match value.as_array() {
+            Some(items) => {
+                if items.len() > max {
+                    self.error(
+                        path.to_string(),
+                        SlackBlockValidationErrorKind::TooMany {
+                            max,
+                            actual: items.len(),
+                        },
+                    );
+                } else if items.len() < min {
+                    self.error(
+                        path.to_string(),
+                        SlackBlockValidationErrorKind::TooFew {
+                            min,
+                            actual: items.len(),
+                        },
+                    );
+                }
+                items
+            }
+            None => &[],
+        }
+    }
+
+    fn allowed_in(&mut self, path: &str, surfaces: &[SlackBlocksSurface]) {
+        if let Some(surface) = self.surface.filter(|surface| !surfaces.contains(surface)) {
+            self.error(
+                path.to_string(),
+                SlackBlockValidationErrorKind::NotAllowed { surface },
+            );
+        }
+    }
+
+    fn unique<'a>(&mut self, path: String, value: &'a Value, seen: &mut HashSet<&'a str>) {
+        if let Some(str) = value.as_str() {
+            if !seen.insert(str) {
+                self.error(
+                    path,
+                    SlackBlockValidationErrorKind::Duplicate {
+                        value: str.to_string(),
+                    },
+                );
+            }
+        }
+    }
+
+    fn blocks(&mut self, path: &str, blocks: &Value, max: usize) {
+        let mut block_ids = HashSet::new();
+        for (index, block) in self.items(path, blocks, 0, max).iter().enumerate() {
+            let block_path = format!("{}[{}]", path, index);
+            self.unique(
+                format!("{}.block_id", block_path),
+                &block["block_id"],
+                &mut block_ids,
+            );
+            self.block(&block_path, block);
+        }
+    }
+
+    fn block(&mut self, path: &str, block: &Value) {
+        self.max_len(&format!("{}.block_id", path), &block["block_id"], 255);
+
+        let mut elements: Vec<(String, &Value)> = Vec::new();
+        match block["type"].as_str().unwrap_or_default() {
+            "section" => {
+                self.text(&format!("{}.text", path), &block["text"], 3000);
+                let fields_path = format!("{}.fields", path);
+                for (index, field) in self
+                    .items(&fields_path, &block["fields"], 0, 10)
+                    .iter()
+                    .enumerate()
+                {
+                    self.text(&format!("{}[{}]", fields_path, index), field, 2000);
+                }
+                if block["text"].is_null() && block["fields"].is_null() {
+                    self.error(
+                        format!("{}.text", path),
+                        SlackBlockValidationErrorKind::Missing,
+                    );
+                }
+                if !block["accessory"].is_null() {
+                    elements.push((format!("{}.accessory", path), &block["accessory"]));
+                }
+            }
+            "header" => {
+                self.text(&format!("{}.text", path), &block["text"], 150);
+            }
+            "image" => {
+                self.image(path, block);
+                self.text(&format!("{}.title", path), &block["title"], 2000);
+            }
+            "actions" => {
+                let elements_path = format!("{}.elements", path);
+                for (index, element) in self
+                    .items(&elements_path, &block["elements"], 1, 25)
+                    .iter()
+                    .enumerate()
+                {
+                    elements.push((format!("{}[{}]", elements_path, index), element));
+                }
+            }
+            "context" => {
+                let elements_path = format!("{}.elements", path);
+                for (index, element) in self
+                    .items(&elements_path, &block["elements"], 1, 10)
+                    .iter()
+                    .enumerate()
+                {
+                    let element_path = format!("{}[{}]", elements_path, index);
+                    match element["type"].as_str() {
+                        Some("image") => self.image(&element_path, element),
+                        _ => {
+                            self.max_len(&format!("{}.text", element_path), &element["text"], 3000)
+                        }
+                    }
+                }
+            }
+            "input" => {
+                self.text(&format!("{}.label", path), &block["label"], 2000);
+                self.text(&format!("{}.hint", path), &block["hint"], 2000);
+                let element_path = format!("{}.element", path);
+                if block["element"]["type"].as_str() == Some("file_input") {
+                    self.allowed_in(&element_path, &[SlackBlocksSurface::Modal]);
+                }
+                elements.push((element_path, &block["element"]));
+            }
+            "file" => {
+                self.allowed_in(path, &[SlackBlocksSurface::Message]);
+            }
+            "markdown" => {
+                self.allowed_in(path, &[SlackBlocksSurface::Message]);
+                self.max_len(&format!("{}.text", path), &block["text"], 12000);
+            }
+            "video" => {
+                self.text(&format!("{}.title", path), &block["title"], 200);
+                self.max_len(&format!("{}.alt_text", path), &block["alt_text"], 2000);
+            }
+            _ => {}
+        }
+
+        let block_type = block["type"].as_str().unwrap_or_default();
+        let mut action_ids = HashSet::new();
+        for (element_path, element) in elements {
+            let element_type = element["type"].as_str().unwrap_or_default();
+            if element_block_types(element_type)
+                .is_some_and(|block_types| !block_types.contains(&block_type))
+            {
+                self.error(
+                    element_path.clone(),
+                    SlackBlockValidationErrorKind::NotAllowedInBlock {
+                        block_type: block_type.to_string(),
+                    },
+                );
+            }
+            if element["response_url_enabled"].as_bool() == Some(true) {
+                self.allowed_in(
+                    &format!("{}.response_url_enabled", element_path),
+                    &[SlackBlocksSurface::Modal],
+                );
+            }
+            self.unique(
+                format!("{}.action_id", element_path),
+                &element["action_id"],
+                &mut action_ids,
+            );
+            self.element(&element_path, element);
+        }
+    }
+
+    fn image(&mut self, path: &str, image: &Value) {
+        self.max_len(&format!("{}.alt_text", path), &image["alt_text"], 2000);
+        self.max_len(&format!("{}.image_url", path), &image["image_url"], 3000);
+    }
+
+    fn element(&mut self, path: &str, element: &Value) {
+        self.max_len(&format!("{}.action_id", path), &element["action_id"], 255);
+        self.text(
+            &format!("{}.placeholder", path),
+            &element["placeholder"],
+            150,
+        );
+
+        if !element["confirm"].is_null() {
+            self.confirm(&format!("{}.confirm", path), &element["confirm"]);
+        }
+
+        match element["type"].as_str().unwrap_or_default() {
+            "button" | "workflow_button" => {
+                self.text(&format!("{}.text", path), &element["text"], 75);
+                self.max_len(&format!("{}.value", path), &element["value"], 2000);
+                self.max_len(&format!("{}.url", path), &element["url"], 3000);
+                self.max_len(
+                    &format!("{}.accessibility_label", path),
+                    &element["accessibility_label"],
+                    75,
+                );
+            }
+            "image" => self.image(path, element),
+            "static_select" | "multi_static_select" => {
+                self.options(&format!("{}.options", path), &element["options"], 0, 100);
+                let groups_path = format!("{}.option_groups", path);
+                for (index, group) in self
+                    .items(&groups_path, &element["option_groups"], 0, 100)
+                    .iter()
+                    .enumerate()
+                {
+                    let group_path = format!("{}[{}]", groups_path, index);
+                    self.text(&format!("{}.label", group_path), &group["label"], 75);
+                    self.options(
+                        &format!("{}.options", group_path),
+                        &group["options"],
+                        0,
+                        100,
+                    );
+                }
+            }
+            "overflow" => self.options(&format!("{}.options", path), &element["options"], 1, 5),
+            "radio_buttons" | "checkboxes" => {
+                self.options(&format!("{}.options", path), &element["options"], 1, 10)
+            }
+            "plain_text_input" => {
+                for field in ["min_length", "max_length"] {
+                    if let Some(length) = element[field].as_u64() {
+                        if length > 3000 {
+                            self.error(
+                                format!("{}.{}", path, field),
+                                SlackBlockValidationErrorKind::TooLong {
+                                    max: 3000,
+                                    actual: length as usize,
+                                },
+                            );
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn options(&mut self, path: &str, options: &Value, min: usize, max: usize) {
+        for (index, option) in self.items(path, options, min, max).iter().enumerate() {
+            let option_path = format!("{}[{}]", path, index);
+            self.text(&format!("{}.text", option_path), &option["text"], 75);
+            self.max_len(&format!("{}.value", option_path), &option["value"], 150);
+            self.text(
+                &format!("{}.description", option_path),
+                &option["description"],
+                75,
+            );
+            self.max_len(&format!("{}.url", option_path), &option["url"], 3000);
+        }
+    }
+
+    fn confirm(&mut self, path: &str, confirm: &Value) {
+        self.text(&format!("{}.title", path), &confirm["title"], 100);
+        self.text(&format!("{}.text", path), &confirm["text"], 300);
+        self.text(&format!("{}.confirm", path), &confirm["confirm"], 30);
+        self.text(&format!("{}.deny", path), &confirm["deny"], 30);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::api::*;
+    use crate::blocks::*;
+    use crate::errors::*;
+    use crate::*;
+
+    #[test]
+    fn test_message_blocks_validation() {
+        let long_text = "a".repeat(3001);
+        let content = SlackMessageContent::new().with_blocks(slack_blocks![
+            some_into(SlackSectionBlock::new().with_text(md!(long_text))),
+            some_into(SlackActionsBlock::new(slack_blocks![
+                some_into(SlackBlockButtonElement::new(
+                    "approve".into(),
+                    pt!("Approve")
+                )),
+                some_into(SlackBlockButtonElement::new(
+                    "approve".into(),
+                    pt!("Approve again")
+                ))
+            ])),
+            some_into(SlackInputBlock::new(
+                pt!("Attachments"),
+                SlackBlockFileInputElement::new("files".into()).into(),
+            ))
+        ]);
+
+        let errors = content.validate().unwrap_err().errors;
+
+        assert_eq!(
+            errors,
+            vec![
+                SlackBlockValidationError {
+                    path: "$.blocks[0].text.text".into(),
+                    kind: SlackBlockValidationErrorKind::TooLong {
+                        max: 3000,
+                        actual: 3001
+                    }
+                },
+                SlackBlockValidationError {
+                    path: "$.blocks[1].elements[1].action_id".into(),
+                    kind: SlackBlockValidationErrorKind::Duplicate {
+                        value: "approve".into()
+                    }
+                },
+                SlackBlockValidationError {
+                    path: "$.blocks[2].element".into(),
+                    kind: SlackBlockValidationErrorKind::NotAllowed {
+                        surface: SlackBlocksSurface::Message
+                    }
+                },
+            ]
+        );
+
+        assert!(SlackMessageContent::new()
+            .with_blocks(slack_blocks![some_into(
+                SlackSectionBlock::new().with_text(md!("Hello"))
+            )])
+            .validate()
+            .is_ok());
+    }
+
+    fn modal(blocks: Vec<SlackBlock>) -> SlackModalView {
+        SlackModalView::new(pt!("Modal"), blocks)
+    }
+
+    fn option(text: &str, value: &str) -> SlackBlockChoiceItem<SlackBlockPlainTextOnly> {
+        SlackBlockChoiceItem::new(pt!(text), value.into())
+    }
+
+    #[test]
+    fn test_view_validation() {
+        let view = SlackView::Modal(
+            modal((0..101).map(|_| SlackDividerBlock::new().into()).collect())
+                .with_title(pt!("t".repeat(25)))
+                .with_submit(pt!("s".repeat(24)))
+                .with_close(pt!("c".repeat(25)))
+                .with_private_metadata("m".repeat(3001))
+                .with_callback_id("i".repeat(256).into()),
+        );
+
+        assert_eq!(
+            view.validate().unwrap_err().errors,
+            vec![
+                SlackBlockValidationError {
+                    path: "$.title.text".into(),
+                    kind: SlackBlockValidationErrorKind::TooLong {
+                        max: 24,
+                        actual: 25
+                    }
+                },
+                SlackBlockValidationError {
+                    path: "$.close.text".into(),
+                    kind: SlackBlockValidationErrorKind::TooLong {
+                        max: 24,
+                        actual: 25
+                    }
+                },
+                SlackBlockValidationError {
+                    path: "$.private_metadata".into(),
+                    kind: SlackBlockValidationErrorKind::TooLong {
+                        max: 3000,
+                        actual: 3001
+                    }
+                },
+                SlackBlockValidationError {
+                    path: "$.callback_id".into(),
+                    kind: SlackBlockValidationErrorKind::TooLong {
+                        max: 255,
+                        actual: 256
+                    }
+                },
+                SlackBlockValidationError {
+                    path: "$.blocks".into(),
+                    kind: SlackBlockValidationErrorKind::TooMany {
+                        max: 100,
+                        actual: 101
+                    }
+                },
+            ]
+        );
+
+        let view = SlackView::Modal(
+            modal((0..100).map(|_| SlackDividerBlock::new().into()).collect())
+                .with_title(pt!("t".repeat(24)))
+                .with_private_metadata("m".repeat(3000))
+                .with_callback_id("i".repeat(255).into()),
+        );
+        assert!(view.validate().is_ok());
+
+        let home = SlackView::Home(SlackHomeView::new(slack_blocks![some_into(
+            SlackFileBlock::new("F1".into())
+        )]));
+        assert_eq!(
+            home.validate().unwrap_err().errors,
+            vec![SlackBlockValidationError {
+                path: "$.blocks[0]".into(),
+                kind: SlackBlockValidationErrorKind::NotAllowed {
+                    surface: SlackBlocksSurface::Home
+                }
+            }]
+        );
+    }
+
+    #[test]
+    fn test_options_validation() {
+        let select = SlackBlockStaticSelectElement::new("select".into())
+            .with_options((0..101).map(|i| option("Option", &i.to_string())).collect())
+            .with_option_groups(vec![SlackBlockOptionGroup::new(
+                pt!("l".repeat(76)),
+                vec![
+                    option(&"o".repeat(76), "first"),
+                    option("Second", &"v".repeat(151)),
+                ],
+            )]);
+        let overflow = SlackBlockOverflowElement::new("overflow".into(), vec![]);
+        let content = SlackMessageContent::new().with_blocks(slack_blocks![some_into(
+            SlackActionsBlock::new(slack_blocks![some_into(select), some_into(overflow)])
+        )]);
+
+        assert_eq!(
+            content.validate().unwrap_err().errors,
+            vec![
+                SlackBlockValidationError {
+                    path: "$.blocks[0].elements[0].options".into(),
+                    kind: SlackBlockValidationErrorKind::TooMany {
+                        max: 100,
+                        actual: 101
+                    }
+                },
+                SlackBlockValidationError {
+                    path: "$.blocks[0].elements[0].option_groups[0].label.text".into(),
+                    kind: SlackBlockValidationErrorKind::TooLong {
+                        max: 75,
+                        actual: 76
+                    }
+                },
+                SlackBlockValidationError {
+                    path: "$.blocks[0].elements[0].option_groups[0].options[0].text.text".into(),
+                    kind: SlackBlockValidationErrorKind::TooLong {
+                        max: 75,
+                        actual: 76
+                    }
+                },
+                SlackBlockValidationError {
+                    path: "$.blocks[0].elements[0].option_groups[0].options[1].value".into(),
+                    kind: SlackBlockValidationErrorKind::TooLong {
+                        max: 150,
+                        actual: 151
+                    }
+                },
+                SlackBlockValidationError {
+                    path: "$.blocks[0].elements[1].options".into(),
+                    kind: SlackBlockValidationErrorKind::TooFew { min: 1, actual: 0 }
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_confirm_validation() {
+        let confirm = SlackBlockConfirmItem::new(
+            pt!("t".repeat(101)),
+            md!("x".repeat(301)),
+            pt!("c".repeat(31)),
+            pt!("d".repeat(30)),
+        );
+        let block: SlackBlock = SlackSectionBlock::new()
+            .with_text(md!("Delete?"))
+            .with_accessory(
+                SlackBlockButtonElement::new("delete".into(), pt!("Delete"))
+                    .with_confirm(confirm)
+                    .into(),
+            )
+            .into();
+
+        assert_eq!(
+            block.validate().unwrap_err().errors,
+            vec![
+                SlackBlockValidationError {
+                    path: "$.accessory.confirm.title.text".into(),
+                    kind: SlackBlockValidationErrorKind::TooLong {
+                        max: 100,
+                        actual: 101
+                    }
+                },
+                SlackBlockValidationError {
+                    path: "$.accessory.confirm.text.text".into(),
+                    kind: SlackBlockValidationErrorKind::TooLong {
+                        max: 300,
+                        actual: 301
+                    }
+                },
+                SlackBlockValidationError {
+                    path: "$.accessory.confirm.confirm.text".into(),
+                    kind: SlackBlockValidationErrorKind::TooLong {
+                        max: 30,
+                        actual: 31
+                    }
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_element_placement_validation() {
+        let content = SlackMessageContent::new().with_blocks(slack_blocks![
+            some_into(
+                SlackSectionBlock::new()
+                    .with_text(md!("Name"))
+                    .with_accessory(SlackBlockPlainTextInputElement::new("name".into()).into())
+            ),
+            some_into(SlackActionsBlock::new(slack_blocks![
+                some_into(SlackBlockButtonElement::new("ok".into(), pt!("OK"))),
+                some_into(SlackBlockDateTimePickerElement::new("at".into()))
+            ]))
+        ]);
+
+        assert_eq!(
+            content.validate().unwrap_err().errors,
+            vec![SlackBlockValidationError {
+                path: "$.blocks[0].accessory".into(),
+                kind: SlackBlockValidationErrorKind::NotAllowedInBlock {
+                    block_type: "section".into()
+                }
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_client_blocks_validation() {
+        let client = SlackClient::new(crate::test_connector::SlackTestHttpConnector::new())
+            .with_blocks_validation();
+        let token = SlackApiToken::new("xoxb-test".into());
+        let session = client.open_session(&token);
+        let blocks: Vec<SlackBlock> =
+            slack_blocks![some_into(SlackHeaderBlock::new(pt!("h".repeat(151))))];
+
+        let posted = session
+            .chat_post_message(&SlackApiChatPostMessageRequest::new(
+                "C1".into(),
+                SlackMessageContent::new().with_blocks(blocks.clone()),
+            ))
+            .await;
+        assert!(matches!(
+            posted,
+            Err(SlackClientError::BlocksValidationError(_))
+        ));
+
+        let opened = session
+            .views_open(&SlackApiViewsOpenRequest::new(
+                "T1".into(),
+                SlackView::Modal(modal(blocks)),
+            ))
+            .await;
+        assert!(matches!(
+            opened,
+            Err(SlackClientError::BlocksValidationError(_))
+        ));
+
+        assert!(client
+            .http_api
+            .connector
+            .requests("chat.postMessage")
+            .is_empty());
+        assert!(client.http_api.connector.requests("views.open").is_empty());
+    }
+}
@@ -1,8 +1,10 @@
 use crate::blocks::kit::SlackBlock;
 use crate::blocks::{SlackBlockId, SlackBlockPlainText, SlackBlockPlainTextOnly};
+use crate::errors::*;
 use crate::SlackCallbackId;
 use crate::*;
 use rsb_derive::Builder;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, skip_serializing_none};
 use std::collections::HashMap;
@@ -54,6 +56,46 @@ pub struct SlackModalView {
     pub external_id: Option<String>,
 }
 
+/// Slack limits the size of `private_metadata` in views
+pub const SLACK_VIEW_PRIVATE_METADATA_MAX_LEN: usize = 3000;
+
+impl SlackView {
+    pub fn private_metadata(&self) -> Option<&String> {
+        match self {
+            SlackView::Home(view) => view.private_metadata.as_ref(),
+            SlackView::Modal(view) => view.private_metadata.as_ref(),
+        }
+    }
+
+    /// Deserializes `private_metadata` stored with `with_typed_private_metadata`
+    pub fn typed_private_metadata<T: DeserializeOwned>(&self) -> ClientResult<Option<T>> {
+        self.private_metadata()
+            .map(|metadata| {
+                serde_json::from_str(metadata).map_err(|err| map_serde_error(err, Some(metadata)))
+            })
+            .transpose()
+    }
+
+    /// Serializes the metadata to JSON and checks it fits into the Slack limit
+    pub fn with_typed_private_metadata<T: Serialize>(self, metadata: &T) -> ClientResult<Self> {
+        let metadata = serde_json::to_string(metadata).map_err(|err| map_serde_error(err, None))?;
+        if metadata.len() > SLACK_VIEW_PRIVATE_METADATA_MAX_LEN {
+            return Err(SlackClientError::SystemError(
+                SlackClientSystemError::new().with_message(format!(
+                    "View private_metadata is {} characters long, while Slack allows up to {}",
+                    metadata.len(),
+                    SLACK_VIEW_PRIVATE_METADATA_MAX_LEN
+                )),
+            ));
+        }
+
+        Ok(match self {
+            SlackView::Home(view) => SlackView::Home(view.with_private_metadata(metadata)),
+            SlackView::Modal(view) => SlackView::Modal(view.with_private_metadata(metadata)),
+        })
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
 pub struct SlackStatefulView {
@@ -98,6 +140,7 @@ pub struct SlackViewStateValue {
     pub selected_conversations: Option<Vec<SlackConversationId>>,
     pub selected_users: Option<Vec<SlackUserId>>,
     pub selected_options: Option<Vec<SlackViewStateValueSelectedOption>>,
+    pub files: Option<Vec<SlackFile>>,
 }
 
 pub type SlackActionState = SlackViewState;
@@ -123,6 +166,34 @@ pub enum SlackViewSubmissionResponse {
     Errors(SlackViewSubmissionErrorsResponse),
 }
 
+impl SlackViewSubmissionResponse {
+    pub fn clear() -> Self {
+        Self::Clear(SlackViewSubmissionClearResponse::new())
+    }
+
+    pub fn update(view: SlackView) -> Self {
+        Self::Update(SlackViewSubmissionUpdateResponse::new(view))
+    }
+
+    pub fn push(view: SlackView) -> Self {
+        Self::Push(SlackViewSubmissionPushResponse::new(view))
+    }
+
+    /// Shows the errors next to the input blocks with the specified ids
+    pub fn errors<I, M>(errors: I) -> Self
+    where
+        I: IntoIterator<Item = (SlackBlockId, M)>,
+        M: Into<String>,
+    {
+        Self::Errors(SlackViewSubmissionErrorsResponse::new(
+            errors
+                .into_iter()
+                .map(|(block_id, message)| (block_id.0, message.into()))
+                .collect(),
+        ))
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
 pub struct SlackViewSubmissionClearResponse {}
 
@@ -145,6 +216,27 @@ pub struct SlackViewSubmissionErrorsResponse {
 mod test {
     use super::*;
 
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct WizardState {
+        step: u32,
+        project: String,
+    }
+
+    #[test]
+    fn test_typed_private_metadata() -> Result<(), Box<dyn std::error::Error>> {
+        let state = WizardState {
+            step: 2,
+            project: "api".into(),
+        };
+        let view = SlackView::Modal(SlackModalView::new("Wizard".into(), vec![]))
+            .with_typed_private_metadata(&state)?;
+        assert_eq!(view.typed_private_metadata::<WizardState>()?, Some(state));
+
+        let too_long = "x".repeat(SLACK_VIEW_PRIVATE_METADATA_MAX_LEN);
+        assert!(view.with_typed_private_metadata(&too_long).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_slack_view_submission_clear_response_serialization() {
         let output = serde_json::to_string(&SlackViewSubmissionResponse::Clear(
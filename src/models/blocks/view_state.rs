@@ -0,0 +1,407 @@
+use crate::blocks::{
+    SlackBlockId, SlackStatefulView, SlackViewState, SlackViewStateValue,
+    SlackViewStateValueSelectedOption,
+};
+use crate::*;
+use chrono::{NaiveDate, NaiveTime};
+use serde::de::DeserializeOwned;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+///
+/// Rust types that can be read from the input values of the view state.
+///
+pub trait SlackViewStateValueType: Sized {
+    /// Returns `None` if the input is empty or has a different type
+    fn from_state_value(value: &SlackViewStateValue) -> Option<Self>;
+}
+
+/// The text of text inputs or the value of the selected option (selects and radio buttons),
+/// the same as `deserialize_values` reads for `String` fields
+impl SlackViewStateValueType for String {
+    fn from_state_value(value: &SlackViewStateValue) -> Option<Self> {
+        value.value.clone().or_else(|| {
+            value
+                .selected_option
+                .as_ref()
+                .map(|option| option.value.clone())
+        })
+    }
+}
+
+impl SlackViewStateValueType for SlackDate {
+    fn from_state_value(value: &SlackViewStateValue) -> Option<Self> {
+        value.selected_date.clone().map(SlackDate)
+    }
+}
+
+impl SlackViewStateValueType for NaiveDate {
+    fn from_state_value(value: &SlackViewStateValue) -> Option<Self> {
+        SlackDate::from_state_value(value).and_then(|date| date.to_naive_date())
+    }
+}
+
+impl SlackViewStateValueType for NaiveTime {
+    fn from_state_value(value: &SlackViewStateValue) -> Option<Self> {
+        value
+            .selected_time
+            .as_ref()
+            .and_then(|time| NaiveTime::parse_from_str(time, "%H:%M").ok())
+    }
+}
+
+impl SlackViewStateValueType for SlackDateTime {
+    fn from_state_value(value: &SlackViewStateValue) -> Option<Self> {
+        value.selected_date_time.clone()
+    }
+}
+
+impl SlackViewStateValueType for SlackUserId {
+    fn from_state_value(value: &SlackViewStateValue) -> Option<Self> {
+        value.selected_user.clone()
+    }
+}
+
+impl SlackViewStateValueType for Vec<SlackUserId> {
+    fn from_state_value(value: &SlackViewStateValue) -> Option<Self> {
+        value.selected_users.clone()
+    }
+}
+
+impl SlackViewStateValueType for SlackChannelId {
+    fn from_state_value(value: &SlackViewStateValue) -> Option<Self> {
+        value.selected_channel.clone()
+    }
+}
+
+impl SlackViewStateValueType for SlackConversationId {
+    fn from_state_value(value: &SlackViewStateValue) -> Option<Self> {
+        value.selected_conversation.clone()
+    }
+}
+
+impl SlackViewStateValueType for Vec<SlackConversationId> {
+    fn from_state_value(value: &SlackViewStateValue) -> Option<Self> {
+        value.selected_conversations.clone()
+    }
+}
+
+impl SlackViewStateValueType for SlackViewStateValueSelectedOption {
+    fn from_state_value(value: &SlackViewStateValue) -> Option<Self> {
+        value.selected_option.clone()
+    }
+}
+
+impl SlackViewStateValueType for Vec<SlackViewStateValueSelectedOption> {
+    fn from_state_value(value: &SlackViewStateValue) -> Option<Self> {
+        value.selected_options.clone()
+    }
+}
+
+impl SlackViewStateValueType for Vec<SlackFile> {
+    fn from_state_value(value: &SlackViewStateValue) -> Option<Self> {
+        value.files.clone()
+    }
+}
+
+impl SlackViewState {
+    pub fn value(
+        &self,
+        block_id: &SlackBlockId,
+        action_id: &SlackActionId,
+    ) -> Option<&SlackViewStateValue> {
+        self.values
+            .get(block_id)
+            .and_then(|actions| actions.get(action_id))
+    }
+
+    /// Reads the required input value
+    pub fn get<T: SlackViewStateValueType>(
+        &self,
+        block_id: &SlackBlockId,
+        action_id: &SlackActionId,
+    ) -> Result<T, SlackViewStateError> {
+        self.get_opt(block_id, action_id)?
+            .ok_or_else(|| SlackViewStateError::EmptyValue {
+                block_id: block_id.clone(),
+                action_id: action_id.clone(),
+            })
+    }
+
+    /// Reads the optional input value. It is `None` when the user left the input empty.
+    pub fn get_opt<T: SlackViewStateValueType>(
+        &self,
+        block_id: &SlackBlockId,
+        action_id: &SlackActionId,
+    ) -> Result<Option<T>, SlackViewStateError> {
+        self.value(block_id, action_id)
+            .map(T::from_state_value)
+            .ok_or_else(|| SlackViewStateError::MissingInput {
+                block_id: block_id.clone(),
+                action_id: action_id.clone(),
+            })
+    }
+
+    ///
+    /// Deserializes the input values to your own struct using serde.
+    /// The fields are matched with the action ids, and the values are:
+    /// - strings for text inputs, dates, times, users, channels, conversations and selected options (their values);
+    /// - arrays of them for multi-selects;
+    /// - timestamps for date time pickers and arrays of files for file inputs;
+    /// - `null` for empty inputs, so use `Option` for optional inputs.
+    ///
+    /// The action ids must be unique across the blocks, otherwise
+    /// `SlackViewStateError::DuplicateActionId` is returned.
+    ///
+    pub fn deserialize_values<T: DeserializeOwned>(&self) -> Result<T, SlackViewStateError> {
+        let mut values = serde_json::Map::new();
+        for (block_id, actions) in self.values.iter() {
+            for (action_id, value) in actions.iter() {
+                if values
+                    .insert(action_id.0.clone(), Self::value_to_json(value))
+                    .is_some()
+                {
+                    return Err(SlackViewStateError::DuplicateActionId {
+                        block_id: block_id.clone(),
+                        action_id: action_id.clone(),
+                    });
+                }
+            }
+        }
+
+        serde_json::from_value(serde_json::Value::Object(values))
+            .map_err(|err| SlackViewStateError::Deserialize(err.to_string()))
+    }
+
+    fn value_to_json(value: &SlackViewStateValue) -> serde_json::Value {
+        fn option_values(options: &[SlackViewStateValueSelectedOption]) -> serde_json::Value {
+            options
+                .iter()
+                .map(|option| serde_json::Value::String(option.value.clone()))
+                .collect()
+        }
+
+        let json = if let Some(text) = &value.value {
+            serde_json::to_value(text)
+        } else if let Some(date) = &value.selected_date {
+            serde_json::to_value(date)
+        } else if let Some(time) = &value.selected_time {
+            serde_json::to_value(time)
+        } else if let Some(date_time) = &value.selected_date_time {
+            serde_json::to_value(date_time)
+        } else if let Some(user) = &value.selected_user {
+            serde_json::to_value(user)
+        } else if let Some(channel) = &value.selected_channel {
+            serde_json::to_value(channel)
+        } else if let Some(conversation) = &value.selected_conversation {
+            serde_json::to_value(conversation)
+        } else if let Some(option) = &value.selected_option {
+            serde_json::to_value(&option.value)
+        } else if let Some(users) = &value.selected_users {
+            serde_json::to_value(users)
+        } else if let Some(conversations) = &value.selected_conversations {
+            serde_json::to_value(conversations)
+        } else if let Some(options) = &value.selected_options {
+            Ok(option_values(options))
+        } else if let Some(files) = &value.files {
+            serde_json::to_value(files)
+        } else {
+            Ok(serde_json::Value::Null)
+        };
+
+        json.unwrap_or(serde_json::Value::Null)
+    }
+}
+
+impl SlackStatefulView {
+    /// Deserializes the submitted input values. See `SlackViewState::deserialize_values`.
+    pub fn deserialize_state<T: DeserializeOwned>(&self) -> Result<T, SlackViewStateError> {
+        self.state_params
+            .state
+            .clone()
+            .unwrap_or_else(|| SlackViewState::new(Default::default()))
+            .deserialize_values()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum SlackViewStateError {
+    MissingInput {
+        block_id: SlackBlockId,
+        action_id: SlackActionId,
+    },
+    EmptyValue {
+        block_id: SlackBlockId,
+        action_id: SlackActionId,
+    },
+    DuplicateActionId {
+        block_id: SlackBlockId,
+        action_id: SlackActionId,
+    },
+    Deserialize(String),
+}
+
+impl SlackViewStateError {
+    /// The block id to show the error for in `SlackViewSubmissionResponse::errors`
+    pub fn block_id(&self) -> Option<&SlackBlockId> {
+        match self {
+            SlackViewStateError::MissingInput { block_id, .. }
+            | SlackViewStateError::EmptyValue { block_id, .. }
+            | SlackViewStateError::DuplicateActionId { block_id, .. } => Some(block_id),
+            SlackViewStateError::Deserialize(_) => None,
+        }
+    }
+}
+
+impl Display for SlackViewStateError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            SlackViewStateError::MissingInput {
+                block_id,
+                action_id,
+            } => write!(
+                f,
+                "View state doesn't have the input: {}/{}",
+                block_id, action_id
+            ),
+            SlackViewStateError::EmptyValue {
+                block_id,
+                action_id,
+            } => write!(
+                f,
+                "View state input is empty or has a different type: {}/{}",
+                block_id, action_id
+            ),
+            SlackViewStateError::DuplicateActionId {
+                block_id,
+                action_id,
+            } => write!(
+                f,
+                "View state has the same action id in several blocks: {}/{}",
+                block_id, action_id
+            ),
+            SlackViewStateError::Deserialize(message) => {
+                write!(f, "Unable to deserialize view state: {}", message)
+            }
+        }
+    }
+}
+
+impl Error for SlackViewStateError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct TaskForm {
+        title: String,
+        assignee: SlackUserId,
+        priority: String,
+        labels: Vec<String>,
+        due_date: Option<SlackDate>,
+    }
+
+    #[test]
+    fn test_view_state_values() -> Result<(), Box<dyn std::error::Error>> {
+        let state: SlackViewState = serde_json::from_value(serde_json::json!({
+            "values": {
+                "title_block": {
+                    "title": { "type": "plain_text_input", "value": "Release" }
+                },
+                "assignee_block": {
+                    "assignee": { "type": "users_select", "selected_user": "U1" }
+                },
+                "priority_block": {
+                    "priority": {
+                        "type": "static_select",
+                        "selected_option": {
+                            "text": { "type": "plain_text", "text": "High" },
+                            "value": "high"
+                        }
+                    }
+                },
+                "labels_block": {
+                    "labels": {
+                        "type": "multi_static_select",
+                        "selected_options": [{
+                            "text": { "type": "plain_text", "text": "Backend" },
+                            "value": "backend"
+                        }]
+                    }
+                },
+                "due_date_block": {
+                    "due_date": { "type": "datepicker" }
+                }
+            }
+        }))?;
+
+        let assignee: SlackUserId = state.get(&"assignee_block".into(), &"assignee".into())?;
+        assert_eq!(assignee, "U1".into());
+
+        let priority: String = state.get(&"priority_block".into(), &"priority".into())?;
+        assert_eq!(priority, "high");
+        assert_eq!(
+            state.get::<String>(&"due_date_block".into(), &"due_date".into()),
+            Err(SlackViewStateError::EmptyValue {
+                block_id: "due_date_block".into(),
+                action_id: "due_date".into()
+            })
+        );
+
+        let due_date: Option<NaiveDate> =
+            state.get_opt(&"due_date_block".into(), &"due_date".into())?;
+        assert_eq!(due_date, None);
+
+        assert_eq!(
+            state.get::<String>(&"unknown_block".into(), &"title".into()),
+            Err(SlackViewStateError::MissingInput {
+                block_id: "unknown_block".into(),
+                action_id: "title".into()
+            })
+        );
+
+        let form: TaskForm = state.deserialize_values()?;
+        assert_eq!(
+            form,
+            TaskForm {
+                title: "Release".into(),
+                assignee: "U1".into(),
+                priority: "high".into(),
+                labels: vec!["backend".into()],
+                due_date: None,
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_deserialize_values_with_duplicate_action_ids() -> Result<(), Box<dyn std::error::Error>>
+    {
+        #[derive(Debug, Deserialize)]
+        struct NoteForm {
+            #[allow(dead_code)]
+            text: String,
+        }
+
+        let state: SlackViewState = serde_json::from_value(serde_json::json!({
+            "values": {
+                "first_block": {
+                    "text": { "type": "plain_text_input", "value": "First" }
+                },
+                "second_block": {
+                    "text": { "type": "plain_text_input", "value": "Second" }
+                }
+            }
+        }))?;
+
+        let err = state.deserialize_values::<NoteForm>().unwrap_err();
+        assert!(matches!(
+            &err,
+            SlackViewStateError::DuplicateActionId { action_id, .. } if action_id == &"text".into()
+        ));
+        assert!(err.block_id().is_some());
+        Ok(())
+    }
+}
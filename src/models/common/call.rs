@@ -0,0 +1,42 @@
+use crate::*;
+
+use rsb_derive::Builder;
+use rvstruct::*;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+use url::Url;
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct SlackCallId(pub String);
+
+/// A call participant is either a Slack user (`slack_id`) or an external user (`external_id`)
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackCallParticipant {
+    pub slack_id: Option<SlackUserId>,
+    pub external_id: Option<String>,
+    pub display_name: Option<String>,
+    pub avatar_url: Option<Url>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackCall {
+    pub id: SlackCallId,
+    /// Not included in the call details of rendered call blocks
+    pub external_unique_id: Option<String>,
+    pub join_url: Url,
+    pub date_start: Option<SlackDateTime>,
+    pub date_end: Option<SlackDateTime>,
+    pub desktop_app_join_url: Option<Url>,
+    pub external_display_id: Option<String>,
+    pub title: Option<String>,
+    pub created_by: Option<SlackUserId>,
+    pub users: Option<Vec<SlackCallParticipant>>,
+    pub channels: Option<Vec<SlackChannelId>>,
+    pub app_id: Option<SlackAppId>,
+    pub name: Option<String>,
+    pub active_participants: Option<Vec<SlackCallParticipant>>,
+    pub all_participants: Option<Vec<SlackCallParticipant>>,
+    pub has_ended: Option<bool>,
+}
@@ -1,3 +1,393 @@
+use crate::blocks::{fmt_slack_date, SlackBlockMarkDownText, SlackBlockText};
+use chrono::{DateTime, TimeZone};
+use url::Url;
+
 pub trait SlackTextFormat {
     fn to_slack_format(&self) -> String;
 }
+
+/// Escapes the control characters of Slack `mrkdwn`: `&`, `<` and `>`
+pub fn escape_mrkdwn(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// URLs in links and dates can't contain the `mrkdwn` delimiters
+fn escape_mrkdwn_url(url: &Url) -> String {
+    url.as_str()
+        .replace('|', "%7C")
+        .replace('<', "%3C")
+        .replace('>', "%3E")
+}
+
+/// `mrkdwn` has no escapes for the formatting markers, so they are replaced with lookalikes
+const MRKDWN_MARKER_LOOKALIKES: [(char, char); 4] =
+    [('*', '∗'), ('_', '＿'), ('~', '∼'), ('`', 'ˋ')];
+
+fn mrkdwn_marker_lookalike(marker: char) -> Option<char> {
+    MRKDWN_MARKER_LOOKALIKES
+        .iter()
+        .find(|(original, _)| *original == marker)
+        .map(|(_, lookalike)| *lookalike)
+}
+
+///
+/// Replaces the formatting markers in `mrkdwn` with lookalikes:
+/// every one of `markers`, and also any other marker Slack could pair up
+/// (at the start or the end of a word) if `boundaries` is set.
+/// Links, mentions and dates in `<...>` are kept as is.
+///
+pub(crate) fn escape_mrkdwn_markers(mrkdwn: &str, markers: &[char], boundaries: bool) -> String {
+    let chars: Vec<char> = mrkdwn.chars().collect();
+    let is_boundary =
+        |c: Option<&char>| c.is_none_or(|c| c.is_whitespace() || c.is_ascii_punctuation());
+    let mut in_angle_brackets = false;
+
+    chars
+        .iter()
+        .enumerate()
+        .map(|(index, c)| {
+            match c {
+                '<' => in_angle_brackets = true,
+                '>' => in_angle_brackets = false,
+                _ => {}
+            }
+            let lookalike = mrkdwn_marker_lookalike(*c).filter(|_| !in_angle_brackets);
+            let previous = index
+                .checked_sub(1)
+                .and_then(|previous| chars.get(previous));
+            let next = chars.get(index + 1);
+            let pairable = (is_boundary(previous)
+                && next.is_some_and(|next| !next.is_whitespace()))
+                || (previous.is_some_and(|previous| !previous.is_whitespace())
+                    && is_boundary(next));
+            match lookalike {
+                Some(lookalike) if markers.contains(c) || (boundaries && pairable) => lookalike,
+                _ => *c,
+            }
+        })
+        .collect()
+}
+
+/// Code blocks can't contain their closing fence
+pub(crate) fn mrkdwn_code_block(mrkdwn: &str) -> String {
+    format!("```\n{}\n```", mrkdwn.replace("```", "ˋˋˋ"))
+}
+
+/// Quotes every line of `mrkdwn` with `>`
+pub(crate) fn mrkdwn_quote(mrkdwn: &str) -> String {
+    mrkdwn
+        .lines()
+        .map(|line| format!("> {}", line).trim_end().to_string())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+///
+/// The text that can be added to `SlackMrkdwnBuilder`.
+/// Strings are escaped, so untrusted input can't inject mentions, links or dates.
+///
+pub trait SlackMrkdwnFragment {
+    fn to_mrkdwn(&self) -> String;
+}
+
+impl SlackMrkdwnFragment for &str {
+    fn to_mrkdwn(&self) -> String {
+        escape_mrkdwn(self)
+    }
+}
+
+impl SlackMrkdwnFragment for String {
+    fn to_mrkdwn(&self) -> String {
+        self.as_str().to_mrkdwn()
+    }
+}
+
+impl SlackMrkdwnFragment for &String {
+    fn to_mrkdwn(&self) -> String {
+        self.as_str().to_mrkdwn()
+    }
+}
+
+impl SlackMrkdwnFragment for SlackMrkdwnBuilder {
+    fn to_mrkdwn(&self) -> String {
+        self.text.clone()
+    }
+}
+
+///
+/// Builds Slack `mrkdwn` text with everything escaped by default:
+///
+/// ```
+/// use slack_morphism::prelude::*;
+///
+/// let text = SlackMrkdwnBuilder::new()
+///     .user(&"U1".into())
+///     .text(" deployed ")
+///     .bold("<!here> v1.2")
+///     .build();
+///
+/// assert_eq!(text, "<@U1> deployed *&lt;!here&gt; v1.2*");
+/// ```
+///
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct SlackMrkdwnBuilder {
+    text: String,
+    block_ended: bool,
+}
+
+impl SlackMrkdwnBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn text<F: SlackMrkdwnFragment>(self, text: F) -> Self {
+        self.push(&text.to_mrkdwn())
+    }
+
+    /// Appends the text as is, without escaping. Use it only for trusted `mrkdwn`.
+    pub fn raw(self, mrkdwn: &str) -> Self {
+        self.push(mrkdwn)
+    }
+
+    /// The `*` in the text are replaced with `∗`, since `mrkdwn` can't escape them
+    pub fn bold<F: SlackMrkdwnFragment>(self, text: F) -> Self {
+        self.wrapped('*', text)
+    }
+
+    /// The `_` in the text are replaced with `＿`, since `mrkdwn` can't escape them
+    pub fn italic<F: SlackMrkdwnFragment>(self, text: F) -> Self {
+        self.wrapped('_', text)
+    }
+
+    /// The `~` in the text are replaced with `∼`, since `mrkdwn` can't escape them
+    pub fn strike<F: SlackMrkdwnFragment>(self, text: F) -> Self {
+        self.wrapped('~', text)
+    }
+
+    /// The `` ` `` in the code are replaced with `ˋ`, since `mrkdwn` can't escape them
+    pub fn code<F: SlackMrkdwnFragment>(self, text: F) -> Self {
+        self.wrapped('`', text)
+    }
+
+    /// The ```` ``` ```` in the code are replaced with `ˋˋˋ`, so they don't close the block
+    pub fn code_block<F: SlackMrkdwnFragment>(self, text: F) -> Self {
+        self.block(&mrkdwn_code_block(&text.to_mrkdwn()))
+    }
+
+    pub fn quote<F: SlackMrkdwnFragment>(self, text: F) -> Self {
+        self.block(&mrkdwn_quote(&text.to_mrkdwn()))
+    }
+
+    pub fn link<F: SlackMrkdwnFragment>(self, url: &Url, text: F) -> Self {
+        self.push(&format!(
+            "<{}|{}>",
+            escape_mrkdwn_url(url),
+            text.to_mrkdwn()
+        ))
+    }
+
+    pub fn bullet_list<I, F>(self, items: I) -> Self
+    where
+        I: IntoIterator<Item = F>,
+        F: SlackMrkdwnFragment,
+    {
+        let list = items
+            .into_iter()
+            .map(|item| format!("• {}", item.to_mrkdwn()))
+            .collect::<Vec<String>>()
+            .join("\n");
+        self.block(&list)
+    }
+
+    pub fn ordered_list<I, F>(self, items: I) -> Self
+    where
+        I: IntoIterator<Item = F>,
+        F: SlackMrkdwnFragment,
+    {
+        let list = items
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| format!("{}. {}", index + 1, item.to_mrkdwn()))
+            .collect::<Vec<String>>()
+            .join("\n");
+        self.block(&list)
+    }
+
+    pub fn user(self, user_id: &crate::SlackUserId) -> Self {
+        self.push(&user_id.to_slack_format())
+    }
+
+    pub fn channel(self, channel_id: &crate::SlackChannelId) -> Self {
+        self.push(&channel_id.to_slack_format())
+    }
+
+    pub fn usergroup(self, usergroup_id: &crate::SlackUserGroupId) -> Self {
+        self.push(&usergroup_id.to_slack_format())
+    }
+
+    pub fn at_here(self) -> Self {
+        self.push("<!here>")
+    }
+
+    pub fn at_channel(self) -> Self {
+        self.push("<!channel>")
+    }
+
+    pub fn at_everyone(self) -> Self {
+        self.push("<!everyone>")
+    }
+
+    /// The date in the reader's time zone, formatted with Slack date tokens (see `SlackDateTimeFormats`)
+    pub fn date<TZ: TimeZone>(self, date: &DateTime<TZ>, format: &str, link: Option<&Url>) -> Self
+    where
+        <TZ as TimeZone>::Offset: std::fmt::Display,
+    {
+        let link = link.map(escape_mrkdwn_url);
+        let format = format.replace(['^', '|', '<', '>'], "");
+        self.push(&fmt_slack_date(date, &format, link.as_ref()))
+    }
+
+    pub fn newline(self) -> Self {
+        self.push("\n")
+    }
+
+    pub fn build(self) -> String {
+        self.text
+    }
+
+    pub fn to_block_text(self) -> SlackBlockText {
+        SlackBlockMarkDownText::new(self.text).into()
+    }
+
+    fn push(mut self, mrkdwn: &str) -> Self {
+        if self.block_ended {
+            self.text.push('\n');
+            self.block_ended = false;
+        }
+        self.text.push_str(mrkdwn);
+        self
+    }
+
+    /// Slack ignores the markers next to whitespace, so the whitespace is kept outside of them
+    fn wrapped<F: SlackMrkdwnFragment>(self, marker: char, text: F) -> Self {
+        let mrkdwn = escape_mrkdwn_markers(&text.to_mrkdwn(), &[marker], false);
+        let content = mrkdwn.trim();
+        if content.is_empty() {
+            return self.push(&mrkdwn);
+        }
+        let leading = mrkdwn.len() - mrkdwn.trim_start().len();
+        self.push(&format!(
+            "{}{marker}{}{marker}{}",
+            &mrkdwn[..leading],
+            content,
+            &mrkdwn[leading + content.len()..]
+        ))
+    }
+
+    /// Block level formatting (e.g. quotes and lists) must start on its own line
+    fn block(self, mrkdwn: &str) -> Self {
+        let separated = if self.text.is_empty() || self.text.ends_with('\n') || self.block_ended {
+            self
+        } else {
+            self.newline()
+        };
+        Self {
+            block_ended: true,
+            ..separated.push(mrkdwn)
+        }
+    }
+}
+
+impl From<SlackMrkdwnBuilder> for String {
+    fn from(builder: SlackMrkdwnBuilder) -> Self {
+        builder.build()
+    }
+}
+
+impl From<SlackMrkdwnBuilder> for SlackBlockText {
+    fn from(builder: SlackMrkdwnBuilder) -> Self {
+        builder.to_block_text()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mrkdwn_builder_escapes_text() -> Result<(), Box<dyn std::error::Error>> {
+        let untrusted = "<@U2> & <https://evil.example.com|click>";
+        let text = SlackMrkdwnBuilder::new()
+            .bold(SlackMrkdwnBuilder::new().text("Report: ").italic(untrusted))
+            .link(&Url::parse("https://example.com/a?b=c|d")?, "open > now")
+            .bullet_list(["one", "<!channel>"])
+            .quote("first\nsecond")
+            .at_here()
+            .build();
+
+        assert_eq!(
+            text,
+            "*Report: _&lt;@U2&gt; &amp; &lt;https://evil.example.com|click&gt;_*\
+             <https://example.com/a?b=c%7Cd|open &gt; now>\n\
+             • one\n\
+             • &lt;!channel&gt;\n\
+             > first\n\
+             > second\n\
+             <!here>"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_mrkdwn_builder_escapes_markers() {
+        let text = SlackMrkdwnBuilder::new()
+            .bold("a*b")
+            .text(" ")
+            .italic("snake_case")
+            .text(" ")
+            .strike("~x~")
+            .text(" ")
+            .code("a`b")
+            .code_block("x```y")
+            .build();
+
+        assert_eq!(text, "*a∗b* _snake＿case_ ~∼x∼~ `aˋb`\n```\nxˋˋˋy\n```");
+    }
+
+    #[test]
+    fn test_mrkdwn_builder_keeps_whitespace_outside_markers() {
+        let text = SlackMrkdwnBuilder::new()
+            .text("Status:")
+            .bold(" done ")
+            .text("|")
+            .italic("  ")
+            .code(" x")
+            .build();
+
+        assert_eq!(text, "Status: *done* |   `x`");
+    }
+
+    #[test]
+    fn test_mrkdwn_builder_escapes_date_link() -> Result<(), Box<dyn std::error::Error>> {
+        let date = chrono::DateTime::parse_from_rfc3339("2024-01-02T03:04:05Z")?;
+        let text = SlackMrkdwnBuilder::new()
+            .date(
+                &date,
+                "{date_short}",
+                Some(&Url::parse("https://example.com/a?b=c|d")?),
+            )
+            .build();
+
+        assert_eq!(
+            text,
+            format!(
+                "<!date^{}^{{date_short}}^https://example.com/a?b=c%7Cd|{}>",
+                date.timestamp(),
+                date.to_rfc2822()
+            )
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use crate::*;
+
+use rsb_derive::Builder;
+use rvstruct::*;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct SlackFunctionId(pub String);
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct SlackFunctionExecutionId(pub String);
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct SlackWorkflowExecutionId(pub String);
+
+/// Parameter type for custom step inputs/outputs, e.g. `string`, `integer` or `slack#/types/user_id`
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct SlackFunctionParameterType(pub String);
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackFunctionParameter {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub parameter_type: SlackFunctionParameterType,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub is_required: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackFunctionInfo {
+    pub id: SlackFunctionId,
+    pub callback_id: SlackCallbackId,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    #[serde(rename = "type")]
+    pub function_type: Option<String>,
+    pub app_id: Option<SlackAppId>,
+    pub input_parameters: Option<Vec<SlackFunctionParameter>>,
+    pub output_parameters: Option<Vec<SlackFunctionParameter>>,
+    pub date_created: Option<SlackDateTime>,
+    pub date_updated: Option<SlackDateTime>,
+}
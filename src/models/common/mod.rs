@@ -49,6 +49,10 @@ mod emoji;
 pub use emoji::*;
 mod assistant;
 pub use assistant::*;
+mod call;
+pub use call::*;
+mod function;
+pub use function::*;
 
 #[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize, Deserialize, ValueStruct)]
 pub struct SlackTs(pub String);
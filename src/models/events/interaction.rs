@@ -179,3 +179,60 @@ pub struct SlackBlockSuggestionOptions {
 pub struct SlackBlockSuggestionOptionGroups {
     pub option_groups: Vec<SlackBlockOptionGroup<SlackBlockPlainTextOnly>>,
 }
+
+/// The response for the interaction events that Slack expects in the same request
+/// (or in the Socket Mode acknowledge)
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SlackInteractionEventResponsePayload {
+    ViewSubmission(SlackViewSubmissionResponse),
+    BlockSuggestion(SlackBlockSuggestionResponse),
+}
+
+impl From<SlackViewSubmissionResponse> for SlackInteractionEventResponsePayload {
+    fn from(response: SlackViewSubmissionResponse) -> Self {
+        Self::ViewSubmission(response)
+    }
+}
+
+impl From<SlackBlockSuggestionResponse> for SlackInteractionEventResponsePayload {
+    fn from(response: SlackBlockSuggestionResponse) -> Self {
+        Self::BlockSuggestion(response)
+    }
+}
+
+/// The results of the interaction event handlers
+pub trait SlackInteractionEventResult {
+    fn into_response_payload(self) -> Option<SlackInteractionEventResponsePayload>;
+}
+
+impl SlackInteractionEventResult for () {
+    fn into_response_payload(self) -> Option<SlackInteractionEventResponsePayload> {
+        None
+    }
+}
+
+impl SlackInteractionEventResult for SlackInteractionEventResponsePayload {
+    fn into_response_payload(self) -> Option<SlackInteractionEventResponsePayload> {
+        Some(self)
+    }
+}
+
+impl SlackInteractionEventResult for SlackViewSubmissionResponse {
+    fn into_response_payload(self) -> Option<SlackInteractionEventResponsePayload> {
+        Some(self.into())
+    }
+}
+
+impl SlackInteractionEventResult for SlackBlockSuggestionResponse {
+    fn into_response_payload(self) -> Option<SlackInteractionEventResponsePayload> {
+        Some(self.into())
+    }
+}
+
+impl<T: SlackInteractionEventResult> SlackInteractionEventResult for Option<T> {
+    fn into_response_payload(self) -> Option<SlackInteractionEventResponsePayload> {
+        self.and_then(|result| result.into_response_payload())
+    }
+}
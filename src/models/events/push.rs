@@ -1,6 +1,8 @@
 use rsb_derive::Builder;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
+use std::collections::HashMap;
 use url::Url;
 
 use crate::blocks::*;
@@ -45,6 +47,34 @@ pub struct SlackPushEventCallback {
     pub event_context: Option<SlackEventContext>,
     pub authed_users: Option<Vec<SlackUserId>>,
     pub authorizations: Option<Vec<SlackEventAuthorization>>,
+    /// Filled in by the listeners when Slack redelivers the event
+    #[serde(skip)]
+    pub retry: Option<SlackEventRetryInfo>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Builder)]
+pub struct SlackEventRetryInfo {
+    pub retry_num: u32,
+    pub retry_reason: Option<String>,
+}
+
+impl SlackEventRetryInfo {
+    pub const SLACK_RETRY_NUM_HEADER: &'static str = "x-slack-retry-num";
+    pub const SLACK_RETRY_REASON_HEADER: &'static str = "x-slack-retry-reason";
+
+    pub fn from_headers(headers: &http::HeaderMap) -> Option<Self> {
+        headers
+            .get(Self::SLACK_RETRY_NUM_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok())
+            .map(|retry_num| Self {
+                retry_num,
+                retry_reason: headers
+                    .get(Self::SLACK_RETRY_REASON_HEADER)
+                    .and_then(|value| value.to_str().ok())
+                    .map(|value| value.to_string()),
+            })
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
@@ -78,6 +108,8 @@ pub enum SlackEventCallbackBody {
     UserStatusChanged(SlackUserStatusChangedEvent),
     AssistantThreadStarted(SlackAssistantThreadStartedEvent),
     AssistantThreadContextChanged(SlackAssistantThreadContextChangedEvent),
+    CallRejected(SlackCallRejectedEvent),
+    FunctionExecuted(SlackFunctionExecutedEvent),
 }
 
 #[skip_serializing_none]
@@ -421,6 +453,39 @@ pub struct SlackAssistantThreadContextChangedEvent {
     pub assistant_thread: SlackAssistantThread,
 }
 
+///
+/// The only event of the Calls API. Slack doesn't send interaction payloads for call blocks,
+/// since their buttons open the `join_url` of the call directly.
+///
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackCallRejectedEvent {
+    pub call_id: SlackCallId,
+    pub user_id: SlackUserId,
+    pub channel_id: Option<SlackChannelId>,
+    pub external_unique_id: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackFunctionExecutedEvent {
+    pub function: SlackFunctionInfo,
+    #[serde(default)]
+    pub inputs: HashMap<String, serde_json::Value>,
+    pub function_execution_id: SlackFunctionExecutionId,
+    pub workflow_execution_id: Option<SlackWorkflowExecutionId>,
+    pub event_ts: SlackTs,
+    /// A short-lived token to call Slack API (including `functions.complete*`) for this execution
+    pub bot_access_token: Option<SlackApiTokenValue>,
+}
+
+impl SlackFunctionExecutedEvent {
+    /// Deserializes the step inputs into your own type
+    pub fn inputs_as<T: DeserializeOwned>(&self) -> serde_json::Result<T> {
+        serde_json::from_value(serde_json::to_value(&self.inputs)?)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -518,4 +583,63 @@ mod test {
             _ => panic!("Unexpected event type"),
         }
     }
+
+    #[test]
+    fn test_slack_event_function_executed_event() {
+        #[derive(Deserialize)]
+        struct SampleInputs {
+            user_id: SlackUserId,
+        }
+
+        let payload = include_str!("./fixtures/function_executed.json");
+        let event: SlackPushEventCallback = serde_json::from_str(payload).unwrap();
+        match event.event {
+            SlackEventCallbackBody::FunctionExecuted(function_executed) => {
+                assert_eq!(
+                    function_executed.function.callback_id,
+                    "sample_function".into()
+                );
+                assert_eq!(
+                    function_executed.function_execution_id,
+                    "Fx05RXD4PS9Y".into()
+                );
+                let inputs: SampleInputs = function_executed.inputs_as().unwrap();
+                assert_eq!(inputs.user_id, "U01F1234567".into());
+            }
+            _ => panic!("Unexpected event type"),
+        }
+    }
+
+    #[test]
+    fn test_slack_event_call_rejected() {
+        let payload = include_str!("./fixtures/call_rejected.json");
+        let event: SlackPushEventCallback = serde_json::from_str(payload).unwrap();
+        match event.event {
+            SlackEventCallbackBody::CallRejected(call_rejected) => {
+                assert_eq!(call_rejected.call_id, "R0123ABCDEF".into());
+                assert_eq!(call_rejected.user_id, "U0123ABCDEF".into());
+                assert_eq!(call_rejected.channel_id, Some("D0123ABCDEF".into()));
+            }
+            _ => panic!("Unexpected event type"),
+        }
+    }
+
+    #[test]
+    fn test_slack_event_retry_info_from_headers() {
+        let mut headers = http::HeaderMap::new();
+        assert_eq!(SlackEventRetryInfo::from_headers(&headers), None);
+
+        headers.insert(
+            SlackEventRetryInfo::SLACK_RETRY_NUM_HEADER,
+            "2".parse().unwrap(),
+        );
+        headers.insert(
+            SlackEventRetryInfo::SLACK_RETRY_REASON_HEADER,
+            "http_timeout".parse().unwrap(),
+        );
+        assert_eq!(
+            SlackEventRetryInfo::from_headers(&headers),
+            Some(SlackEventRetryInfo::new(2).with_retry_reason("http_timeout".into()))
+        );
+    }
 }
@@ -0,0 +1,150 @@
+use crate::blocks::SlackRichTextBlock;
+use crate::*;
+
+use rsb_derive::Builder;
+use rvstruct::*;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct SlackListId(pub String);
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct SlackListItemId(pub String);
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct SlackListColumnId(pub String);
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct SlackListSelectOptionId(pub String);
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlackListColumnType {
+    Text,
+    RichText,
+    User,
+    Date,
+    Select,
+    Checkbox,
+    Number,
+    Email,
+    Phone,
+    Channel,
+    Attachment,
+    Rating,
+    /// Catch-all for column types Slack may add in the future.
+    #[serde(untagged)]
+    Other(String),
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackListSelectChoice {
+    pub value: SlackListSelectOptionId,
+    pub label: String,
+    pub color: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackListColumnOptions {
+    pub choices: Option<Vec<SlackListSelectChoice>>,
+    pub format: Option<String>,
+    pub date_format: Option<String>,
+    pub show_member_name: Option<bool>,
+    pub notify_users: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackListColumn {
+    pub key: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub column_type: SlackListColumnType,
+    pub id: Option<SlackListColumnId>,
+    pub is_primary_column: Option<bool>,
+    pub options: Option<SlackListColumnOptions>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackListMetadata {
+    pub schema: Option<Vec<SlackListColumn>>,
+    pub subtask_schema: Option<Vec<SlackListColumn>>,
+}
+
+/// A typed cell value for Slack Lists.
+/// Slack sends and expects the value under the key named after the cell type,
+/// so this enum is meant to be flattened into the enclosing field object.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlackListCellValue {
+    Text(String),
+    RichText(Vec<SlackRichTextBlock>),
+    User(Vec<SlackUserId>),
+    Date(Vec<SlackDate>),
+    Select(Vec<SlackListSelectOptionId>),
+    Checkbox(bool),
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackListFieldValue {
+    pub column_id: SlackListColumnId,
+    #[serde(flatten)]
+    pub value: SlackListCellValue,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackListCellUpdate {
+    pub row_id: SlackListItemId,
+    pub column_id: SlackListColumnId,
+    #[serde(flatten)]
+    pub value: SlackListCellValue,
+}
+
+/// A field of a list item as Slack returns it.
+/// Slack may fill in several representations of the same value (e.g. `text` and `rich_text`),
+/// so all of them are kept here. Use `cell_value` to get a single typed value.
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackListItemField {
+    pub column_id: SlackListColumnId,
+    pub key: Option<String>,
+    pub value: Option<serde_json::Value>,
+    pub text: Option<String>,
+    pub rich_text: Option<Vec<SlackRichTextBlock>>,
+    pub user: Option<Vec<SlackUserId>>,
+    pub date: Option<Vec<SlackDate>>,
+    pub select: Option<Vec<SlackListSelectOptionId>>,
+    pub checkbox: Option<bool>,
+}
+
+impl SlackListItemField {
+    pub fn cell_value(&self) -> Option<SlackListCellValue> {
+        self.rich_text
+            .clone()
+            .map(SlackListCellValue::RichText)
+            .or_else(|| self.text.clone().map(SlackListCellValue::Text))
+            .or_else(|| self.user.clone().map(SlackListCellValue::User))
+            .or_else(|| self.date.clone().map(SlackListCellValue::Date))
+            .or_else(|| self.select.clone().map(SlackListCellValue::Select))
+            .or_else(|| self.checkbox.map(SlackListCellValue::Checkbox))
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackListItem {
+    pub id: SlackListItemId,
+    pub list_id: Option<SlackListId>,
+    pub parent_item_id: Option<SlackListItemId>,
+    pub date_created: Option<SlackDateTime>,
+    pub created_by: Option<SlackUserId>,
+    pub updated_by: Option<SlackUserId>,
+    pub updated_timestamp: Option<SlackTs>,
+    pub fields: Vec<SlackListItemField>,
+}
@@ -6,10 +6,14 @@ use rvstruct::ValueStruct;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
+mod render;
 mod templates;
+mod tokens;
 
 use crate::SlackFile;
+pub use render::*;
 pub use templates::*;
+pub use tokens::*;
 
 #[skip_serializing_none]
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
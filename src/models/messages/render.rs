@@ -0,0 +1,1437 @@
+use crate::blocks::*;
+use crate::*;
+use chrono::{DateTime, Utc};
+use rsb_derive::Builder;
+use std::collections::HashMap;
+
+///
+/// Resolves mentions, emoji and dates when rendering messages to text.
+/// All methods have defaults, so implement only what your app can resolve (e.g. from a users cache).
+///
+pub trait SlackMentionsLookup {
+    /// The display name of the user without `@`
+    fn user_name(&self, _user_id: &SlackUserId) -> Option<String> {
+        None
+    }
+
+    /// The channel name without `#`
+    fn channel_name(&self, _channel_id: &SlackChannelId) -> Option<String> {
+        None
+    }
+
+    /// The user group handle without `@`
+    fn usergroup_name(&self, _usergroup_id: &SlackUserGroupId) -> Option<String> {
+        None
+    }
+
+    /// The text for the emoji (e.g. the Unicode emoji or the custom emoji name)
+    fn emoji(&self, _name: &SlackEmojiName) -> Option<String> {
+        None
+    }
+
+    /// Formats the date with Slack date tokens (e.g. `{date_short} at {time}`). The default uses UTC.
+    fn format_date(&self, date: &DateTime<Utc>, format: &str) -> String {
+        [
+            ("{date_num}", "%Y-%m-%d"),
+            ("{date}", "%B %-d, %Y"),
+            ("{date_short}", "%b %-d, %Y"),
+            ("{date_long}", "%A, %B %-d, %Y"),
+            ("{date_pretty}", "%B %-d, %Y"),
+            ("{date_short_pretty}", "%b %-d, %Y"),
+            ("{date_long_pretty}", "%A, %B %-d, %Y"),
+            ("{time}", "%H:%M"),
+            ("{time_secs}", "%H:%M:%S"),
+            ("{ago}", "%Y-%m-%d %H:%M"),
+        ]
+        .iter()
+        .fold(format.to_string(), |result, (token, date_format)| {
+            result.replace(token, &date.format(date_format).to_string())
+        })
+    }
+}
+
+/// The lookup that doesn't resolve anything, so mentions are rendered with ids
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct SlackNoMentionsLookup;
+
+impl SlackMentionsLookup for SlackNoMentionsLookup {}
+
+/// The lookup from the prepared maps
+#[derive(Debug, PartialEq, Eq, Clone, Builder)]
+pub struct SlackMentionsMap {
+    #[default = "HashMap::new()"]
+    pub users: HashMap<SlackUserId, String>,
+    #[default = "HashMap::new()"]
+    pub channels: HashMap<SlackChannelId, String>,
+    #[default = "HashMap::new()"]
+    pub usergroups: HashMap<SlackUserGroupId, String>,
+    #[default = "HashMap::new()"]
+    pub emojis: HashMap<SlackEmojiName, String>,
+}
+
+impl SlackMentionsLookup for SlackMentionsMap {
+    fn user_name(&self, user_id: &SlackUserId) -> Option<String> {
+        self.users.get(user_id).cloned()
+    }
+
+    fn channel_name(&self, channel_id: &SlackChannelId) -> Option<String> {
+        self.channels.get(channel_id).cloned()
+    }
+
+    fn usergroup_name(&self, usergroup_id: &SlackUserGroupId) -> Option<String> {
+        self.usergroups.get(usergroup_id).cloned()
+    }
+
+    fn emoji(&self, name: &SlackEmojiName) -> Option<String> {
+        self.emojis.get(name).cloned()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SlackRenderFormat {
+    PlainText,
+    Markdown,
+    Html,
+}
+
+///
+/// Renders messages and blocks (e.g. from `conversations_history`) to plain text, Markdown or HTML.
+///
+/// Messages are rendered from their blocks, and from the `mrkdwn` text only when there are no blocks,
+/// since Slack duplicates the block content in the text as a fallback.
+///
+#[derive(Debug, Clone)]
+pub struct SlackMessageRenderer<L: SlackMentionsLookup> {
+    lookup: L,
+}
+
+impl SlackMessageRenderer<SlackNoMentionsLookup> {
+    pub fn without_lookup() -> Self {
+        Self::new(SlackNoMentionsLookup)
+    }
+}
+
+impl<L: SlackMentionsLookup> SlackMessageRenderer<L> {
+    pub fn new(lookup: L) -> Self {
+        Self { lookup }
+    }
+
+    pub fn to_plain_text(&self, content: &SlackMessageContent) -> String {
+        self.render_message(content, SlackRenderFormat::PlainText)
+    }
+
+    pub fn to_markdown(&self, content: &SlackMessageContent) -> String {
+        self.render_message(content, SlackRenderFormat::Markdown)
+    }
+
+    pub fn to_html(&self, content: &SlackMessageContent) -> String {
+        self.render_message(content, SlackRenderFormat::Html)
+    }
+
+    pub fn render_message(
+        &self,
+        content: &SlackMessageContent,
+        format: SlackRenderFormat,
+    ) -> String {
+        let mut nodes = self.content_nodes(content.blocks.as_deref(), content.text.as_deref());
+        for attachment in content.attachments.iter().flatten() {
+            nodes.extend(
+                self.content_nodes(
+                    attachment.blocks.as_deref(),
+                    attachment
+                        .text
+                        .as_deref()
+                        .or(attachment.fallback.as_deref()),
+                ),
+            );
+        }
+        render_nodes(&nodes, format)
+    }
+
+    pub fn render_blocks(&self, blocks: &[SlackBlock], format: SlackRenderFormat) -> String {
+        render_nodes(&self.blocks_nodes(blocks), format)
+    }
+
+    pub fn render_rich_text(
+        &self,
+        block: &SlackRichTextBlock,
+        format: SlackRenderFormat,
+    ) -> String {
+        render_nodes(&self.rich_text_nodes(&block.elements), format)
+    }
+
+    /// Renders Slack `mrkdwn` text, such as the `text` of messages
+    pub fn render_mrkdwn(&self, text: &str, format: SlackRenderFormat) -> String {
+        render_nodes(&self.mrkdwn_nodes(text), format)
+    }
+
+    fn content_nodes(&self, blocks: Option<&[SlackBlock]>, text: Option<&str>) -> Vec<RenderNode> {
+        let nodes = blocks
+            .map(|blocks| self.blocks_nodes(blocks))
+            .unwrap_or_default();
+        if nodes.is_empty() {
+            text.map(|text| self.mrkdwn_nodes(text)).unwrap_or_default()
+        } else {
+            nodes
+        }
+    }
+
+    fn blocks_nodes(&self, blocks: &[SlackBlock]) -> Vec<RenderNode> {
+        blocks
+            .iter()
+            .flat_map(|block| self.block_nodes(block))
+            .collect()
+    }
+
+    fn block_nodes(&self, block: &SlackBlock) -> Vec<RenderNode> {
+        match block {
+            SlackBlock::Section(section) => section
+                .text
+                .iter()
+                .chain(section.fields.iter().flatten())
+                .flat_map(|text| self.block_text_nodes(text))
+                .collect(),
+            SlackBlock::Header(header) => {
+                vec![RenderNode::Heading(
+                    self.block_text_spans(&header.text.clone().into()),
+                )]
+            }
+            SlackBlock::Divider(_) => vec![RenderNode::Rule],
+            SlackBlock::Image(image) => vec![RenderNode::Image {
+                url: image
+                    .image_url_or_file
+                    .image_url()
+                    .map(|url| url.to_string()),
+                alt: image.alt_text.clone(),
+            }],
+            SlackBlock::Context(context) => {
+                let mut spans = Vec::new();
+                for element in context
+                    .elements
+                    .iter()
+                    .filter(|element| !matches!(element, SlackContextBlockElement::Unknown(_)))
+                {
+                    if !spans.is_empty() {
+                        spans.push(RenderSpan::text(" "));
+                    }
+                    match element {
+                        SlackContextBlockElement::Image(image) => {
+                            spans.push(RenderSpan::text(&image.alt_text))
+                        }
+                        SlackContextBlockElement::Plain(text) => {
+                            spans.extend(self.block_text_spans(&text.as_block_text()))
+                        }
+                        SlackContextBlockElement::MarkDown(text) => {
+                            spans.extend(self.mrkdwn_spans(&text.text))
+                        }
+                        SlackContextBlockElement::Unknown(_) => {}
+                    }
+                }
+                vec![RenderNode::Paragraph(spans)]
+            }
+            SlackBlock::Markdown(markdown) => SlackMarkdownConverter::new()
+                .to_rich_text_blocks(&markdown.text)
+                .iter()
+                .flat_map(|block| self.rich_text_nodes(&block.elements))
+                .collect(),
+            SlackBlock::RichText(rich_text) => self.rich_text_nodes(&rich_text.elements),
+            SlackBlock::Video(video) => {
+                let title = plain_text_spans(&self.block_text_spans(&video.title.clone().into()));
+                let url = video.title_url.as_ref().unwrap_or(&video.video_url);
+                vec![RenderNode::Paragraph(vec![RenderSpan::link(
+                    url.as_str(),
+                    Some(&title),
+                )])]
+            }
+            SlackBlock::Table(table) => vec![RenderNode::Table(
+                table
+                    .rows
+                    .iter()
+                    .map(|row| {
+                        row.iter()
+                            .map(|cell| match cell {
+                                SlackTableCell::RawText(cell) => vec![RenderSpan::text(&cell.text)],
+                                SlackTableCell::RichText(cell) => {
+                                    nodes_spans(self.rich_text_nodes(&cell.elements))
+                                }
+                                SlackTableCell::Unknown(_) => vec![],
+                            })
+                            .collect()
+                    })
+                    .collect(),
+            )],
+            SlackBlock::TaskCard(task_card) => {
+                let mut nodes = vec![RenderNode::Paragraph(vec![RenderSpan::text(
+                    &task_card.title,
+                )
+                .bold()])];
+                for content in task_card.details.iter().chain(task_card.output.iter()) {
+                    match content {
+                        SlackRichTextInlineContent::RichText(rich_text) => {
+                            nodes.extend(self.rich_text_nodes(&rich_text.elements))
+                        }
+                        SlackRichTextInlineContent::Unknown(_) => {}
+                    }
+                }
+                nodes
+            }
+            SlackBlock::Alert(alert) => self.block_text_nodes(&alert.text),
+            SlackBlock::Card(card) => {
+                let mut nodes = Vec::new();
+                if let Some(title) = &card.title {
+                    nodes.push(RenderNode::Heading(self.block_text_spans(title)));
+                }
+                for text in card.subtitle.iter().chain(card.body.iter()) {
+                    nodes.extend(self.block_text_nodes(text));
+                }
+                nodes
+            }
+            SlackBlock::Carousel(carousel) => self.blocks_nodes(&carousel.elements),
+            SlackBlock::Actions(_)
+            | SlackBlock::Input(_)
+            | SlackBlock::File(_)
+            | SlackBlock::ContextActions(_)
+            | SlackBlock::Call(_)
+            | SlackBlock::ShareShortcut(_)
+            | SlackBlock::Event(_)
+            | SlackBlock::Unknown(_) => vec![],
+        }
+    }
+
+    fn block_text_nodes(&self, text: &SlackBlockText) -> Vec<RenderNode> {
+        match text {
+            SlackBlockText::Plain(plain) => {
+                vec![RenderNode::Paragraph(vec![RenderSpan::text(&plain.text)])]
+            }
+            SlackBlockText::MarkDown(markdown) => self.mrkdwn_nodes(&markdown.text),
+        }
+    }
+
+    fn block_text_spans(&self, text: &SlackBlockText) -> Vec<RenderSpan> {
+        match text {
+            SlackBlockText::Plain(plain) => vec![RenderSpan::text(&plain.text)],
+            SlackBlockText::MarkDown(markdown) => self.mrkdwn_spans(&markdown.text),
+        }
+    }
+
+    fn rich_text_nodes(&self, elements: &[SlackRichTextElement]) -> Vec<RenderNode> {
+        elements
+            .iter()
+            .filter_map(|element| match element {
+                SlackRichTextElement::Section(section) => {
+                    let spans = self.rich_text_spans(&section.elements);
+                    Some(RenderNode::Paragraph(trim_spans(spans))).filter(|node| !node.is_empty())
+                }
+                SlackRichTextElement::List(list) => Some(RenderNode::List {
+                    ordered: list.style == SlackRichTextListStyle::Ordered,
+                    start: list.offset.unwrap_or(0) + 1,
+                    indent: list.indent.unwrap_or(0),
+                    items: list
+                        .elements
+                        .iter()
+                        .filter_map(|item| match item {
+                            SlackRichTextListElement::Section(section) => {
+                                Some(trim_spans(self.rich_text_spans(&section.elements)))
+                            }
+                            SlackRichTextListElement::Unknown(_) => None,
+                        })
+                        .collect(),
+                }),
+                SlackRichTextElement::Preformatted(preformatted) => Some(RenderNode::Code(
+                    self.rich_text_spans(&preformatted.elements)
+                        .into_iter()
+                        .map(|span| span.text)
+                        .collect(),
+                )),
+                SlackRichTextElement::Quote(quote) => Some(RenderNode::Quote(trim_spans(
+                    self.rich_text_spans(&quote.elements),
+                ))),
+                SlackRichTextElement::Unknown(_) => None,
+            })
+            .collect()
+    }
+
+    fn rich_text_spans(&self, elements: &[SlackRichTextInlineElement]) -> Vec<RenderSpan> {
+        elements
+            .iter()
+            .filter_map(|element| match element {
+                SlackRichTextInlineElement::Text(text) => {
+                    Some(RenderSpan::text(&text.text).with_style(text.style.as_ref()))
+                }
+                SlackRichTextInlineElement::Link(link) => Some(
+                    RenderSpan::link(&link.url.0, link.text.as_deref())
+                        .with_style(link.style.as_ref()),
+                ),
+                SlackRichTextInlineElement::User(user) => Some(
+                    RenderSpan::text(&self.user_mention(&user.user_id, None))
+                        .with_style(user.style.as_ref()),
+                ),
+                SlackRichTextInlineElement::Channel(channel) => Some(
+                    RenderSpan::text(&self.channel_mention(&channel.channel_id, None))
+                        .with_style(channel.style.as_ref()),
+                ),
+                SlackRichTextInlineElement::UserGroup(usergroup) => Some(
+                    RenderSpan::text(&self.usergroup_mention(&usergroup.usergroup_id, None))
+                        .with_style(usergroup.style.as_ref()),
+                ),
+                SlackRichTextInlineElement::Emoji(emoji) => Some(RenderSpan::text(
+                    &self.emoji_text(&emoji.name, emoji.unicode.as_deref()),
+                )),
+                SlackRichTextInlineElement::Date(date) => Some(
+                    RenderSpan::text(&self.lookup.format_date(&date.timestamp.0, &date.format))
+                        .with_style(date.style.as_ref()),
+                ),
+                SlackRichTextInlineElement::Broadcast(broadcast) => Some(
+                    RenderSpan::text(broadcast_text(&broadcast.range))
+                        .with_style(broadcast.style.as_ref()),
+                ),
+                SlackRichTextInlineElement::Color(color) => Some(RenderSpan::text(&color.value)),
+                SlackRichTextInlineElement::MessageMention(mention) => Some(
+                    RenderSpan::link(&mention.url.0, mention.text.as_deref())
+                        .with_style(mention.style.as_ref()),
+                ),
+                SlackRichTextInlineElement::Unknown(_) => None,
+            })
+            .collect()
+    }
+
+    fn user_mention(&self, user_id: &SlackUserId, label: Option<&str>) -> String {
+        let name = self.lookup.user_name(user_id);
+        format!("@{}", name.as_deref().or(label).unwrap_or(&user_id.0))
+    }
+
+    fn channel_mention(&self, channel_id: &SlackChannelId, label: Option<&str>) -> String {
+        let name = self.lookup.channel_name(channel_id);
+        format!("#{}", name.as_deref().or(label).unwrap_or(&channel_id.0))
+    }
+
+    fn usergroup_mention(&self, usergroup_id: &SlackUserGroupId, label: Option<&str>) -> String {
+        let name = self.lookup.usergroup_name(usergroup_id);
+        let name = name.as_deref().or(label).unwrap_or(&usergroup_id.0);
+        format!("@{}", name.trim_start_matches('@'))
+    }
+
+    fn emoji_text(&self, name: &SlackEmojiName, unicode: Option<&str>) -> String {
+        self.lookup
+            .emoji(name)
+            .or_else(|| {
+                unicode.and_then(|unicode| {
+                    unicode
+                        .split('-')
+                        .map(|code| u32::from_str_radix(code, 16).ok().and_then(char::from_u32))
+                        .collect::<Option<String>>()
+                })
+            })
+            .unwrap_or_else(|| format!(":{}:", name))
+    }
+
+    fn mrkdwn_nodes(&self, text: &str) -> Vec<RenderNode> {
+        let mut nodes = Vec::new();
+        let mut paragraph: Vec<&str> = Vec::new();
+        let mut lines = text.lines();
+
+        let flush = |paragraph: &mut Vec<&str>, nodes: &mut Vec<RenderNode>| {
+            if !paragraph.is_empty() {
+                nodes.push(RenderNode::Paragraph(
+                    self.mrkdwn_spans(&paragraph.join("\n")),
+                ));
+                paragraph.clear();
+            }
+        };
+
+        while let Some(line) = lines.next() {
+            if let Some(code) = line.trim_start().strip_prefix("```") {
+                flush(&mut paragraph, &mut nodes);
+                let mut code_lines = Vec::new();
+                let mut code_line = Some(code);
+                while let Some(line) = code_line {
+                    if let Some(end) = line.find("```") {
+                        code_lines.push(&line[..end]);
+                        break;
+                    }
+                    code_lines.push(line);
+                    code_line = lines.next();
+                }
+                let code = code_lines.join("\n");
+                nodes.push(RenderNode::Code(decode_entities(code.trim_matches('\n'))));
+            } else if let Some(quoted) =
+                line.strip_prefix("&gt;").or_else(|| line.strip_prefix('>'))
+            {
+                flush(&mut paragraph, &mut nodes);
+                let quoted = quoted.strip_prefix(' ').unwrap_or(quoted);
+                match nodes.last_mut() {
+                    Some(RenderNode::Quote(spans)) => {
+                        spans.push(RenderSpan::text("\n"));
+                        spans.extend(self.mrkdwn_spans(quoted));
+                    }
+                    _ => nodes.push(RenderNode::Quote(self.mrkdwn_spans(quoted))),
+                }
+            } else if line.trim().is_empty() {
+                flush(&mut paragraph, &mut nodes);
+                // Separates the following quote lines from the previous quote
+                nodes.push(RenderNode::Paragraph(vec![]));
+            } else {
+                paragraph.push(line);
+            }
+        }
+        flush(&mut paragraph, &mut nodes);
+
+        nodes.retain(|node| !node.is_empty());
+        nodes
+    }
+
+    fn mrkdwn_spans(&self, text: &str) -> Vec<RenderSpan> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut spans = Vec::new();
+        self.parse_mrkdwn(&chars, RenderStyle::default(), &mut spans);
+        spans
+    }
+
+    fn token_span(&self, token: SlackMessageToken) -> RenderSpan {
+        match token {
+            SlackMessageToken::Text(text) => RenderSpan::text(&text),
+            SlackMessageToken::UserMention { user_id, label } => {
+                RenderSpan::text(&self.user_mention(&user_id, label.as_deref()))
+            }
+            SlackMessageToken::ChannelMention { channel_id, label } => {
+                RenderSpan::text(&self.channel_mention(&channel_id, label.as_deref()))
+            }
+            SlackMessageToken::UserGroupMention {
+                usergroup_id,
+                label,
+            } => RenderSpan::text(&self.usergroup_mention(&usergroup_id, label.as_deref())),
+            SlackMessageToken::SpecialMention(range) => RenderSpan::text(broadcast_text(&range)),
+            SlackMessageToken::Link { url, label } => RenderSpan::link(&url.0, label.as_deref()),
+            SlackMessageToken::Emoji(name) => RenderSpan::text(&self.emoji_text(&name, None)),
+            SlackMessageToken::Date {
+                timestamp, format, ..
+            } => RenderSpan::text(&self.lookup.format_date(&timestamp.0, &format)),
+        }
+    }
+
+    fn parse_mrkdwn(&self, chars: &[char], style: RenderStyle, spans: &mut Vec<RenderSpan>) {
+        let mut text = String::new();
+        let flush = |text: &mut String, spans: &mut Vec<RenderSpan>| {
+            if !text.is_empty() {
+                spans.push(RenderSpan {
+                    text: decode_entities(&std::mem::take(text)),
+                    style,
+                    link: None,
+                });
+            }
+        };
+        let is_boundary = |index: Option<&char>| {
+            index.is_none_or(|c| c.is_whitespace() || (c.is_ascii_punctuation() && *c != '`'))
+        };
+
+        let mut index = 0;
+        while index < chars.len() {
+            let c = chars[index];
+            let close = |from: usize, delimiter: char| {
+                (from..chars.len())
+                    .find(|close| chars[*close] == delimiter || chars[*close] == '\n')
+                    .filter(|close| chars[*close] == delimiter)
+            };
+
+            match c {
+                '<' => match close(index + 1, '>') {
+                    Some(end) => {
+                        flush(&mut text, spans);
+                        let reference: String = chars[index + 1..end].iter().collect();
+                        spans.push(
+                            self.token_span(SlackMessageToken::parse_reference(&reference))
+                                .with_render_style(style),
+                        );
+                        index = end + 1;
+                    }
+                    None => {
+                        text.push(c);
+                        index += 1;
+                    }
+                },
+                ':' if index == 0 || !chars[index - 1].is_alphanumeric() => {
+                    let rest: String = chars[index..].iter().collect();
+                    match SlackMessageToken::emoji_name(&rest) {
+                        Some(name) => {
+                            flush(&mut text, spans);
+                            spans.push(RenderSpan {
+                                text: self.emoji_text(&name.into(), None),
+                                style,
+                                link: None,
+                            });
+                            index += name.chars().count() + 2;
+                        }
+                        None => {
+                            text.push(c);
+                            index += 1;
+                        }
+                    }
+                }
+                '`' | '*' | '_' | '~'
+                    if (index == 0 || is_boundary(chars.get(index - 1)))
+                        && chars
+                            .get(index + 1)
+                            .is_some_and(|next| !next.is_whitespace() && *next != c) =>
+                {
+                    let end = (index + 2..chars.len())
+                        .take_while(|end| chars[*end] != '\n')
+                        .find(|end| {
+                            chars[*end] == c
+                                && !chars[*end - 1].is_whitespace()
+                                && is_boundary(chars.get(*end + 1))
+                        });
+                    match end {
+                        Some(end) => {
+                            flush(&mut text, spans);
+                            let inner = &chars[index + 1..end];
+                            match c {
+                                '`' => spans.push(RenderSpan {
+                                    text: decode_entities(&inner.iter().collect::<String>()),
+                                    style: RenderStyle {
+                                        code: true,
+                                        ..style
+                                    },
+                                    link: None,
+                                }),
+                                '*' => self.parse_mrkdwn(
+                                    inner,
+                                    RenderStyle {
+                                        bold: true,
+                                        ..style
+                                    },
+                                    spans,
+                                ),
+                                '_' => self.parse_mrkdwn(
+                                    inner,
+                                    RenderStyle {
+                                        italic: true,
+                                        ..style
+                                    },
+                                    spans,
+                                ),
+                                _ => self.parse_mrkdwn(
+                                    inner,
+                                    RenderStyle {
+                                        strike: true,
+                                        ..style
+                                    },
+                                    spans,
+                                ),
+                            }
+                            index = end + 1;
+                        }
+                        None => {
+                            text.push(c);
+                            index += 1;
+                        }
+                    }
+                }
+                _ => {
+                    text.push(c);
+                    index += 1;
+                }
+            }
+        }
+        flush(&mut text, spans);
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+struct RenderStyle {
+    bold: bool,
+    italic: bool,
+    strike: bool,
+    code: bool,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct RenderSpan {
+    text: String,
+    style: RenderStyle,
+    link: Option<String>,
+}
+
+impl RenderSpan {
+    fn text(text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            style: RenderStyle::default(),
+            link: None,
+        }
+    }
+
+    fn link(url: &str, text: Option<&str>) -> Self {
+        Self {
+            text: text
+                .filter(|text| !text.is_empty())
+                .unwrap_or(url)
+                .to_string(),
+            style: RenderStyle::default(),
+            link: Some(url.to_string()),
+        }
+    }
+
+    fn bold(self) -> Self {
+        Self {
+            style: RenderStyle {
+                bold: true,
+                ..self.style
+            },
+            ..self
+        }
+    }
+
+    fn with_style(self, style: Option<&SlackRichTextStyle>) -> Self {
+        match style {
+            Some(style) => self.with_render_style(RenderStyle {
+                bold: style.bold.unwrap_or(false),
+                italic: style.italic.unwrap_or(false),
+                strike: style.strike.unwrap_or(false),
+                code: style.code.unwrap_or(false),
+            }),
+            None => self,
+        }
+    }
+
+    fn with_render_style(self, style: RenderStyle) -> Self {
+        Self {
+            style: RenderStyle {
+                bold: self.style.bold || style.bold,
+                italic: self.style.italic || style.italic,
+                strike: self.style.strike || style.strike,
+                code: self.style.code || style.code,
+            },
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum RenderNode {
+    Paragraph(Vec<RenderSpan>),
+    Heading(Vec<RenderSpan>),
+    Code(String),
+    Quote(Vec<RenderSpan>),
+    List {
+        ordered: bool,
+        start: u64,
+        indent: u64,
+        items: Vec<Vec<RenderSpan>>,
+    },
+    Table(Vec<Vec<Vec<RenderSpan>>>),
+    Image {
+        url: Option<String>,
+        alt: String,
+    },
+    Rule,
+}
+
+impl RenderNode {
+    fn is_empty(&self) -> bool {
+        match self {
+            RenderNode::Paragraph(spans)
+            | RenderNode::Heading(spans)
+            | RenderNode::Quote(spans) => spans.iter().all(|span| span.text.trim().is_empty()),
+            RenderNode::List { items, .. } => items.is_empty(),
+            RenderNode::Table(rows) => rows.is_empty(),
+            _ => false,
+        }
+    }
+}
+
+/// Rich text sections end with new lines that separate them in Slack
+fn trim_spans(mut spans: Vec<RenderSpan>) -> Vec<RenderSpan> {
+    while let Some(last) = spans.last_mut() {
+        let trimmed = last.text.trim_end_matches('\n');
+        if trimmed.is_empty() && last.link.is_none() {
+            spans.pop();
+        } else {
+            last.text = trimmed.to_string();
+            break;
+        }
+    }
+    spans
+}
+
+fn nodes_spans(nodes: Vec<RenderNode>) -> Vec<RenderSpan> {
+    let mut spans = Vec::new();
+    for node in nodes {
+        if !spans.is_empty() {
+            spans.push(RenderSpan::text(" "));
+        }
+        match node {
+            RenderNode::Paragraph(node_spans)
+            | RenderNode::Heading(node_spans)
+            | RenderNode::Quote(node_spans) => spans.extend(node_spans),
+            RenderNode::List { items, .. } => spans.extend(items.into_iter().flatten()),
+            RenderNode::Code(code) => spans.push(RenderSpan {
+                text: code,
+                style: RenderStyle {
+                    code: true,
+                    ..RenderStyle::default()
+                },
+                link: None,
+            }),
+            RenderNode::Image { alt, .. } => spans.push(RenderSpan::text(&alt)),
+            RenderNode::Table(_) | RenderNode::Rule => {}
+        }
+    }
+    spans
+}
+
+fn broadcast_text(range: &SlackRichTextBroadcastRange) -> &'static str {
+    match range {
+        SlackRichTextBroadcastRange::Here => "@here",
+        SlackRichTextBroadcastRange::Channel => "@channel",
+        SlackRichTextBroadcastRange::Everyone => "@everyone",
+    }
+}
+
+fn render_nodes(nodes: &[RenderNode], format: SlackRenderFormat) -> String {
+    let mut result = String::new();
+    let mut index = 0;
+    while index < nodes.len() {
+        // Lists with different indents are rendered together as nested lists
+        let lists = nodes[index..]
+            .iter()
+            .take_while(|node| matches!(node, RenderNode::List { .. }))
+            .count()
+            .max(1);
+        let rendered = match format {
+            SlackRenderFormat::PlainText => nodes[index..index + lists]
+                .iter()
+                .map(render_plain_text)
+                .collect::<Vec<String>>()
+                .join("\n"),
+            SlackRenderFormat::Markdown => nodes[index..index + lists]
+                .iter()
+                .map(render_markdown)
+                .collect::<Vec<String>>()
+                .join("\n"),
+            SlackRenderFormat::Html if lists > 1 => render_html_lists(&nodes[index..index + lists]),
+            SlackRenderFormat::Html => render_html(&nodes[index]),
+        };
+
+        if !result.is_empty() {
+            result.push_str(if format == SlackRenderFormat::Html {
+                "\n"
+            } else {
+                "\n\n"
+            });
+        }
+        result.push_str(&rendered);
+        index += lists;
+    }
+    result
+}
+
+fn plain_text_spans(spans: &[RenderSpan]) -> String {
+    spans
+        .iter()
+        .map(|span| match &span.link {
+            Some(link) if *link != span.text => format!("{} ({})", span.text, link),
+            _ => span.text.clone(),
+        })
+        .collect()
+}
+
+fn list_markers(ordered: bool, start: u64, items: usize) -> impl Iterator<Item = String> {
+    (0..items as u64).map(move |index| {
+        if ordered {
+            format!("{}.", start + index)
+        } else {
+            "-".to_string()
+        }
+    })
+}
+
+fn render_plain_text(node: &RenderNode) -> String {
+    match node {
+        RenderNode::Paragraph(spans) | RenderNode::Heading(spans) => plain_text_spans(spans),
+        RenderNode::Code(code) => code.clone(),
+        RenderNode::Quote(spans) => prefix_lines(&plain_text_spans(spans), "> "),
+        RenderNode::List {
+            ordered,
+            start,
+            indent,
+            items,
+        } => list_markers(*ordered, *start, items.len())
+            .zip(items)
+            .map(|(marker, item)| {
+                format!(
+                    "{}{} {}",
+                    "    ".repeat(*indent as usize),
+                    marker,
+                    plain_text_spans(item)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+        RenderNode::Table(rows) => rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|cell| plain_text_spans(cell))
+                    .collect::<Vec<String>>()
+                    .join(" | ")
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+        RenderNode::Image { alt, .. } => alt.clone(),
+        RenderNode::Rule => "---".to_string(),
+    }
+}
+
+fn prefix_lines(text: &str, prefix: &str) -> String {
+    text.lines()
+        .map(|line| format!("{}{}", prefix, line).trim_end().to_string())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn escape_markdown(text: &str) -> String {
+    text.chars()
+        .fold(String::with_capacity(text.len()), |mut result, c| {
+            if matches!(c, '\\' | '*' | '_' | '~' | '`' | '[' | ']' | '<') {
+                result.push('\\');
+            }
+            result.push(c);
+            result
+        })
+}
+
+fn markdown_code(code: &str) -> String {
+    let longest_run = code
+        .split(|c| c != '`')
+        .map(|run| run.len())
+        .max()
+        .unwrap_or(0);
+    let fence = "`".repeat(longest_run + 1);
+    let padding = if code.starts_with('`') || code.ends_with('`') {
+        " "
+    } else {
+        ""
+    };
+    format!("{fence}{padding}{code}{padding}{fence}")
+}
+
+fn markdown_spans(spans: &[RenderSpan]) -> String {
+    merge_spans(spans)
+        .iter()
+        .map(|span| {
+            let mut result = if span.style.code {
+                markdown_code(&span.text)
+            } else {
+                escape_markdown(&span.text)
+            };
+            if let Some(link) = &span.link {
+                result = format!(
+                    "[{}]({})",
+                    result,
+                    link.replace(' ', "%20")
+                        .replace('(', "%28")
+                        .replace(')', "%29")
+                );
+            }
+
+            let markers = [
+                (span.style.strike, "~~"),
+                (span.style.italic, "*"),
+                (span.style.bold, "**"),
+            ];
+            let (leading, content, trailing) = split_whitespace_edges(&result);
+            if content.is_empty() {
+                return result;
+            }
+            let mut content = content.to_string();
+            for (enabled, marker) in markers {
+                if enabled {
+                    content = format!("{marker}{content}{marker}");
+                }
+            }
+            format!("{leading}{content}{trailing}")
+        })
+        .collect()
+}
+
+fn split_whitespace_edges(text: &str) -> (&str, &str, &str) {
+    let content = text.trim();
+    let start = text.len() - text.trim_start().len();
+    (&text[..start], content, &text[start + content.len()..])
+}
+
+fn merge_spans(spans: &[RenderSpan]) -> Vec<RenderSpan> {
+    let mut merged: Vec<RenderSpan> = Vec::new();
+    for span in spans.iter().filter(|span| !span.text.is_empty()) {
+        match merged.last_mut() {
+            Some(last)
+                if last.style == span.style && last.link.is_none() && span.link.is_none() =>
+            {
+                last.text.push_str(&span.text)
+            }
+            _ => merged.push(span.clone()),
+        }
+    }
+    merged
+}
+
+fn render_markdown(node: &RenderNode) -> String {
+    match node {
+        RenderNode::Paragraph(spans) => markdown_spans(spans),
+        RenderNode::Heading(spans) => format!("## {}", markdown_spans(spans).replace('\n', " ")),
+        RenderNode::Code(code) => {
+            let fence = if code.contains("```") { "~~~" } else { "```" };
+            format!("{fence}\n{code}\n{fence}")
+        }
+        RenderNode::Quote(spans) => prefix_lines(&markdown_spans(spans), "> "),
+        RenderNode::List {
+            ordered,
+            start,
+            indent,
+            items,
+        } => list_markers(*ordered, *start, items.len())
+            .zip(items)
+            .map(|(marker, item)| {
+                let indent = "    ".repeat(*indent as usize);
+                let content = markdown_spans(item);
+                let continuation = format!("\n{}    ", indent);
+                format!(
+                    "{}{} {}",
+                    indent,
+                    marker,
+                    content.replace('\n', &continuation)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+        RenderNode::Table(rows) => {
+            let columns = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+            let render_row = |cells: Vec<String>| format!("| {} |", cells.join(" | "));
+            let mut lines: Vec<String> = rows
+                .iter()
+                .map(|row| {
+                    render_row(
+                        (0..columns)
+                            .map(|column| {
+                                row.get(column)
+                                    .map(|cell| {
+                                        markdown_spans(cell).replace('\n', " ").replace('|', "\\|")
+                                    })
+                                    .unwrap_or_default()
+                            })
+                            .collect(),
+                    )
+                })
+                .collect();
+            if !lines.is_empty() {
+                lines.insert(1, render_row(vec!["---".to_string(); columns]));
+            }
+            lines.join("\n")
+        }
+        RenderNode::Image {
+            url: Some(url),
+            alt,
+        } => {
+            format!("![{}]({})", escape_markdown(alt), url.replace(' ', "%20"))
+        }
+        RenderNode::Image { url: None, alt } => escape_markdown(alt),
+        RenderNode::Rule => "---".to_string(),
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// The URL schemes allowed in HTML links and images, others are rendered as plain text
+const HTML_URL_SCHEMES: [&str; 4] = ["http", "https", "mailto", "slack"];
+
+fn is_html_safe_url(url: &str) -> bool {
+    url.split_once(':').is_some_and(|(scheme, _)| {
+        HTML_URL_SCHEMES
+            .iter()
+            .any(|allowed| scheme.eq_ignore_ascii_case(allowed))
+    })
+}
+
+fn html_spans(spans: &[RenderSpan]) -> String {
+    merge_spans(spans)
+        .iter()
+        .map(|span| {
+            let mut result = escape_html(&span.text).replace('\n', "<br>");
+            if span.style.code {
+                result = format!("<code>{}</code>", result);
+            }
+            if let Some(link) = span.link.as_ref().filter(|link| is_html_safe_url(link)) {
+                result = format!("<a href=\"{}\">{}</a>", escape_html(link), result);
+            }
+
+            let tags = [
+                (span.style.strike, "del"),
+                (span.style.italic, "em"),
+                (span.style.bold, "strong"),
+            ];
+            let (leading, content, trailing) = split_whitespace_edges(&result);
+            if content.is_empty() {
+                return result;
+            }
+            let mut content = content.to_string();
+            for (enabled, tag) in tags {
+                if enabled {
+                    content = format!("<{tag}>{content}</{tag}>");
+                }
+            }
+            format!("{leading}{content}{trailing}")
+        })
+        .collect()
+}
+
+fn render_html(node: &RenderNode) -> String {
+    match node {
+        RenderNode::Paragraph(spans) => format!("<p>{}</p>", html_spans(spans)),
+        RenderNode::Heading(spans) => format!("<h2>{}</h2>", html_spans(spans)),
+        RenderNode::Code(code) => format!("<pre><code>{}</code></pre>", escape_html(code)),
+        RenderNode::Quote(spans) => format!("<blockquote>{}</blockquote>", html_spans(spans)),
+        RenderNode::List { .. } => render_html_lists(std::slice::from_ref(node)),
+        RenderNode::Table(rows) => format!(
+            "<table>{}</table>",
+            rows.iter()
+                .map(|row| format!(
+                    "<tr>{}</tr>",
+                    row.iter()
+                        .map(|cell| format!("<td>{}</td>", html_spans(cell)))
+                        .collect::<String>()
+                ))
+                .collect::<String>()
+        ),
+        RenderNode::Image {
+            url: Some(url),
+            alt,
+        } if is_html_safe_url(url) => format!(
+            "<img src=\"{}\" alt=\"{}\">",
+            escape_html(url),
+            escape_html(alt)
+        ),
+        RenderNode::Image { alt, .. } => format!("<p>{}</p>", escape_html(alt)),
+        RenderNode::Rule => "<hr>".to_string(),
+    }
+}
+
+/// Renders the consecutive lists, nesting them by their indents
+fn render_html_lists(lists: &[RenderNode]) -> String {
+    struct OpenList {
+        tag: &'static str,
+        item_open: bool,
+    }
+
+    fn close(html: &mut String, open: OpenList) {
+        if open.item_open {
+            html.push_str("</li>");
+        }
+        html.push_str(&format!("</{}>", open.tag));
+    }
+
+    let mut html = String::new();
+    let mut stack: Vec<OpenList> = Vec::new();
+    for list in lists {
+        let RenderNode::List {
+            ordered,
+            start,
+            indent,
+            items,
+        } = list
+        else {
+            continue;
+        };
+        let tag = if *ordered { "ol" } else { "ul" };
+        let depth = *indent as usize + 1;
+
+        while stack.len() > depth || (stack.len() == depth && stack[depth - 1].tag != tag) {
+            if let Some(open) = stack.pop() {
+                close(&mut html, open);
+            }
+        }
+        while stack.len() < depth {
+            if let Some(parent) = stack.last_mut().filter(|parent| !parent.item_open) {
+                html.push_str("<li>");
+                parent.item_open = true;
+            }
+            let nested_tag = if stack.len() + 1 == depth { tag } else { "ul" };
+            match (nested_tag, *start) {
+                ("ol", start) if start > 1 => html.push_str(&format!("<ol start=\"{}\">", start)),
+                _ => html.push_str(&format!("<{}>", nested_tag)),
+            }
+            stack.push(OpenList {
+                tag: nested_tag,
+                item_open: false,
+            });
+        }
+
+        if let Some(current) = stack.last_mut() {
+            for item in items {
+                if current.item_open {
+                    html.push_str("</li>");
+                }
+                html.push_str("<li>");
+                html.push_str(&html_spans(item));
+                current.item_open = true;
+            }
+        }
+    }
+    while let Some(open) = stack.pop() {
+        close(&mut html, open);
+    }
+    html
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn renderer() -> SlackMessageRenderer<SlackMentionsMap> {
+        SlackMessageRenderer::new(
+            SlackMentionsMap::new()
+                .with_users([("U1".into(), "alice".to_string())].into())
+                .with_channels([("C1".into(), "general".to_string())].into()),
+        )
+    }
+
+    #[test]
+    fn test_render_mrkdwn_text() {
+        let content = SlackMessageContent::new().with_text(
+            "Hi <@U1>, *deploy* of <https://example.com|v1.2> to <#C1> is ~done~ :tada: &amp; `ok`\n&gt; quoted <@U2>"
+                .into(),
+        );
+
+        assert_eq!(
+            renderer().to_plain_text(&content),
+            "Hi @alice, deploy of v1.2 (https://example.com) to #general is done :tada: & ok\n\n> quoted @U2"
+        );
+        assert_eq!(
+            renderer().to_markdown(&content),
+            "Hi @alice, **deploy** of [v1.2](https://example.com) to #general is ~~done~~ :tada: & `ok`\n\n> quoted @U2"
+        );
+        assert_eq!(
+            renderer().to_html(&content),
+            "<p>Hi @alice, <strong>deploy</strong> of <a href=\"https://example.com\">v1.2</a> to #general is <del>done</del> :tada: &amp; <code>ok</code></p>\n<blockquote>quoted @U2</blockquote>"
+        );
+    }
+
+    #[test]
+    fn test_render_html_unsafe_links_as_text() {
+        let content = SlackMessageContent::new().with_text(
+            "<javascript:alert(1)|x>, <JavaScript:alert(2)> and <mailto:team@example.com|mail>"
+                .into(),
+        );
+
+        assert_eq!(
+            renderer().to_html(&content),
+            "<p>x, JavaScript:alert(2) and <a href=\"mailto:team@example.com\">mail</a></p>"
+        );
+    }
+
+    #[test]
+    fn test_render_rich_text_blocks() -> Result<(), Box<dyn std::error::Error>> {
+        let content: SlackMessageContent = serde_json::from_value(serde_json::json!({
+            "text": "fallback",
+            "blocks": [{
+                "type": "rich_text",
+                "elements": [
+                    {
+                        "type": "rich_text_section",
+                        "elements": [
+                            { "type": "user", "user_id": "U1" },
+                            { "type": "text", "text": " shipped ", "style": { "bold": true } },
+                            { "type": "emoji", "name": "rocket", "unicode": "1f680" },
+                            { "type": "text", "text": "\n" }
+                        ]
+                    },
+                    {
+                        "type": "rich_text_list",
+                        "style": "bullet",
+                        "elements": [
+                            { "type": "rich_text_section", "elements": [{ "type": "text", "text": "one" }] }
+                        ]
+                    },
+                    {
+                        "type": "rich_text_list",
+                        "style": "ordered",
+                        "indent": 1,
+                        "elements": [
+                            { "type": "rich_text_section", "elements": [{ "type": "text", "text": "nested" }] }
+                        ]
+                    },
+                    {
+                        "type": "rich_text_preformatted",
+                        "elements": [{ "type": "text", "text": "cargo test" }]
+                    }
+                ]
+            }]
+        }))?;
+
+        assert_eq!(
+            renderer().to_markdown(&content),
+            "@alice **shipped** 🚀\n\n- one\n    1. nested\n\n```\ncargo test\n```"
+        );
+        assert_eq!(
+            renderer().to_html(&content),
+            "<p>@alice <strong>shipped</strong> 🚀</p>\n<ul><li>one<ol><li>nested</li></ol></li></ul>\n<pre><code>cargo test</code></pre>"
+        );
+        Ok(())
+    }
+
+    fn list(style: &str, indent: u64, items: &[&str]) -> serde_json::Value {
+        serde_json::json!({
+            "type": "rich_text_list",
+            "style": style,
+            "indent": indent,
+            "elements": items
+                .iter()
+                .map(|item| serde_json::json!({
+                    "type": "rich_text_section",
+                    "elements": [{ "type": "text", "text": item }]
+                }))
+                .collect::<Vec<_>>()
+        })
+    }
+
+    #[test]
+    fn test_render_nested_lists() -> Result<(), Box<dyn std::error::Error>> {
+        let content: SlackMessageContent = serde_json::from_value(serde_json::json!({
+            "blocks": [{
+                "type": "rich_text",
+                "elements": [
+                    list("bullet", 0, &["one"]),
+                    list("ordered", 1, &["nested", "nested too"]),
+                    list("bullet", 2, &["deep"]),
+                    list("bullet", 0, &["two"]),
+                    list("ordered", 0, &["first"])
+                ]
+            }]
+        }))?;
+
+        assert_eq!(
+            renderer().to_markdown(&content),
+            "- one\n    1. nested\n    2. nested too\n        - deep\n- two\n1. first"
+        );
+        assert_eq!(renderer().to_html(&content), "<ul><li>one<ol><li>nested</li><li>nested too<ul><li>deep</li></ul></li></ol></li><li>two</li></ul><ol><li>first</li></ol>");
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_tables() -> Result<(), Box<dyn std::error::Error>> {
+        let content: SlackMessageContent = serde_json::from_value(serde_json::json!({
+            "blocks": [{
+                "type": "table",
+                "rows": [
+                    [{ "type": "raw_text", "text": "Name" }, { "type": "raw_text", "text": "Value" }],
+                    [{ "type": "raw_text", "text": "a|b" }, { "type": "raw_text", "text": "<1>" }]
+                ]
+            }]
+        }))?;
+
+        assert_eq!(
+            renderer().to_markdown(&content),
+            "| Name | Value |\n| --- | --- |\n| a\\|b | \\<1> |"
+        );
+        assert_eq!(renderer().to_html(&content), "<table><tr><td>Name</td><td>Value</td></tr><tr><td>a|b</td><td>&lt;1&gt;</td></tr></table>");
+        assert_eq!(
+            renderer().to_plain_text(&content),
+            "Name | Value\na|b | <1>"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_markdown_escaping() -> Result<(), Box<dyn std::error::Error>> {
+        let content: SlackMessageContent = serde_json::from_value(serde_json::json!({
+            "blocks": [{
+                "type": "rich_text",
+                "elements": [{
+                    "type": "rich_text_section",
+                    "elements": [
+                        { "type": "text", "text": "2*3 snake_case [x](y) " },
+                        { "type": "text", "text": "a`b", "style": { "code": true } },
+                        { "type": "text", "text": " " },
+                        { "type": "text", "text": "`tick`", "style": { "code": true } }
+                    ]
+                }]
+            }]
+        }))?;
+
+        assert_eq!(
+            renderer().to_markdown(&content),
+            "2\\*3 snake\\_case \\[x\\](y) ``a`b`` `` `tick` ``"
+        );
+        Ok(())
+    }
+
+    struct FixedDateLookup;
+
+    impl SlackMentionsLookup for FixedDateLookup {
+        fn format_date(&self, date: &DateTime<Utc>, format: &str) -> String {
+            format!("{} ({})", format, date.timestamp())
+        }
+    }
+
+    #[test]
+    fn test_render_dates_with_lookup() {
+        let content = SlackMessageContent::new()
+            .with_text("Due <!date^1700000000^{date_short} at {time}|Nov 14>".into());
+
+        assert_eq!(
+            SlackMessageRenderer::new(FixedDateLookup).to_plain_text(&content),
+            "Due {date_short} at {time} (1700000000)"
+        );
+        assert_eq!(
+            SlackMessageRenderer::without_lookup().to_plain_text(&content),
+            "Due Nov 14, 2023 at 22:13"
+        );
+    }
+
+    #[test]
+    fn test_render_attachments_fallback() -> Result<(), Box<dyn std::error::Error>> {
+        let content: SlackMessageContent = serde_json::from_value(serde_json::json!({
+            "text": "Build finished",
+            "attachments": [
+                { "fallback": "Build *passed*" },
+                { "fallback": "ignored", "text": "Deployed to <#C1>" }
+            ]
+        }))?;
+
+        assert_eq!(
+            renderer().to_markdown(&content),
+            "Build finished\n\nBuild **passed**\n\nDeployed to #general"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_blocks_with_empty_text() -> Result<(), Box<dyn std::error::Error>> {
+        let content: SlackMessageContent = serde_json::from_value(serde_json::json!({
+            "text": "",
+            "blocks": [
+                { "type": "header", "text": { "type": "plain_text", "text": "Release" } },
+                { "type": "section", "text": { "type": "mrkdwn", "text": "v1.2 is *out*" } },
+                { "type": "divider" }
+            ]
+        }))?;
+
+        assert_eq!(
+            renderer().to_plain_text(&content),
+            "Release\n\nv1.2 is out\n\n---"
+        );
+        assert_eq!(
+            renderer().to_html(&content),
+            "<h2>Release</h2>\n<p>v1.2 is <strong>out</strong></p>\n<hr>"
+        );
+        assert_eq!(
+            renderer().to_plain_text(&SlackMessageContent::new().with_text("".into())),
+            ""
+        );
+        Ok(())
+    }
+}
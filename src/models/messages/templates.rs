@@ -1,4 +1,5 @@
 use crate::blocks::SlackBlock;
+use crate::events::{SlackInteractionActionInfo, SlackInteractionBlockActionsEvent};
 use crate::SlackMessageContent;
 
 pub trait SlackMessageTemplate {
@@ -8,3 +9,164 @@ pub trait SlackMessageTemplate {
 pub trait SlackBlocksTemplate {
     fn render_template(&self) -> Vec<SlackBlock>;
 }
+
+/// Typed block actions, e.g. derived with `#[derive(SlackBlockActionsPayload)]` using the `derive` feature
+pub trait SlackBlockActionsPayload: Sized {
+    fn from_action(action: &SlackInteractionActionInfo) -> Option<Self>;
+
+    /// The actions of the event that match the type
+    fn from_event(event: &SlackInteractionBlockActionsEvent) -> Vec<Self> {
+        event
+            .actions
+            .iter()
+            .flatten()
+            .filter_map(Self::from_action)
+            .collect()
+    }
+}
+
+/// The values of block actions for `SlackBlockActionsPayload` variants
+pub trait SlackBlockActionValue: Sized {
+    fn from_action_value(action: &SlackInteractionActionInfo) -> Option<Self>;
+}
+
+impl SlackBlockActionValue for String {
+    fn from_action_value(action: &SlackInteractionActionInfo) -> Option<Self> {
+        action.value.clone()
+    }
+}
+
+impl SlackBlockActionValue for Option<String> {
+    fn from_action_value(action: &SlackInteractionActionInfo) -> Option<Self> {
+        Some(action.value.clone())
+    }
+}
+
+impl SlackBlockActionValue for SlackInteractionActionInfo {
+    fn from_action_value(action: &SlackInteractionActionInfo) -> Option<Self> {
+        Some(action.clone())
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod test {
+    use crate::prelude::*;
+
+    #[derive(SlackBlocksTemplate)]
+    struct ChangeTemplate {
+        #[slack(section, raw)]
+        description: String,
+        #[slack(context)]
+        author: String,
+    }
+
+    #[derive(SlackBlocksTemplate)]
+    struct DeployTemplate {
+        #[slack(header)]
+        title: String,
+        #[slack(field = "Service")]
+        service: String,
+        #[slack(field = "Notes")]
+        notes: Option<String>,
+        #[slack(list, divider)]
+        changes: Vec<ChangeTemplate>,
+        #[slack(button(action_id = "deploy-approve", text = "Approve", style = "primary"))]
+        approve: String,
+        #[slack(button(action_id = "deploy-reject", text = "Reject"))]
+        reject: String,
+    }
+
+    #[derive(SlackBlocksTemplate)]
+    struct KindTemplate {
+        #[slack(button(text = "Pick"))]
+        r#type: String,
+    }
+
+    #[derive(Debug, PartialEq, SlackBlockActionsPayload)]
+    enum DeployAction {
+        #[slack(action_id = "deploy-approve")]
+        Approve(String),
+        Cancel,
+    }
+
+    fn action(action_id: &str, value: Option<&str>) -> SlackInteractionActionInfo {
+        SlackInteractionActionInfo::new("button".into(), action_id.into())
+            .opt_value(value.map(|value| value.to_string()))
+    }
+
+    #[test]
+    fn test_derive_blocks_template() {
+        let template = DeployTemplate {
+            title: "Deploy".into(),
+            service: "<!here> api".into(),
+            notes: None,
+            changes: vec![ChangeTemplate {
+                description: "*Fixed* retries".into(),
+                author: "<@U1> & co".into(),
+            }],
+            approve: "42".into(),
+            reject: "42".into(),
+        };
+
+        let blocks: Vec<SlackBlock> = slack_blocks![
+            some_into(SlackHeaderBlock::new(pt!("Deploy"))),
+            some_into(
+                SlackSectionBlock::new().with_fields(vec![md!("*Service*\n&lt;!here&gt; api")])
+            ),
+            some_into(SlackDividerBlock::new()),
+            some_into(SlackSectionBlock::new().with_text(md!("*Fixed* retries"))),
+            some_into(SlackContextBlock::new(vec![
+                SlackContextBlockElement::MarkDown(SlackBlockMarkDownText::new(
+                    "&lt;@U1&gt; &amp; co".into()
+                ))
+            ])),
+            some_into(SlackActionsBlock::new(slack_blocks![
+                some_into(
+                    SlackBlockButtonElement::new("deploy-approve".into(), pt!("Approve"))
+                        .with_value("42".into())
+                        .with_style(SlackBlockButtonStyle::Primary)
+                ),
+                some_into(
+                    SlackBlockButtonElement::new("deploy-reject".into(), pt!("Reject"))
+                        .with_value("42".into())
+                )
+            ]))
+        ];
+        assert_eq!(template.render_template(), blocks);
+
+        assert_eq!(
+            DeployTemplateAction::from_action(&action("deploy-reject", Some("42"))),
+            Some(DeployTemplateAction::Reject("42".into()))
+        );
+    }
+
+    #[test]
+    fn test_derive_blocks_template_raw_identifiers() {
+        let template = KindTemplate {
+            r#type: "bug".into(),
+        };
+
+        let blocks: Vec<SlackBlock> =
+            slack_blocks![some_into(SlackActionsBlock::new(slack_blocks![some_into(
+                SlackBlockButtonElement::new("type".into(), pt!("Pick")).with_value("bug".into())
+            )]))];
+        assert_eq!(template.render_template(), blocks);
+        assert_eq!(
+            KindTemplateAction::from_action(&action("type", Some("bug"))),
+            Some(KindTemplateAction::Type("bug".into()))
+        );
+    }
+
+    #[test]
+    fn test_derive_block_actions_payload() {
+        assert_eq!(
+            DeployAction::from_action(&action("deploy-approve", Some("42"))),
+            Some(DeployAction::Approve("42".into()))
+        );
+        assert_eq!(
+            DeployAction::from_action(&action("cancel", None)),
+            Some(DeployAction::Cancel)
+        );
+        assert_eq!(DeployAction::from_action(&action("unknown", None)), None);
+    }
+}
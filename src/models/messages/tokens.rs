@@ -0,0 +1,336 @@
+use crate::blocks::*;
+use crate::*;
+use chrono::DateTime;
+
+///
+/// Typed segments of the message text, such as mentions and links
+/// (see [formatting text](https://api.slack.com/reference/surfaces/formatting)).
+///
+#[derive(Debug, PartialEq, Clone)]
+pub enum SlackMessageToken {
+    /// The text with `&amp;`, `&lt;` and `&gt;` decoded
+    Text(String),
+    UserMention {
+        user_id: SlackUserId,
+        label: Option<String>,
+    },
+    ChannelMention {
+        channel_id: SlackChannelId,
+        label: Option<String>,
+    },
+    UserGroupMention {
+        usergroup_id: SlackUserGroupId,
+        label: Option<String>,
+    },
+    /// `@here`, `@channel` and `@everyone`
+    SpecialMention(SlackRichTextBroadcastRange),
+    Link {
+        url: SlackRelaxedUrl,
+        label: Option<String>,
+    },
+    Emoji(SlackEmojiName),
+    Date {
+        timestamp: SlackDateTime,
+        format: String,
+        link: Option<SlackRelaxedUrl>,
+        fallback: Option<String>,
+    },
+}
+
+impl SlackMessageToken {
+    pub fn parse(text: &str) -> Vec<SlackMessageToken> {
+        let mut tokens = Vec::new();
+        let mut plain_text = String::new();
+        let mut rest = text;
+
+        while let Some(c) = rest.chars().next() {
+            let token = match c {
+                '<' => rest[1..]
+                    .split_once('>')
+                    .filter(|(reference, _)| !reference.contains(['\n', '<']))
+                    .map(|(reference, _)| (Self::parse_reference(reference), reference.len() + 2)),
+                ':' if !plain_text.ends_with(|c: char| c.is_alphanumeric()) => {
+                    Self::emoji_name(rest).map(|name| {
+                        let length = name.len() + 2;
+                        (SlackMessageToken::Emoji(name.into()), length)
+                    })
+                }
+                _ => None,
+            };
+
+            match token {
+                Some((token, length)) => {
+                    if !plain_text.is_empty() {
+                        tokens.push(SlackMessageToken::Text(decode_entities(&std::mem::take(
+                            &mut plain_text,
+                        ))));
+                    }
+                    tokens.push(token);
+                    rest = &rest[length..];
+                }
+                None => {
+                    plain_text.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        if !plain_text.is_empty() {
+            tokens.push(SlackMessageToken::Text(decode_entities(&plain_text)));
+        }
+
+        tokens
+    }
+
+    /// The emoji name at the start of the text, e.g. `:thumbsup::skin-tone-2:`
+    pub(crate) fn emoji_name(text: &str) -> Option<&str> {
+        let is_name_char =
+            |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '+' | '-' | '\'');
+        let name_end = 1 + text[1..].find(|c: char| !is_name_char(c))?;
+        if name_end == 1 || !text[name_end..].starts_with(':') {
+            return None;
+        }
+
+        const SKIN_TONE_PREFIX: &str = "::skin-tone-";
+        let end = text[name_end..]
+            .strip_prefix(SKIN_TONE_PREFIX)
+            .and_then(|skin_tone| {
+                let digits = skin_tone.find(|c: char| !c.is_ascii_digit())?;
+                (digits > 0 && skin_tone[digits..].starts_with(':'))
+                    .then_some(name_end + SKIN_TONE_PREFIX.len() + digits)
+            })
+            .unwrap_or(name_end);
+        Some(&text[1..end])
+    }
+
+    /// Parses the content of `<...>`
+    pub(crate) fn parse_reference(reference: &str) -> SlackMessageToken {
+        let (target, label) = match reference.split_once('|') {
+            Some((target, label)) => (target, Some(decode_entities(label))),
+            None => (reference, None),
+        };
+
+        if let Some(user_id) = target.strip_prefix('@') {
+            SlackMessageToken::UserMention {
+                user_id: user_id.into(),
+                label,
+            }
+        } else if let Some(channel_id) = target.strip_prefix('#') {
+            SlackMessageToken::ChannelMention {
+                channel_id: channel_id.into(),
+                label,
+            }
+        } else if let Some(usergroup_id) = target.strip_prefix("!subteam^") {
+            SlackMessageToken::UserGroupMention {
+                usergroup_id: usergroup_id.into(),
+                label,
+            }
+        } else if let Some(date) = target.strip_prefix("!date^") {
+            let mut parts = date.splitn(3, '^');
+            let timestamp = parts
+                .next()
+                .and_then(|timestamp| timestamp.parse::<i64>().ok())
+                .and_then(|timestamp| DateTime::from_timestamp(timestamp, 0));
+            match (timestamp, parts.next()) {
+                (Some(timestamp), Some(format)) => SlackMessageToken::Date {
+                    timestamp: SlackDateTime(timestamp),
+                    format: format.to_string(),
+                    link: parts
+                        .next()
+                        .map(|link| SlackRelaxedUrl(decode_entities(link))),
+                    fallback: label,
+                },
+                _ => SlackMessageToken::Text(label.unwrap_or_default()),
+            }
+        } else if let Some(special) = target.strip_prefix('!') {
+            match special {
+                "here" => SlackMessageToken::SpecialMention(SlackRichTextBroadcastRange::Here),
+                "channel" => {
+                    SlackMessageToken::SpecialMention(SlackRichTextBroadcastRange::Channel)
+                }
+                "everyone" => {
+                    SlackMessageToken::SpecialMention(SlackRichTextBroadcastRange::Everyone)
+                }
+                _ => SlackMessageToken::Text(label.unwrap_or_else(|| special.to_string())),
+            }
+        } else {
+            SlackMessageToken::Link {
+                url: SlackRelaxedUrl(decode_entities(target)),
+                label,
+            }
+        }
+    }
+}
+
+pub(crate) fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+impl SlackMessageContent {
+    /// The typed segments of the message text
+    pub fn text_tokens(&self) -> Vec<SlackMessageToken> {
+        self.text
+            .as_deref()
+            .map(SlackMessageToken::parse)
+            .unwrap_or_default()
+    }
+
+    /// The users mentioned in the text and rich text blocks, without duplicates
+    pub fn mentioned_users(&self) -> Vec<SlackUserId> {
+        let text_mentions = self
+            .text_tokens()
+            .into_iter()
+            .filter_map(|token| match token {
+                SlackMessageToken::UserMention { user_id, .. } => Some(user_id),
+                _ => None,
+            });
+
+        let rich_text_mentions = self
+            .blocks
+            .iter()
+            .flatten()
+            .filter_map(|block| match block {
+                SlackBlock::RichText(rich_text) => Some(rich_text),
+                _ => None,
+            })
+            .flat_map(|rich_text| rich_text.elements.iter())
+            .flat_map(|element| match element {
+                SlackRichTextElement::Section(section) => section.elements.clone(),
+                SlackRichTextElement::List(list) => list
+                    .elements
+                    .iter()
+                    .flat_map(|item| match item {
+                        SlackRichTextListElement::Section(section) => section.elements.clone(),
+                        SlackRichTextListElement::Unknown(_) => vec![],
+                    })
+                    .collect(),
+                SlackRichTextElement::Preformatted(preformatted) => preformatted.elements.clone(),
+                SlackRichTextElement::Quote(quote) => quote.elements.clone(),
+                SlackRichTextElement::Unknown(_) => vec![],
+            })
+            .filter_map(|element| match element {
+                SlackRichTextInlineElement::User(user) => Some(user.user_id),
+                _ => None,
+            });
+
+        let mut users: Vec<SlackUserId> = Vec::new();
+        for user_id in text_mentions.chain(rich_text_mentions) {
+            if !users.contains(&user_id) {
+                users.push(user_id);
+            }
+        }
+        users
+    }
+
+    ///
+    /// The `mrkdwn` text after the first mention of the user (e.g. your bot),
+    /// so `<@U0BOT> deploy api` is `deploy api`. Returns `None` if the user isn't mentioned.
+    /// Entities aren't decoded, so the escaped `&lt;@U2&gt;` typed by users can't be taken for mentions:
+    /// use `tokens_after_mention` to get the decoded text and mentions.
+    ///
+    pub fn text_after_mention(&self, user_id: &SlackUserId) -> Option<String> {
+        let text = self.text.as_deref()?;
+        let mention = format!("<@{}", user_id);
+        let start = text
+            .match_indices(&mention)
+            .map(|(index, _)| index + mention.len())
+            .find(|end| text[*end..].starts_with(['>', '|']))?;
+        let end = start + text[start..].find('>')? + 1;
+
+        Some(
+            text[end..]
+                .trim_start_matches(|c: char| c.is_whitespace() || c == ':' || c == ',')
+                .trim_end()
+                .to_string(),
+        )
+    }
+
+    /// The typed segments of the text after the first mention of the user, see `text_after_mention`
+    pub fn tokens_after_mention(&self, user_id: &SlackUserId) -> Option<Vec<SlackMessageToken>> {
+        self.text_after_mention(user_id)
+            .map(|text| SlackMessageToken::parse(&text))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_message_tokens() {
+        let tokens = SlackMessageToken::parse(
+            "<@U1> see <#C1|general> &amp; <https://example.com|docs> :thumbsup::skin-tone-2: <!here> <!subteam^S1> at <!date^1392734382^{date}|Feb 18>",
+        );
+
+        assert_eq!(
+            tokens,
+            vec![
+                SlackMessageToken::UserMention {
+                    user_id: "U1".into(),
+                    label: None
+                },
+                SlackMessageToken::Text(" see ".into()),
+                SlackMessageToken::ChannelMention {
+                    channel_id: "C1".into(),
+                    label: Some("general".into())
+                },
+                SlackMessageToken::Text(" & ".into()),
+                SlackMessageToken::Link {
+                    url: SlackRelaxedUrl("https://example.com".into()),
+                    label: Some("docs".into())
+                },
+                SlackMessageToken::Text(" ".into()),
+                SlackMessageToken::Emoji("thumbsup::skin-tone-2".into()),
+                SlackMessageToken::Text(" ".into()),
+                SlackMessageToken::SpecialMention(SlackRichTextBroadcastRange::Here),
+                SlackMessageToken::Text(" ".into()),
+                SlackMessageToken::UserGroupMention {
+                    usergroup_id: "S1".into(),
+                    label: None
+                },
+                SlackMessageToken::Text(" at ".into()),
+                SlackMessageToken::Date {
+                    timestamp: SlackDateTime(DateTime::from_timestamp(1392734382, 0).unwrap()),
+                    format: "{date}".into(),
+                    link: None,
+                    fallback: Some("Feb 18".into())
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mentions_helpers() {
+        let content =
+            SlackMessageContent::new().with_text("<@U0BOT>: deploy api &amp; notify <@U2>".into());
+
+        assert_eq!(
+            content.text_after_mention(&"U0BOT".into()),
+            Some("deploy api &amp; notify <@U2>".into())
+        );
+        assert_eq!(content.text_after_mention(&"U3".into()), None);
+        assert_eq!(content.mentioned_users(), vec!["U0BOT".into(), "U2".into()]);
+    }
+
+    #[test]
+    fn test_text_after_mention_keeps_escaped_mentions() {
+        let content =
+            SlackMessageContent::new().with_text("<@U0BOT> notify &lt;@U2&gt; and <@U3>".into());
+
+        assert_eq!(
+            content.text_after_mention(&"U0BOT".into()),
+            Some("notify &lt;@U2&gt; and <@U3>".into())
+        );
+        assert_eq!(
+            content.tokens_after_mention(&"U0BOT".into()),
+            Some(vec![
+                SlackMessageToken::Text("notify <@U2> and ".into()),
+                SlackMessageToken::UserMention {
+                    user_id: "U3".into(),
+                    label: None
+                },
+            ])
+        );
+    }
+}
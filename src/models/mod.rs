@@ -13,14 +13,22 @@
 
 mod common;
 
+pub mod admin;
 pub mod apps;
+pub mod audit;
 pub mod blocks;
 pub mod events;
 pub mod files;
+pub mod lists;
 mod messages;
+pub mod scim;
 pub mod socket_mode;
 
+pub use admin::*;
 pub use apps::*;
+pub use audit::*;
 pub use common::*;
 pub use files::*;
+pub use lists::*;
 pub use messages::*;
+pub use scim::*;
@@ -0,0 +1,238 @@
+use crate::*;
+
+use rsb_derive::Builder;
+use rvstruct::*;
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+pub const SLACK_SCIM_USER_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:User";
+pub const SLACK_SCIM_GROUP_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:Group";
+pub const SLACK_SCIM_ENTERPRISE_USER_SCHEMA: &str =
+    "urn:ietf:params:scim:schemas:extension:enterprise:2.0:User";
+pub const SLACK_SCIM_PATCH_OP_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:PatchOp";
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackScimMeta {
+    pub created: Option<String>,
+    pub location: Option<String>,
+    pub resource_type: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackScimName {
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+    pub formatted: Option<String>,
+    pub honorific_prefix: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackScimEmail {
+    pub value: EmailAddress,
+    pub primary: Option<bool>,
+    #[serde(rename = "type")]
+    pub email_type: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackScimPhoneNumber {
+    pub value: String,
+    pub primary: Option<bool>,
+    #[serde(rename = "type")]
+    pub phone_type: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackScimPhoto {
+    pub value: String,
+    #[serde(rename = "type")]
+    pub photo_type: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackScimAddress {
+    pub street_address: Option<String>,
+    pub locality: Option<String>,
+    pub region: Option<String>,
+    pub postal_code: Option<String>,
+    pub country: Option<String>,
+    pub primary: Option<bool>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackScimUserGroupRef {
+    pub value: SlackEnterpriseSubteamId,
+    pub display: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackScimManager {
+    pub manager_id: SlackUserId,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackScimEnterpriseUser {
+    pub employee_number: Option<String>,
+    pub cost_center: Option<String>,
+    pub organization: Option<String>,
+    pub division: Option<String>,
+    pub department: Option<String>,
+    pub manager: Option<SlackScimManager>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackScimUser {
+    pub schemas: Vec<String>,
+    pub id: Option<SlackUserId>,
+    pub external_id: Option<String>,
+    pub user_name: String,
+    pub nick_name: Option<String>,
+    pub name: Option<SlackScimName>,
+    pub display_name: Option<String>,
+    pub profile_url: Option<String>,
+    pub title: Option<String>,
+    pub timezone: Option<String>,
+    pub active: Option<bool>,
+    pub emails: Option<Vec<SlackScimEmail>>,
+    pub phone_numbers: Option<Vec<SlackScimPhoneNumber>>,
+    pub photos: Option<Vec<SlackScimPhoto>>,
+    pub addresses: Option<Vec<SlackScimAddress>>,
+    pub groups: Option<Vec<SlackScimUserGroupRef>>,
+    pub user_type: Option<String>,
+    pub preferred_language: Option<String>,
+    pub locale: Option<String>,
+    pub password: Option<String>,
+    pub meta: Option<SlackScimMeta>,
+    #[serde(rename = "urn:ietf:params:scim:schemas:extension:enterprise:2.0:User")]
+    pub enterprise_user: Option<SlackScimEnterpriseUser>,
+}
+
+impl SlackScimUser {
+    /// Creates a user with the core SCIM user schema already set
+    pub fn from_user_name(user_name: String) -> Self {
+        Self::new(vec![SLACK_SCIM_USER_SCHEMA.to_string()], user_name)
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackScimGroupMember {
+    pub value: SlackUserId,
+    pub display: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackScimGroup {
+    pub schemas: Vec<String>,
+    pub id: Option<SlackEnterpriseSubteamId>,
+    pub display_name: String,
+    pub members: Option<Vec<SlackScimGroupMember>>,
+    pub meta: Option<SlackScimMeta>,
+}
+
+impl SlackScimGroup {
+    /// Creates a group with the core SCIM group schema already set
+    pub fn from_display_name(display_name: String) -> Self {
+        Self::new(vec![SLACK_SCIM_GROUP_SCHEMA.to_string()], display_name)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlackScimPatchOperationType {
+    Add,
+    Remove,
+    Replace,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackScimPatchOperation {
+    pub op: SlackScimPatchOperationType,
+    pub path: Option<String>,
+    pub value: Option<serde_json::Value>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackScimPatchRequest {
+    pub schemas: Vec<String>,
+    #[serde(rename = "Operations")]
+    pub operations: Vec<SlackScimPatchOperation>,
+}
+
+impl SlackScimPatchRequest {
+    /// Creates a patch request with the SCIM PatchOp message schema already set
+    pub fn from_operations(operations: Vec<SlackScimPatchOperation>) -> Self {
+        Self::new(vec![SLACK_SCIM_PATCH_OP_SCHEMA.to_string()], operations)
+    }
+}
+
+/// A SCIM filter expression (e.g. `userName eq "john"`).
+/// Values are quoted and escaped, so it is safe to pass user provided input.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Serialize, Deserialize, ValueStruct)]
+pub struct SlackScimFilter(pub String);
+
+impl SlackScimFilter {
+    fn quote(value: &str) -> String {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+
+    fn compare(attribute: &str, operator: &str, value: &str) -> Self {
+        Self(format!("{} {} {}", attribute, operator, Self::quote(value)))
+    }
+
+    pub fn eq(attribute: &str, value: &str) -> Self {
+        Self::compare(attribute, "eq", value)
+    }
+
+    pub fn ne(attribute: &str, value: &str) -> Self {
+        Self::compare(attribute, "ne", value)
+    }
+
+    pub fn co(attribute: &str, value: &str) -> Self {
+        Self::compare(attribute, "co", value)
+    }
+
+    pub fn sw(attribute: &str, value: &str) -> Self {
+        Self::compare(attribute, "sw", value)
+    }
+
+    pub fn ew(attribute: &str, value: &str) -> Self {
+        Self::compare(attribute, "ew", value)
+    }
+
+    pub fn pr(attribute: &str) -> Self {
+        Self(format!("{attribute} pr"))
+    }
+
+    pub fn and(self, other: SlackScimFilter) -> Self {
+        Self(format!("({}) and ({})", self.0, other.0))
+    }
+
+    pub fn or(self, other: SlackScimFilter) -> Self {
+        Self(format!("({}) or ({})", self.0, other.0))
+    }
+
+    pub fn negate(self) -> Self {
+        Self(format!("not ({})", self.0))
+    }
+}
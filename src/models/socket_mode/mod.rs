@@ -3,7 +3,8 @@ use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
 use crate::events::{
-    SlackCommandEvent, SlackCommandEventResponse, SlackInteractionEvent, SlackPushEventCallback,
+    SlackCommandEvent, SlackCommandEventResponse, SlackInteractionEvent,
+    SlackInteractionEventResponsePayload, SlackPushEventCallback,
 };
 use crate::*;
 use rvstruct::*;
@@ -84,6 +85,8 @@ pub struct SlackSocketModeEventsApiEvent {
     #[serde(flatten)]
     pub envelope_params: SlackSocketModeEventEnvelopeParams,
     pub payload: SlackPushEventCallback,
+    pub retry_attempt: Option<u32>,
+    pub retry_reason: Option<String>,
 }
 
 #[skip_serializing_none]
@@ -101,3 +104,11 @@ pub struct SlackSocketModeCommandEventAck {
     pub envelope_ack_params: SlackSocketModeEventCommonAcknowledge,
     pub payload: Option<SlackCommandEventResponse>,
 }
+
+#[skip_serializing_none]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackSocketModeInteractiveEventAck {
+    #[serde(flatten)]
+    pub envelope_ack_params: SlackSocketModeEventCommonAcknowledge,
+    pub payload: Option<SlackInteractionEventResponsePayload>,
+}
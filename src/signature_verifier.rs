@@ -60,7 +60,7 @@ impl SlackEventSignatureVerifier {
         }
     }
 
-    fn sign<'a, 'b>(
+    pub(crate) fn sign<'a, 'b>(
         &'a self,
         body: &'b str,
         ts: &'b str,
@@ -1,15 +1,16 @@
 use crate::errors::*;
 use crate::events::*;
-use crate::listener::{SlackClientEventsUserState, UserCallbackFunction};
+use crate::listener::{SlackClientEventsUserState, SlackEventsRouter};
 use crate::models::events::{SlackCommandEvent, SlackCommandEventResponse};
 use crate::models::socket_mode::SlackSocketModeHelloEvent;
 use crate::{AnyStdResult, SlackClient, SlackClientHttpConnector, UserCallbackResult};
 use futures::future::BoxFuture;
+use futures::TryFutureExt;
 use std::future::Future;
 use std::sync::Arc;
 use tracing::*;
 
-pub trait SlackSocketModeListenerCallback<SCHC, RQ, RS>
+pub trait SlackSocketModeListenerCallback<SCHC, RQ, RS, S = SlackClientEventsUserState>
 where
     SCHC: SlackClientHttpConnector + Send + Sync,
     RQ: Send + Sync + 'static,
@@ -19,13 +20,13 @@ where
         &self,
         ev: RQ,
         client: Arc<SlackClient<SCHC>>,
-        state_storage: SlackClientEventsUserState,
+        state_storage: S,
     ) -> BoxFuture<'static, RS>;
 }
 
-impl<T, F, SCHC, RQ, RS> SlackSocketModeListenerCallback<SCHC, RQ, RS> for T
+impl<T, F, SCHC, RQ, RS, S> SlackSocketModeListenerCallback<SCHC, RQ, RS, S> for T
 where
-    T: Send + Sync + Fn(RQ, Arc<SlackClient<SCHC>>, SlackClientEventsUserState) -> F,
+    T: Send + Sync + Fn(RQ, Arc<SlackClient<SCHC>>, S) -> F,
     F: Future<Output = RS> + Send + 'static,
     SCHC: SlackClientHttpConnector + Send + Sync,
     RQ: Send + Sync + 'static,
@@ -35,42 +36,50 @@ where
         &self,
         ev: RQ,
         client: Arc<SlackClient<SCHC>>,
-        state_storage: SlackClientEventsUserState,
+        state_storage: S,
     ) -> BoxFuture<'static, RS> {
         Box::pin(self(ev, client, state_storage))
     }
 }
 
-pub struct SlackSocketModeListenerCallbacks<SCHC>
+pub struct SlackSocketModeListenerCallbacks<SCHC, S = SlackClientEventsUserState>
 where
     SCHC: SlackClientHttpConnector + Send + Sync,
+    S: Clone + Send + Sync + 'static,
 {
-    pub hello_callback:
-        Box<dyn SlackSocketModeListenerCallback<SCHC, SlackSocketModeHelloEvent, ()> + Send + Sync>,
+    pub hello_callback: Box<
+        dyn SlackSocketModeListenerCallback<SCHC, SlackSocketModeHelloEvent, (), S> + Send + Sync,
+    >,
 
     pub command_callback: Box<
         dyn SlackSocketModeListenerCallback<
                 SCHC,
                 SlackCommandEvent,
                 UserCallbackResult<SlackCommandEventResponse>,
+                S,
             > + Send
             + Sync,
     >,
     pub interaction_callback: Box<
-        dyn SlackSocketModeListenerCallback<SCHC, SlackInteractionEvent, UserCallbackResult<()>>
-            + Send
+        dyn SlackSocketModeListenerCallback<
+                SCHC,
+                SlackInteractionEvent,
+                UserCallbackResult<Option<SlackInteractionEventResponsePayload>>,
+                S,
+            > + Send
             + Sync,
     >,
     pub push_events_callback: Box<
-        dyn SlackSocketModeListenerCallback<SCHC, SlackPushEventCallback, UserCallbackResult<()>>
+        dyn SlackSocketModeListenerCallback<SCHC, SlackPushEventCallback, UserCallbackResult<()>, S>
             + Send
             + Sync,
     >,
 }
 
-impl<SCHC> SlackSocketModeListenerCallbacks<SCHC>
+impl<SCHC, S> SlackSocketModeListenerCallbacks<SCHC, S>
 where
     SCHC: SlackClientHttpConnector + Send + Sync + 'static,
+    S: Clone + Send + Sync + 'static,
 {
     pub fn new() -> Self {
         Self {
@@ -81,11 +90,9 @@ where
         }
     }
 
-    pub fn with_hello_events<F>(
-        mut self,
-        hello_events_fn: UserCallbackFunction<SlackSocketModeHelloEvent, F, SCHC>,
-    ) -> Self
+    pub fn with_hello_events<EF, F>(mut self, hello_events_fn: EF) -> Self
     where
+        EF: Fn(SlackSocketModeHelloEvent, Arc<SlackClient<SCHC>>, S) -> F + Send + Sync + 'static,
         F: Future<Output = ()> + Send + 'static,
     {
         self.hello_callback = Box::new(hello_events_fn);
@@ -95,16 +102,14 @@ where
     async fn empty_hello_callback(
         event: SlackSocketModeHelloEvent,
         _client: Arc<SlackClient<SCHC>>,
-        _states: SlackClientEventsUserState,
+        _states: S,
     ) {
         debug!("Received Slack hello for socket mode: {:?}", event);
     }
 
-    pub fn with_command_events<F>(
-        mut self,
-        command_events_fn: UserCallbackFunction<SlackCommandEvent, F, SCHC>,
-    ) -> Self
+    pub fn with_command_events<EF, F>(mut self, command_events_fn: EF) -> Self
     where
+        EF: Fn(SlackCommandEvent, Arc<SlackClient<SCHC>>, S) -> F + Send + Sync + 'static,
         F: Future<Output = UserCallbackResult<SlackCommandEventResponse>> + Send + 'static,
     {
         self.command_callback = Box::new(command_events_fn);
@@ -114,7 +119,7 @@ where
     async fn empty_command_events_callback(
         event: SlackCommandEvent,
         _client: Arc<SlackClient<SCHC>>,
-        _states: SlackClientEventsUserState,
+        _states: S,
     ) -> AnyStdResult<SlackCommandEventResponse> {
         warn!("No callback is specified for a command event: {:?}", event);
         Err(Box::new(SlackClientError::SystemError(
@@ -123,22 +128,26 @@ where
         )))
     }
 
-    pub fn with_interaction_events<F>(
-        mut self,
-        interaction_events_fn: UserCallbackFunction<SlackInteractionEvent, F, SCHC>,
-    ) -> Self
+    /// The callback may return a response (e.g. `SlackBlockSuggestionResponse` or `SlackViewSubmissionResponse`)
+    /// that is sent to Slack in the acknowledge, or `()` otherwise.
+    pub fn with_interaction_events<EF, F, R>(mut self, interaction_events_fn: EF) -> Self
     where
-        F: Future<Output = UserCallbackResult<()>> + Send + 'static,
+        EF: Fn(SlackInteractionEvent, Arc<SlackClient<SCHC>>, S) -> F + Send + Sync + 'static,
+        F: Future<Output = UserCallbackResult<R>> + Send + 'static,
+        R: SlackInteractionEventResult + 'static,
     {
-        self.interaction_callback = Box::new(interaction_events_fn);
+        self.interaction_callback = Box::new(move |event, client, states| {
+            interaction_events_fn(event, client, states)
+                .map_ok(SlackInteractionEventResult::into_response_payload)
+        });
         self
     }
 
     async fn empty_interaction_events_callback(
         event: SlackInteractionEvent,
         _client: Arc<SlackClient<SCHC>>,
-        _states: SlackClientEventsUserState,
-    ) -> UserCallbackResult<()> {
+        _states: S,
+    ) -> UserCallbackResult<Option<SlackInteractionEventResponsePayload>> {
         warn!(
             "No callback is specified for interactive events: {:?}",
             event
@@ -149,21 +158,45 @@ where
         )))
     }
 
-    pub fn with_push_events<F>(
-        mut self,
-        push_events_fn: UserCallbackFunction<SlackPushEventCallback, F, SCHC>,
-    ) -> Self
+    pub fn with_push_events<EF, F>(mut self, push_events_fn: EF) -> Self
     where
+        EF: Fn(SlackPushEventCallback, Arc<SlackClient<SCHC>>, S) -> F + Send + Sync + 'static,
         F: Future<Output = UserCallbackResult<()>> + Send + 'static,
     {
         self.push_events_callback = Box::new(push_events_fn);
         self
     }
 
+    /// Routes push events, commands and interactions with the events router
+    pub fn with_events_router(self, router: Arc<SlackEventsRouter<SCHC, S>>) -> Self {
+        let push_router = router.clone();
+        let command_router = router.clone();
+        self.with_push_events(move |event, client, user_state| {
+            let router = push_router.clone();
+            async move {
+                router
+                    .route_push_event_callback(event, client, user_state)
+                    .await
+            }
+        })
+        .with_command_events(move |event, client, user_state| {
+            let router = command_router.clone();
+            async move { router.route_command_event(event, client, user_state).await }
+        })
+        .with_interaction_events(move |event, client, user_state| {
+            let router = router.clone();
+            async move {
+                router
+                    .route_interaction_event(event, client, user_state)
+                    .await
+            }
+        })
+    }
+
     async fn empty_push_events_callback(
         event: SlackPushEventCallback,
         _client: Arc<SlackClient<SCHC>>,
-        _states: SlackClientEventsUserState,
+        _states: S,
     ) -> UserCallbackResult<()> {
         warn!("No callback is specified for a push event: {:?}", event);
 
@@ -173,3 +206,76 @@ where
         )))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_connector::SlackTestHttpConnector;
+    use crate::SlackMessageContent;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn context_callbacks(
+        counter: Arc<AtomicUsize>,
+        greeting: Arc<String>,
+    ) -> SlackSocketModeListenerCallbacks<SlackTestHttpConnector> {
+        SlackSocketModeListenerCallbacks::new()
+            .with_push_events(move |_event, _client, _states| {
+                let counter = counter.clone();
+                async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    Ok(())
+                }
+            })
+            .with_command_events(move |event, _client, _states| {
+                let greeting = greeting.clone();
+                async move {
+                    Ok(SlackCommandEventResponse::new(
+                        SlackMessageContent::new()
+                            .with_text(format!("{}, <@{}>", greeting, event.user_id)),
+                    ))
+                }
+            })
+    }
+
+    #[tokio::test]
+    async fn test_closure_callbacks_capture_context() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let callbacks = context_callbacks(counter.clone(), Arc::new("Hello".to_string()));
+        let client = Arc::new(SlackClient::new(SlackTestHttpConnector::new()));
+        let states = SlackClientEventsUserState::new(
+            crate::listener::SlackClientEventsUserStateStorage::new(),
+        );
+
+        let push_event: SlackPushEventCallback =
+            serde_json::from_str(include_str!("../models/events/fixtures/call_rejected.json"))
+                .unwrap();
+        callbacks
+            .push_events_callback
+            .call(push_event.clone(), client.clone(), states.clone())
+            .await
+            .unwrap();
+        callbacks
+            .push_events_callback
+            .call(push_event, client.clone(), states.clone())
+            .await
+            .unwrap();
+        assert_eq!(counter.load(Ordering::SeqCst), 2);
+
+        let command_event = SlackCommandEvent::new(
+            "T1".into(),
+            "C1".into(),
+            "U1".into(),
+            "/hello".into(),
+            url::Url::parse("https://hooks.slack.com/commands/T1/1/xyz")
+                .unwrap()
+                .into(),
+            "trigger".into(),
+        );
+        let response = callbacks
+            .command_callback
+            .call(command_event, client, states)
+            .await
+            .unwrap();
+        assert_eq!(response.content.text, Some("Hello, <@U1>".to_string()));
+    }
+}
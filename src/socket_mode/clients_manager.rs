@@ -10,10 +10,12 @@ pub trait SlackSocketModeClientsManagerFactory<SCHC>
 where
     SCHC: SlackClientHttpConnector + Send + Sync,
 {
-    fn new_clients_manager(
+    fn new_clients_manager<S>(
         &self,
-        listener_environment: Arc<SlackClientEventsListenerEnvironment<SCHC>>,
-    ) -> Arc<dyn SlackSocketModeClientsManager + Send + Sync>;
+        listener_environment: Arc<SlackClientEventsListenerEnvironment<SCHC, S>>,
+    ) -> Arc<dyn SlackSocketModeClientsManager + Send + Sync>
+    where
+        S: Clone + Send + Sync + 'static;
 }
 
 #[async_trait]
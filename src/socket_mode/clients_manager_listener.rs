@@ -5,7 +5,11 @@ use async_trait::async_trait;
 use std::sync::{Arc, Weak};
 
 use crate::errors::*;
-use crate::listener::SlackClientEventsListenerEnvironment;
+use crate::events::{SlackCommandEventResponse, SlackEventRetryInfo, SlackPushEventCallback};
+use crate::listener::{
+    SlackClientEventsListenerEnvironment, SlackEventErrorContext, SlackEventErrorResponse,
+    SlackEventKind,
+};
 use crate::socket_mode::wss_client_id::SlackSocketModeWssClientId;
 use tracing::*;
 
@@ -22,23 +26,25 @@ pub trait SlackSocketModeClientListener {
     async fn on_disconnect(&self, client_id: &SlackSocketModeWssClientId);
 }
 
-pub(crate) struct SlackSocketModeClientsManagerListener<SCHC>
+pub(crate) struct SlackSocketModeClientsManagerListener<SCHC, S>
 where
     SCHC: SlackClientHttpConnector + SlackSocketModeClientsManagerFactory<SCHC> + Send + Sync,
+    S: Clone + Send + Sync + 'static,
 {
     clients_manager: Weak<dyn SlackSocketModeClientsManager + Send + Sync>,
-    listener_environment: Arc<SlackClientEventsListenerEnvironment<SCHC>>,
-    callbacks: Arc<SlackSocketModeListenerCallbacks<SCHC>>,
+    listener_environment: Arc<SlackClientEventsListenerEnvironment<SCHC, S>>,
+    callbacks: Arc<SlackSocketModeListenerCallbacks<SCHC, S>>,
 }
 
-impl<SCHC> SlackSocketModeClientsManagerListener<SCHC>
+impl<SCHC, S> SlackSocketModeClientsManagerListener<SCHC, S>
 where
     SCHC: SlackClientHttpConnector + SlackSocketModeClientsManagerFactory<SCHC> + Send + Sync,
+    S: Clone + Send + Sync + 'static,
 {
     pub(crate) fn new(
         manager: Weak<dyn SlackSocketModeClientsManager + Send + Sync>,
-        listener_environment: Arc<SlackClientEventsListenerEnvironment<SCHC>>,
-        callbacks: SlackSocketModeListenerCallbacks<SCHC>,
+        listener_environment: Arc<SlackClientEventsListenerEnvironment<SCHC, S>>,
+        callbacks: SlackSocketModeListenerCallbacks<SCHC, S>,
     ) -> Self {
         Self {
             clients_manager: manager,
@@ -46,16 +52,37 @@ where
             callbacks: Arc::new(callbacks),
         }
     }
+
+    async fn is_duplicate_envelope(&self, envelope_id: &SlackSocketModeEnvelopeId) -> bool {
+        match &self.listener_environment.deduplicator {
+            Some(deduplicator) => deduplicator.is_duplicate_envelope(envelope_id).await,
+            None => false,
+        }
+    }
+
+    async fn is_duplicate_push_event(&self, event: &SlackPushEventCallback) -> bool {
+        match &self.listener_environment.deduplicator {
+            Some(deduplicator) => deduplicator.is_duplicate_push_event(event).await,
+            None => false,
+        }
+    }
+
+    async fn forget_failed_envelope(&self, envelope_id: &SlackSocketModeEnvelopeId) {
+        if let Some(deduplicator) = &self.listener_environment.deduplicator {
+            deduplicator.forget_envelope(envelope_id).await;
+        }
+    }
 }
 
 #[async_trait]
-impl<SCHC> SlackSocketModeClientListener for SlackSocketModeClientsManagerListener<SCHC>
+impl<SCHC, S> SlackSocketModeClientListener for SlackSocketModeClientsManagerListener<SCHC, S>
 where
     SCHC: SlackClientHttpConnector
         + SlackSocketModeClientsManagerFactory<SCHC>
         + Send
         + Sync
         + 'static,
+    S: Clone + Send + Sync + 'static,
 {
     async fn on_message(
         &self,
@@ -65,7 +92,7 @@ where
         if let Some(clients_manager) = self.clients_manager.upgrade() {
             match serde_json::from_str::<SlackSocketModeEvent>(message_body.as_str()).map_err(|e| {
                 SlackClientProtocolError::new(e)
-                    .with_json_body(message_body)
+                    .with_json_body(message_body.clone())
                     .into()
             }) {
                 Ok(sm_event) => match sm_event {
@@ -90,12 +117,20 @@ where
                         None
                     }
                     SlackSocketModeEvent::Interactive(event) => {
+                        let duplicate = self
+                            .is_duplicate_envelope(&event.envelope_params.envelope_id)
+                            .await;
+
                         let reply =
                             serde_json::to_string(&SlackSocketModeEventCommonAcknowledge::new(
-                                event.envelope_params.envelope_id,
+                                event.envelope_params.envelope_id.clone(),
                             ))
                             .unwrap();
 
+                        if duplicate {
+                            return Some(reply);
+                        }
+
                         match self
                             .callbacks
                             .interaction_callback
@@ -106,14 +141,32 @@ where
                             )
                             .await
                         {
-                            Ok(_) => Some(reply),
-                            Err(err) => {
-                                if self.listener_environment.error_handler.clone()(
-                                    err,
-                                    self.listener_environment.client.clone(),
-                                    self.listener_environment.user_state.clone(),
+                            Ok(None) => Some(reply),
+                            Ok(payload) => Some(
+                                serde_json::to_string(
+                                    &SlackSocketModeInteractiveEventAck::new(
+                                        SlackSocketModeEventCommonAcknowledge::new(
+                                            event.envelope_params.envelope_id,
+                                        ),
+                                    )
+                                    .opt_payload(payload),
                                 )
-                                .is_success()
+                                .unwrap(),
+                            ),
+                            Err(err) => {
+                                self.forget_failed_envelope(&event.envelope_params.envelope_id)
+                                    .await;
+                                if self
+                                    .listener_environment
+                                    .handle_error_with_status(
+                                        err,
+                                        SlackEventErrorContext::from_interaction_event(
+                                            &event.payload,
+                                        )
+                                        .with_raw_payload(message_body),
+                                    )
+                                    .await
+                                    .is_success()
                                 {
                                     Some(reply)
                                 } else {
@@ -123,17 +176,41 @@ where
                         }
                     }
                     SlackSocketModeEvent::EventsApi(event) => {
+                        let duplicate = self
+                            .is_duplicate_envelope(&event.envelope_params.envelope_id)
+                            .await
+                            || self.is_duplicate_push_event(&event.payload).await;
+
                         let reply =
                             serde_json::to_string(&SlackSocketModeEventCommonAcknowledge::new(
-                                event.envelope_params.envelope_id,
+                                event.envelope_params.envelope_id.clone(),
                             ))
                             .unwrap();
 
+                        if duplicate {
+                            return Some(reply);
+                        }
+
+                        let event_id = event.payload.event_id.clone();
+                        let error_context =
+                            SlackEventErrorContext::from_push_event_callback(&event.payload)
+                                .with_raw_payload(message_body);
+
+                        let payload = SlackPushEventCallback {
+                            retry: event.retry_attempt.filter(|attempt| *attempt > 0).map(
+                                |retry_num| SlackEventRetryInfo {
+                                    retry_num,
+                                    retry_reason: event.retry_reason.clone(),
+                                },
+                            ),
+                            ..event.payload
+                        };
+
                         match self
                             .callbacks
                             .push_events_callback
                             .call(
-                                event.payload.clone(),
+                                payload,
                                 self.listener_environment.client.clone(),
                                 self.listener_environment.user_state.clone(),
                             )
@@ -141,12 +218,16 @@ where
                         {
                             Ok(_) => Some(reply),
                             Err(err) => {
-                                if self.listener_environment.error_handler.clone()(
-                                    err,
-                                    self.listener_environment.client.clone(),
-                                    self.listener_environment.user_state.clone(),
-                                )
-                                .is_success()
+                                self.forget_failed_envelope(&event.envelope_params.envelope_id)
+                                    .await;
+                                self.listener_environment
+                                    .forget_failed_push_event(&event_id)
+                                    .await;
+                                if self
+                                    .listener_environment
+                                    .handle_error_with_status(err, error_context)
+                                    .await
+                                    .is_success()
                                 {
                                     Some(reply)
                                 } else {
@@ -157,6 +238,20 @@ where
                     }
 
                     SlackSocketModeEvent::SlashCommands(event) => {
+                        if self
+                            .is_duplicate_envelope(&event.envelope_params.envelope_id)
+                            .await
+                        {
+                            return Some(
+                                serde_json::to_string(&SlackSocketModeCommandEventAck::new(
+                                    SlackSocketModeEventCommonAcknowledge::new(
+                                        event.envelope_params.envelope_id,
+                                    ),
+                                ))
+                                .unwrap(),
+                            );
+                        }
+
                         match self
                             .callbacks
                             .command_callback
@@ -179,36 +274,51 @@ where
                                 .unwrap(),
                             ),
                             Err(err) => {
-                                if self.listener_environment.error_handler.clone()(
-                                    err,
-                                    self.listener_environment.client.clone(),
-                                    self.listener_environment.user_state.clone(),
-                                )
-                                .is_success()
+                                self.forget_failed_envelope(&event.envelope_params.envelope_id)
+                                    .await;
+                                let error_context =
+                                    SlackEventErrorContext::from_command_event(&event.payload)
+                                        .with_raw_payload(message_body);
+                                let error_reply = match self
+                                    .listener_environment
+                                    .handle_error(err, error_context)
+                                    .await
                                 {
-                                    Some(
-                                        serde_json::to_string(
-                                            &SlackSocketModeCommandEventAck::new(
-                                                SlackSocketModeEventCommonAcknowledge::new(
-                                                    event.envelope_params.envelope_id,
-                                                ),
+                                    SlackEventErrorResponse::Status(status_code)
+                                        if status_code.is_success() =>
+                                    {
+                                        Some(None)
+                                    }
+                                    SlackEventErrorResponse::Status(_) => None,
+                                    SlackEventErrorResponse::EphemeralReply(content) => Some(Some(
+                                        SlackCommandEventResponse::new(content).with_response_type(
+                                            SlackMessageResponseType::Ephemeral,
+                                        ),
+                                    )),
+                                };
+                                error_reply.map(|payload| {
+                                    serde_json::to_string(
+                                        &SlackSocketModeCommandEventAck::new(
+                                            SlackSocketModeEventCommonAcknowledge::new(
+                                                event.envelope_params.envelope_id,
                                             ),
                                         )
-                                        .unwrap(),
+                                        .opt_payload(payload),
                                     )
-                                } else {
-                                    None
-                                }
+                                    .unwrap()
+                                })
                             }
                         }
                     }
                 },
                 Err(err) => {
-                    self.listener_environment.error_handler.clone()(
-                        err,
-                        self.listener_environment.client.clone(),
-                        self.listener_environment.user_state.clone(),
-                    );
+                    self.listener_environment
+                        .handle_error_with_status(
+                            err,
+                            SlackEventErrorContext::new(SlackEventKind::Unknown)
+                                .with_raw_payload(message_body),
+                        )
+                        .await;
                     None
                 }
             }
@@ -218,11 +328,9 @@ where
     }
 
     async fn on_error(&self, error: BoxError) {
-        self.listener_environment.error_handler.clone()(
-            error,
-            self.listener_environment.client.clone(),
-            self.listener_environment.user_state.clone(),
-        );
+        self.listener_environment
+            .handle_error_with_status(error, SlackEventErrorContext::new(SlackEventKind::Unknown))
+            .await;
     }
 
     async fn on_disconnect(&self, client_id: &SlackSocketModeWssClientId) {
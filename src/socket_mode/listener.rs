@@ -1,35 +1,37 @@
-use crate::listener::SlackClientEventsListenerEnvironment;
+use crate::listener::{SlackClientEventsListenerEnvironment, SlackClientEventsUserState};
 use crate::socket_mode::clients_manager::*;
 use crate::socket_mode::clients_manager_listener::SlackSocketModeClientsManagerListener;
 use crate::*;
 use std::sync::Arc;
 use tracing::*;
 
-pub struct SlackClientSocketModeListener<SCHC>
+pub struct SlackClientSocketModeListener<SCHC, S = SlackClientEventsUserState>
 where
     SCHC: SlackClientHttpConnector
         + SlackSocketModeClientsManagerFactory<SCHC>
         + Send
         + Sync
         + 'static,
+    S: Clone + Send + Sync + 'static,
 {
     config: SlackClientSocketModeConfig,
     clients_manager: Arc<dyn SlackSocketModeClientsManager + Send + Sync>,
-    clients_manager_listener: Arc<SlackSocketModeClientsManagerListener<SCHC>>,
+    clients_manager_listener: Arc<SlackSocketModeClientsManagerListener<SCHC, S>>,
 }
 
-impl<SCHC> SlackClientSocketModeListener<SCHC>
+impl<SCHC, S> SlackClientSocketModeListener<SCHC, S>
 where
     SCHC: SlackClientHttpConnector
         + SlackSocketModeClientsManagerFactory<SCHC>
         + Send
         + Sync
         + 'static,
+    S: Clone + Send + Sync + 'static,
 {
     pub fn new(
         config: &SlackClientSocketModeConfig,
-        listener_environment: Arc<SlackClientEventsListenerEnvironment<SCHC>>,
-        callbacks: SlackSocketModeListenerCallbacks<SCHC>,
+        listener_environment: Arc<SlackClientEventsListenerEnvironment<SCHC, S>>,
+        callbacks: SlackSocketModeListenerCallbacks<SCHC, S>,
     ) -> Self {
         let clients_manager: Arc<dyn SlackSocketModeClientsManager + Send + Sync> =
             listener_environment
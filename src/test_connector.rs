@@ -0,0 +1,163 @@
+//!
+//! An HTTP connector for tests that records requests and replies with queued responses
+//! instead of calling Slack
+//!
+
+use crate::errors::*;
+use crate::multipart_form::FileMultipartData;
+use crate::*;
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use url::Url;
+
+#[derive(Default)]
+pub(crate) struct SlackTestHttpConnector {
+    responses: Mutex<HashMap<String, VecDeque<Result<serde_json::Value, String>>>>,
+    requests: Mutex<Vec<(String, serde_json::Value)>>,
+}
+
+impl SlackTestHttpConnector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a successful response for the API method (e.g. `views.update`)
+    pub fn respond(&self, method: &str, response: serde_json::Value) {
+        self.queue(method, Ok(response));
+    }
+
+    /// Queues a Slack API error (e.g. `hash_conflict`) for the API method
+    pub fn respond_error(&self, method: &str, code: &str) {
+        self.queue(method, Err(code.to_string()));
+    }
+
+    /// The request bodies sent to the API method
+    pub fn requests(&self, method: &str) -> Vec<serde_json::Value> {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(request_method, _)| request_method == method)
+            .map(|(_, body)| body.clone())
+            .collect()
+    }
+
+    fn queue(&self, method: &str, response: Result<serde_json::Value, String>) {
+        self.responses
+            .lock()
+            .unwrap()
+            .entry(method.to_string())
+            .or_default()
+            .push_back(response);
+    }
+
+    fn reply<'a, RS>(
+        &self,
+        full_uri: &Url,
+        body: serde_json::Value,
+    ) -> BoxFuture<'a, ClientResult<RS>>
+    where
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a,
+    {
+        let method = full_uri
+            .path()
+            .trim_start_matches("/api/")
+            .trim_start_matches('/')
+            .to_string();
+        self.requests.lock().unwrap().push((method.clone(), body));
+
+        let response = self
+            .responses
+            .lock()
+            .unwrap()
+            .get_mut(&method)
+            .and_then(|responses| responses.pop_front())
+            .unwrap_or_else(|| Err(format!("no_test_response_for_{}", method)));
+
+        let result = match response {
+            Ok(value) => serde_json::from_value(value).map_err(|err| map_serde_error(err, None)),
+            Err(code) => Err(SlackClientError::ApiError(SlackClientApiError::new(code))),
+        };
+        std::future::ready(result).boxed()
+    }
+}
+
+impl SlackClientHttpConnector for SlackTestHttpConnector {
+    fn http_get_uri<'a, RS>(
+        &'a self,
+        full_uri: Url,
+        _context: SlackClientApiCallContext<'a>,
+    ) -> BoxFuture<'a, ClientResult<RS>>
+    where
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a + 'a + Send,
+    {
+        let params: serde_json::Map<String, serde_json::Value> = full_uri
+            .query_pairs()
+            .map(|(name, value)| (name.to_string(), value.to_string().into()))
+            .collect();
+        self.reply(&full_uri, params.into())
+    }
+
+    fn http_get_with_client_secret<'a, RS>(
+        &'a self,
+        full_uri: Url,
+        _client_id: &'a SlackClientId,
+        _client_secret: &'a SlackClientSecret,
+    ) -> BoxFuture<'a, ClientResult<RS>>
+    where
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a + 'a + Send,
+    {
+        self.reply(&full_uri, serde_json::Value::Null)
+    }
+
+    fn http_post_uri<'a, RQ, RS>(
+        &'a self,
+        full_uri: Url,
+        request_body: &'a RQ,
+        _context: SlackClientApiCallContext<'a>,
+    ) -> BoxFuture<'a, ClientResult<RS>>
+    where
+        RQ: serde::ser::Serialize + Send + Sync,
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a + Send + 'a,
+    {
+        let body = serde_json::to_value(request_body).unwrap_or_default();
+        self.reply(&full_uri, body)
+    }
+
+    fn http_post_uri_multipart_form<'a, 'p, RS, PT, TS>(
+        &'a self,
+        full_uri: Url,
+        _file: Option<FileMultipartData<'p>>,
+        params: &'p PT,
+        _context: SlackClientApiCallContext<'a>,
+    ) -> BoxFuture<'a, ClientResult<RS>>
+    where
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a + Send + 'a,
+        PT: std::iter::IntoIterator<Item = (&'p str, Option<TS>)> + Clone,
+        TS: AsRef<str> + 'p + Send,
+    {
+        let params: serde_json::Map<String, serde_json::Value> = params
+            .clone()
+            .into_iter()
+            .filter_map(|(name, value)| {
+                value.map(|value| (name.to_string(), value.as_ref().into()))
+            })
+            .collect();
+        self.reply(&full_uri, params.into())
+    }
+
+    fn http_post_uri_binary<'a, 'p, RS>(
+        &'a self,
+        full_uri: Url,
+        _content_type: String,
+        _data: &'a [u8],
+        _context: SlackClientApiCallContext<'a>,
+    ) -> BoxFuture<'a, ClientResult<RS>>
+    where
+        RS: for<'de> serde::de::Deserialize<'de> + Send + 'a + Send + 'a,
+    {
+        self.reply(&full_uri, serde_json::Value::Null)
+    }
+}
@@ -0,0 +1,610 @@
+//!
+//! A higher-level helper over Slack Views API methods to manage modal stacks and Home tabs
+//!
+
+use crate::api::*;
+use crate::blocks::*;
+use crate::errors::*;
+use crate::events::*;
+use crate::*;
+use async_trait::async_trait;
+use rsb_derive::Builder;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::*;
+
+/// Slack accepts trigger ids only within 3 seconds after they were issued
+pub const SLACK_TRIGGER_ID_TTL: Duration = Duration::from_secs(3);
+
+/// A trigger id with the time it was received, to avoid API calls with expired triggers
+#[derive(Debug, PartialEq, Clone)]
+pub struct SlackViewTrigger {
+    pub trigger_id: SlackTriggerId,
+    pub received_at: Instant,
+}
+
+impl SlackViewTrigger {
+    pub fn new(trigger_id: SlackTriggerId) -> Self {
+        Self {
+            trigger_id,
+            received_at: Instant::now(),
+        }
+    }
+
+    pub fn with_received_at(self, received_at: Instant) -> Self {
+        Self {
+            received_at,
+            ..self
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.received_at.elapsed() >= SLACK_TRIGGER_ID_TTL
+    }
+}
+
+impl From<SlackTriggerId> for SlackViewTrigger {
+    fn from(trigger_id: SlackTriggerId) -> Self {
+        Self::new(trigger_id)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Builder)]
+pub struct SlackViewStackEntry {
+    pub view_id: SlackViewId,
+    /// The latest known hash of the view, used for `views.update` optimistic concurrency
+    pub hash: String,
+    pub callback_id: Option<SlackCallbackId>,
+}
+
+impl From<&SlackStatefulView> for SlackViewStackEntry {
+    fn from(view: &SlackStatefulView) -> Self {
+        let callback_id = match &view.view {
+            SlackView::Home(view) => view.callback_id.clone(),
+            SlackView::Modal(view) => view.callback_id.clone(),
+        };
+        SlackViewStackEntry::new(view.state_params.id.clone(), view.state_params.hash.clone())
+            .opt_callback_id(callback_id)
+    }
+}
+
+/// The views of a user tracked by `SlackViewsManager`
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct SlackUserViews {
+    /// Opened modals, from the root view to the top one
+    pub stack: Vec<SlackViewStackEntry>,
+    /// The digest of the last published Home tab, to skip publishing it again without changes
+    pub home_digest: Option<String>,
+}
+
+/// A store for the views of users, which can be shared between instances of an app
+#[async_trait]
+pub trait SlackViewsStore {
+    async fn load(&self, user_id: &SlackUserId) -> Option<SlackUserViews>;
+
+    /// Applies the change to the views of the user atomically, so concurrent changes aren't lost.
+    /// Stores with optimistic locking may call the function again when they retry.
+    /// The views equal to `SlackUserViews::default()` can be removed from the store.
+    async fn modify(
+        &self,
+        user_id: &SlackUserId,
+        f: &(dyn for<'v> Fn(&'v mut SlackUserViews) + Send + Sync),
+    );
+}
+
+/// The in-memory views store, suitable for a single instance of an app
+pub struct SlackViewsInMemoryStore {
+    users: Mutex<HashMap<SlackUserId, SlackUserViews>>,
+}
+
+impl SlackViewsInMemoryStore {
+    pub fn new() -> Self {
+        Self {
+            users: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl SlackViewsStore for SlackViewsInMemoryStore {
+    async fn load(&self, user_id: &SlackUserId) -> Option<SlackUserViews> {
+        self.users.lock().unwrap().get(user_id).cloned()
+    }
+
+    async fn modify(
+        &self,
+        user_id: &SlackUserId,
+        f: &(dyn for<'v> Fn(&'v mut SlackUserViews) + Send + Sync),
+    ) {
+        let mut users = self.users.lock().unwrap();
+        let mut views = users.remove(user_id).unwrap_or_default();
+        f(&mut views);
+        if views != SlackUserViews::default() {
+            users.insert(user_id.clone(), views);
+        }
+    }
+}
+
+///
+/// Tracks the modal stack of each user for `views.open`, `views.push` and `views.update`,
+/// keeping the latest view hashes for optimistic concurrency, and publishes Home tabs
+/// only when they change.
+///
+/// The stack is updated from the API responses, and from the interactions passed
+/// to `track_block_actions` and `track_view_closed`.
+///
+pub struct SlackViewsManager {
+    store: Arc<dyn SlackViewsStore + Send + Sync>,
+    max_update_retries: usize,
+}
+
+impl SlackViewsManager {
+    pub const DEFAULT_MAX_UPDATE_RETRIES: usize = 2;
+
+    pub fn new() -> Self {
+        Self::with_store(Arc::new(SlackViewsInMemoryStore::new()))
+    }
+
+    pub fn with_store(store: Arc<dyn SlackViewsStore + Send + Sync>) -> Self {
+        Self {
+            store,
+            max_update_retries: Self::DEFAULT_MAX_UPDATE_RETRIES,
+        }
+    }
+
+    pub fn with_max_update_retries(self, max_update_retries: usize) -> Self {
+        Self {
+            max_update_retries,
+            ..self
+        }
+    }
+
+    pub async fn user_views(&self, user_id: &SlackUserId) -> SlackUserViews {
+        self.store.load(user_id).await.unwrap_or_default()
+    }
+
+    /// Opens a new modal, replacing the tracked stack of the user
+    pub async fn open<SCHC>(
+        &self,
+        session: &SlackClientSession<'_, SCHC>,
+        user_id: &SlackUserId,
+        trigger: &SlackViewTrigger,
+        view: SlackView,
+    ) -> ClientResult<SlackStatefulView>
+    where
+        SCHC: SlackClientHttpConnector + Send + Sync,
+    {
+        Self::check_trigger(trigger)?;
+        let opened = session
+            .views_open(&SlackApiViewsOpenRequest::new(
+                trigger.trigger_id.clone(),
+                view,
+            ))
+            .await?
+            .view;
+
+        self.modify(user_id, |views| {
+            views.stack = vec![SlackViewStackEntry::from(&opened)];
+        })
+        .await;
+        Ok(opened)
+    }
+
+    /// Pushes a modal on top of the opened ones (Slack allows up to 3 in a stack)
+    pub async fn push<SCHC>(
+        &self,
+        session: &SlackClientSession<'_, SCHC>,
+        user_id: &SlackUserId,
+        trigger: &SlackViewTrigger,
+        view: SlackView,
+    ) -> ClientResult<SlackStatefulView>
+    where
+        SCHC: SlackClientHttpConnector + Send + Sync,
+    {
+        Self::check_trigger(trigger)?;
+        let pushed = session
+            .views_push(&SlackApiViewsPushRequest::new(
+                trigger.trigger_id.clone(),
+                view,
+            ))
+            .await?
+            .view;
+
+        self.modify(user_id, |views| {
+            views.stack.push(SlackViewStackEntry::from(&pushed));
+        })
+        .await;
+        Ok(pushed)
+    }
+
+    ///
+    /// Updates the view with the latest tracked hash.
+    ///
+    /// On `hash_conflict` the view is rendered again (so it can pick up the current app state)
+    /// and retried up to `max_update_retries` times, but only when the tracked hash has changed
+    /// since the failed attempt (e.g. with `track_block_actions` for a concurrent interaction).
+    /// Otherwise, the conflict error is returned, so the view is never overwritten without a hash.
+    ///
+    pub async fn update<SCHC, F>(
+        &self,
+        session: &SlackClientSession<'_, SCHC>,
+        user_id: &SlackUserId,
+        view_id: &SlackViewId,
+        mut render: F,
+    ) -> ClientResult<SlackStatefulView>
+    where
+        SCHC: SlackClientHttpConnector + Send + Sync,
+        F: FnMut() -> SlackView,
+    {
+        let mut hash = self.tracked_hash(user_id, view_id).await;
+        let mut retries = 0;
+        loop {
+            let req = SlackApiViewsUpdateRequest::new(render())
+                .with_view_id(view_id.clone())
+                .opt_hash(hash.clone());
+
+            match session.views_update(&req).await {
+                Ok(res) => {
+                    self.track_view(user_id, &res.view).await;
+                    return Ok(res.view);
+                }
+                Err(SlackClientError::ApiError(err))
+                    if err.code == "hash_conflict" && retries < self.max_update_retries =>
+                {
+                    retries += 1;
+                    debug!(
+                        "Slack view {} has been updated concurrently. Retrying: {}/{}",
+                        view_id, retries, self.max_update_retries
+                    );
+                    match self.tracked_hash(user_id, view_id).await {
+                        Some(tracked_hash) if Some(&tracked_hash) != hash.as_ref() => {
+                            hash = Some(tracked_hash);
+                        }
+                        _ => return Err(SlackClientError::ApiError(err)),
+                    }
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Publishes the Home tab unless it's the same as the last one published for the user
+    pub async fn publish_home<SCHC>(
+        &self,
+        session: &SlackClientSession<'_, SCHC>,
+        user_id: &SlackUserId,
+        view: SlackHomeView,
+    ) -> ClientResult<Option<SlackStatefulView>>
+    where
+        SCHC: SlackClientHttpConnector + Send + Sync,
+    {
+        let digest = Self::home_digest(&view)?;
+        let views = self.user_views(user_id).await;
+        if views.home_digest.as_ref() == Some(&digest) {
+            trace!("Slack Home tab for {} hasn't changed", user_id);
+            return Ok(None);
+        }
+
+        let published = session
+            .views_publish(&SlackApiViewsPublishRequest::new(
+                user_id.clone(),
+                SlackView::Home(view),
+            ))
+            .await?
+            .view;
+
+        self.modify(user_id, |views| views.home_digest = Some(digest.clone()))
+            .await;
+        Ok(Some(published))
+    }
+
+    pub async fn publish_home_template<SCHC, T>(
+        &self,
+        session: &SlackClientSession<'_, SCHC>,
+        user_id: &SlackUserId,
+        template: &T,
+    ) -> ClientResult<Option<SlackStatefulView>>
+    where
+        SCHC: SlackClientHttpConnector + Send + Sync,
+        T: SlackBlocksTemplate,
+    {
+        self.publish_home(
+            session,
+            user_id,
+            SlackHomeView::new(template.render_template()),
+        )
+        .await
+    }
+
+    /// Forgets the last published Home tab, so the next one is published even without changes
+    pub async fn reset_home(&self, user_id: &SlackUserId) {
+        self.modify(user_id, |views| views.home_digest = None).await;
+    }
+
+    /// Updates the hash of a tracked view, e.g. from `view_submission` payloads
+    pub async fn track_view(&self, user_id: &SlackUserId, view: &SlackStatefulView) {
+        self.modify(user_id, |views| {
+            if let Some(entry) = views
+                .stack
+                .iter_mut()
+                .find(|entry| entry.view_id == view.state_params.id)
+            {
+                *entry = SlackViewStackEntry::from(view);
+            }
+        })
+        .await;
+    }
+
+    /// Updates the hash of a tracked view from block actions in modals
+    pub async fn track_block_actions(&self, event: &SlackInteractionBlockActionsEvent) {
+        let (
+            Some(user),
+            SlackInteractionActionContainer::View(container),
+            Some(SlackView::Modal(SlackModalView {
+                hash: Some(hash), ..
+            })),
+        ) = (&event.user, &event.container, &event.view)
+        else {
+            return;
+        };
+
+        self.modify(&user.id, |views| {
+            if let Some(entry) = views
+                .stack
+                .iter_mut()
+                .find(|entry| entry.view_id == container.view_id)
+            {
+                entry.hash = hash.clone();
+            }
+        })
+        .await;
+    }
+
+    pub async fn track_view_closed(&self, event: &SlackInteractionViewClosedEvent) {
+        self.close_view(&event.user.id, &event.view.state_params.id)
+            .await;
+    }
+
+    /// Removes the view and the views pushed after it from the tracked stack
+    pub async fn close_view(&self, user_id: &SlackUserId, view_id: &SlackViewId) {
+        self.modify(user_id, |views| {
+            if let Some(position) = views
+                .stack
+                .iter()
+                .position(|entry| &entry.view_id == view_id)
+            {
+                views.stack.truncate(position);
+            }
+        })
+        .await;
+    }
+
+    /// Clears the tracked stack, e.g. after `SlackViewSubmissionResponse::clear()`
+    pub async fn clear_stack(&self, user_id: &SlackUserId) {
+        self.modify(user_id, |views| views.stack.clear()).await;
+    }
+
+    async fn tracked_hash(&self, user_id: &SlackUserId, view_id: &SlackViewId) -> Option<String> {
+        self.user_views(user_id)
+            .await
+            .stack
+            .into_iter()
+            .find(|entry| &entry.view_id == view_id)
+            .map(|entry| entry.hash)
+    }
+
+    async fn modify<F>(&self, user_id: &SlackUserId, f: F)
+    where
+        F: Fn(&mut SlackUserViews) + Send + Sync,
+    {
+        self.store.modify(user_id, &f).await;
+    }
+
+    /// The 64-bit FNV-1a hash of the view JSON, which is the same for all instances of an app
+    fn home_digest(view: &SlackHomeView) -> ClientResult<String> {
+        let json = serde_json::to_vec(view).map_err(|err| map_serde_error(err, None))?;
+        let digest = json.iter().fold(0xcbf29ce484222325u64, |digest, byte| {
+            (digest ^ *byte as u64).wrapping_mul(0x100000001b3)
+        });
+        Ok(format!("{:016x}", digest))
+    }
+
+    fn check_trigger(trigger: &SlackViewTrigger) -> ClientResult<()> {
+        if trigger.is_expired() {
+            Err(SlackClientError::SystemError(
+                SlackClientSystemError::new().with_message(format!(
+                    "Slack trigger id {} has expired, it was received {:?} ago",
+                    trigger.trigger_id,
+                    trigger.received_at.elapsed()
+                )),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::test_connector::SlackTestHttpConnector;
+    use futures::FutureExt;
+
+    fn stateful_view(id: &str, hash: &str) -> SlackStatefulView {
+        SlackStatefulView::new(
+            SlackStatefulStateParams::new(id.into(), "T1".into(), hash.into()),
+            SlackView::Modal(SlackModalView::new(
+                SlackBlockPlainTextOnly::from("Wizard"),
+                vec![],
+            )),
+        )
+    }
+
+    #[test]
+    fn test_view_trigger_expiration() {
+        assert!(!SlackViewTrigger::new("T1".into()).is_expired());
+        assert!(SlackViewTrigger::new("T1".into())
+            .with_received_at(Instant::now() - SLACK_TRIGGER_ID_TTL)
+            .is_expired());
+    }
+
+    #[test]
+    fn test_views_stack_tracking() {
+        let manager = SlackViewsManager::new();
+        let user_id: SlackUserId = "U1".into();
+        futures::executor::block_on(async {
+            manager
+                .store
+                .modify(&user_id, &|views| {
+                    views.stack = vec![
+                        SlackViewStackEntry::from(&stateful_view("V1", "h1")),
+                        SlackViewStackEntry::from(&stateful_view("V2", "h2")),
+                        SlackViewStackEntry::from(&stateful_view("V3", "h3")),
+                    ]
+                })
+                .await;
+
+            manager
+                .track_view(&user_id, &stateful_view("V2", "h2-updated"))
+                .await;
+            assert_eq!(
+                manager.tracked_hash(&user_id, &"V2".into()).await,
+                Some("h2-updated".into())
+            );
+
+            manager.close_view(&user_id, &"V2".into()).await;
+            assert_eq!(
+                manager.user_views(&user_id).await.stack,
+                vec![SlackViewStackEntry::from(&stateful_view("V1", "h1"))]
+            );
+
+            manager.clear_stack(&user_id).await;
+            assert_eq!(manager.store.load(&user_id).await, None);
+        });
+    }
+
+    fn view_response(id: &str, hash: &str) -> serde_json::Value {
+        serde_json::json!({ "view": stateful_view(id, hash) })
+    }
+
+    fn modal(title: &str) -> SlackView {
+        SlackView::Modal(SlackModalView::new(title.into(), vec![]))
+    }
+
+    #[tokio::test]
+    async fn test_update_retries_with_tracked_hash() {
+        let client = SlackClient::new(SlackTestHttpConnector::new());
+        let token = SlackApiToken::new("xoxb-test".into());
+        let session = client.open_session(&token);
+        let store = Arc::new(SlackViewsInMemoryStore::new());
+        let manager = SlackViewsManager::with_store(store.clone());
+        let user_id: SlackUserId = "U1".into();
+        manager
+            .store
+            .modify(&user_id, &|views| {
+                views.stack = vec![SlackViewStackEntry::from(&stateful_view("V1", "h1"))]
+            })
+            .await;
+
+        // A concurrent interaction is tracked while the first attempt is in flight
+        let mut renders = 0;
+        client
+            .http_api
+            .connector
+            .respond_error("views.update", "hash_conflict");
+        client
+            .http_api
+            .connector
+            .respond("views.update", view_response("V1", "h3"));
+        let updated = manager
+            .update(&session, &user_id, &"V1".into(), || {
+                renders += 1;
+                if renders == 1 {
+                    store
+                        .modify(&user_id, &|views| views.stack[0].hash = "h2".into())
+                        .now_or_never()
+                        .unwrap();
+                }
+                modal(&format!("Render {renders}"))
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(updated.state_params.hash, "h3");
+        assert_eq!(renders, 2);
+        let hashes: Vec<serde_json::Value> = client
+            .http_api
+            .connector
+            .requests("views.update")
+            .into_iter()
+            .map(|request| request["hash"].clone())
+            .collect();
+        assert_eq!(hashes, vec!["h1", "h2"]);
+        assert_eq!(
+            manager.tracked_hash(&user_id, &"V1".into()).await,
+            Some("h3".into())
+        );
+
+        // Without a newer tracked hash the conflict is returned instead of overwriting the view
+        client
+            .http_api
+            .connector
+            .respond_error("views.update", "hash_conflict");
+        let err = manager
+            .update(&session, &user_id, &"V1".into(), || modal("Stale"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SlackClientError::ApiError(err) if err.code == "hash_conflict"));
+        assert_eq!(client.http_api.connector.requests("views.update").len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_publish_home_only_when_changed() {
+        let client = SlackClient::new(SlackTestHttpConnector::new());
+        let token = SlackApiToken::new("xoxb-test".into());
+        let session = client.open_session(&token);
+        let manager = SlackViewsManager::new();
+        let user_id: SlackUserId = "U1".into();
+        let home = SlackHomeView::new(vec![SlackDividerBlock::new().into()]);
+        for _ in 0..3 {
+            client
+                .http_api
+                .connector
+                .respond("views.publish", view_response("V1", "h1"));
+        }
+
+        assert!(manager
+            .publish_home(&session, &user_id, home.clone())
+            .await
+            .unwrap()
+            .is_some());
+        assert!(manager
+            .publish_home(&session, &user_id, home.clone())
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(client.http_api.connector.requests("views.publish").len(), 1);
+
+        let changed = home.clone().with_private_metadata("changed".into());
+        assert!(manager
+            .publish_home(&session, &user_id, changed.clone())
+            .await
+            .unwrap()
+            .is_some());
+
+        assert_eq!(
+            manager.user_views(&user_id).await.home_digest,
+            Some(SlackViewsManager::home_digest(&changed).unwrap())
+        );
+
+        manager.reset_home(&user_id).await;
+        assert!(manager
+            .publish_home(&session, &user_id, changed)
+            .await
+            .unwrap()
+            .is_some());
+        assert_eq!(client.http_api.connector.requests("views.publish").len(), 3);
+    }
+}